@@ -1 +1,2 @@
 pub mod size_estimation;
+pub mod workspace;
@@ -42,6 +42,7 @@ pub type DltEstimateCompressedSizeFn = unsafe extern "C" fn(
 
 /// C-compatible size estimator that wraps function pointers.
 #[repr(C)]
+#[derive(Clone, Copy)]
 pub struct DltSizeEstimator {
     /// User-provided context passed to all callbacks
     pub context: *mut c_void,
@@ -0,0 +1,63 @@
+//! C-compatible handle for [`AutoTransformWorkspace`].
+
+use crate::estimate::AutoTransformWorkspace;
+use alloc::boxed::Box;
+
+/// Opaque handle for an [`AutoTransformWorkspace`].
+///
+/// Holds the scratch memory used by automatic transforms, so it can be reused between calls
+/// (e.g. `dltbc1_AutoTransformBuilder_TransformWithWorkspace`) instead of being allocated for
+/// each one. A workspace may be used with any format, but by only one transform at a time.
+///
+/// Create with [`dlt_new_AutoTransformWorkspace`] and free with [`dlt_free_AutoTransformWorkspace`].
+///
+/// This struct intentionally lacks `#[repr(C)]` to ensure it generates as an opaque forward declaration.
+pub struct DltAutoTransformWorkspace {
+    /// The wrapped workspace.
+    pub workspace: AutoTransformWorkspace,
+}
+
+/// Create a new, empty auto transform workspace.
+///
+/// Memory is only allocated once the workspace is first used by a transform.
+///
+/// # Returns
+/// A pointer to a new workspace, which must be freed with [`dlt_free_AutoTransformWorkspace`].
+#[no_mangle]
+pub extern "C" fn dlt_new_AutoTransformWorkspace() -> *mut DltAutoTransformWorkspace {
+    Box::into_raw(Box::new(DltAutoTransformWorkspace {
+        workspace: AutoTransformWorkspace::new(),
+    }))
+}
+
+/// Free an auto transform workspace, along with the memory it holds.
+///
+/// # Safety
+/// - `workspace` must be a pointer returned by [`dlt_new_AutoTransformWorkspace`], or null
+/// - `workspace` must not be used after calling this function
+#[no_mangle]
+pub unsafe extern "C" fn dlt_free_AutoTransformWorkspace(
+    workspace: *mut DltAutoTransformWorkspace,
+) {
+    if !workspace.is_null() {
+        drop(Box::from_raw(workspace));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_and_free_workspace() {
+        let workspace = dlt_new_AutoTransformWorkspace();
+        assert!(!workspace.is_null());
+        unsafe {
+            assert_eq!((*workspace).workspace.capacity(), 0);
+            dlt_free_AutoTransformWorkspace(workspace);
+
+            // Should not crash when freeing null pointer
+            dlt_free_AutoTransformWorkspace(core::ptr::null_mut());
+        }
+    }
+}
@@ -6,6 +6,11 @@
 
 use alloc::boxed::Box;
 
+mod progress;
+mod workspace;
+pub use progress::{EstimateProgress, ProgressCallback};
+pub use workspace::AutoTransformWorkspace;
+
 /// Trait for size estimation operations.
 ///
 /// This trait is used to test the most optimal transform by comparing compressed sizes
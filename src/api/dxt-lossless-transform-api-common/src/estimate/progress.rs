@@ -0,0 +1,86 @@
+//! Progress reporting and cancellation for automatic transforms.
+
+use alloc::boxed::Box;
+use core::fmt::{Debug, Formatter};
+use core::ops::ControlFlow;
+
+/// Progress of an automatic transform, reported after each tested candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EstimateProgress {
+    /// Number of candidate configurations tested so far.
+    pub completed: usize,
+    /// Total number of candidate configurations that will be tested.
+    pub total: usize,
+}
+
+/// Callback invoked by automatic transforms after each tested candidate.
+///
+/// Return [`ControlFlow::Break`] to cancel the transform; it then returns a `Cancelled` error
+/// and leaves the output buffer in an unspecified state.
+///
+/// Estimating a candidate with a slow estimator (e.g. ZStandard on large textures) can take
+/// a while, so cancellation takes effect once the candidate being tested completes.
+///
+/// # Examples
+///
+/// ```
+/// use core::ops::ControlFlow;
+/// use core::sync::atomic::{AtomicBool, Ordering};
+/// use dxt_lossless_transform_api_common::estimate::ProgressCallback;
+///
+/// static CANCEL: AtomicBool = AtomicBool::new(false);
+///
+/// let callback = ProgressCallback::new(|progress| {
+///     println!("{}/{}", progress.completed, progress.total);
+///     if CANCEL.load(Ordering::Relaxed) {
+///         ControlFlow::Break(())
+///     } else {
+///         ControlFlow::Continue(())
+///     }
+/// });
+/// ```
+pub struct ProgressCallback(Box<dyn Fn(EstimateProgress) -> ControlFlow<()> + Send + Sync>);
+
+impl ProgressCallback {
+    /// Creates a callback from a closure.
+    pub fn new(
+        callback: impl Fn(EstimateProgress) -> ControlFlow<()> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Box::new(callback))
+    }
+
+    /// Reports progress to the callback, returning whether the transform should continue.
+    pub fn report(&self, completed: usize, total: usize) -> ControlFlow<()> {
+        (self.0)(EstimateProgress { completed, total })
+    }
+}
+
+impl Debug for ProgressCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("ProgressCallback")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn report_passes_progress_and_returns_result() {
+        static LAST_COMPLETED: AtomicUsize = AtomicUsize::new(0);
+        let callback = ProgressCallback::new(|progress| {
+            LAST_COMPLETED.store(progress.completed, Ordering::Relaxed);
+            if progress.completed < progress.total {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        });
+
+        assert_eq!(callback.report(1, 4), ControlFlow::Continue(()));
+        assert_eq!(LAST_COMPLETED.load(Ordering::Relaxed), 1);
+        assert_eq!(callback.report(4, 4), ControlFlow::Break(()));
+        assert_eq!(LAST_COMPLETED.load(Ordering::Relaxed), 4);
+    }
+}
@@ -0,0 +1,106 @@
+//! Reusable scratch memory for automatic transforms.
+
+use dxt_lossless_transform_common::allocate::{allocate_align_64, AllocateError};
+use safe_allocator_api::RawAlloc;
+
+/// Scratch memory reused across automatic transforms.
+///
+/// Each automatic transform needs a buffer for the size estimator to compress into, sized by
+/// [`SizeEstimationOperations::max_compressed_size`]. Without a workspace, that buffer is
+/// allocated and freed on every call; when transforming many (small) textures, the allocations
+/// can dominate. Passing the same workspace to each call allocates once, growing the buffer
+/// only when a larger one is needed.
+///
+/// A workspace can be used with any format and estimator, but only by one transform at a time;
+/// use one workspace per thread.
+///
+/// State internal to an estimator (e.g. a compression context) is kept by the estimator
+/// itself, so reuse the estimator (and builder) alongside the workspace.
+///
+/// # Examples
+///
+/// ```
+/// use dxt_lossless_transform_api_common::estimate::AutoTransformWorkspace;
+///
+/// let mut workspace = AutoTransformWorkspace::new();
+/// assert_eq!(workspace.capacity(), 0);
+/// // Pass `&mut workspace` to e.g. `Bc1AutoTransformBuilder::transform_with_workspace`
+/// // for every texture.
+/// ```
+///
+/// [`SizeEstimationOperations::max_compressed_size`]: crate::estimate::SizeEstimationOperations::max_compressed_size
+#[derive(Debug, Default)]
+pub struct AutoTransformWorkspace {
+    compression_buffer: Option<RawAlloc>,
+}
+
+impl AutoTransformWorkspace {
+    /// Creates an empty workspace; memory is allocated on first use.
+    pub const fn new() -> Self {
+        Self {
+            compression_buffer: None,
+        }
+    }
+
+    /// Size of the compression buffer currently held, in bytes.
+    pub fn capacity(&self) -> usize {
+        self.compression_buffer.as_ref().map_or(0, RawAlloc::len)
+    }
+
+    /// Frees the memory held by the workspace.
+    pub fn clear(&mut self) {
+        self.compression_buffer = None;
+    }
+
+    /// Returns a pointer to and the length of a 64-byte aligned compression buffer
+    /// of at least `num_bytes`, growing the held buffer if needed.
+    ///
+    /// Returns a null pointer and length 0 if `num_bytes` is 0.
+    ///
+    /// This is used by the format crates; the returned buffer is only valid until the
+    /// next call, or until the workspace is dropped.
+    #[doc(hidden)]
+    pub fn compression_buffer(
+        &mut self,
+        num_bytes: usize,
+    ) -> Result<(*mut u8, usize), AllocateError> {
+        if num_bytes == 0 {
+            return Ok((core::ptr::null_mut(), 0));
+        }
+
+        if self.capacity() < num_bytes {
+            // Free the old buffer first, to avoid holding both at once
+            self.compression_buffer = None;
+            self.compression_buffer = Some(allocate_align_64(num_bytes)?);
+        }
+
+        let buffer = self.compression_buffer.as_mut().unwrap();
+        Ok((buffer.as_mut_ptr(), buffer.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compression_buffer_grows_and_is_reused() {
+        let mut workspace = AutoTransformWorkspace::new();
+        assert_eq!(workspace.compression_buffer(0).unwrap().1, 0);
+        assert_eq!(workspace.capacity(), 0);
+
+        let (ptr, len) = workspace.compression_buffer(128).unwrap();
+        assert_eq!(len, 128);
+        assert_eq!(ptr as usize % 64, 0);
+
+        // Smaller requests reuse the existing buffer
+        assert_eq!(workspace.compression_buffer(64).unwrap(), (ptr, 128));
+
+        // Larger requests grow it
+        assert_eq!(workspace.compression_buffer(256).unwrap().1, 256);
+        assert_eq!(workspace.capacity(), 256);
+
+        workspace.clear();
+        assert_eq!(workspace.capacity(), 0);
+    }
+}
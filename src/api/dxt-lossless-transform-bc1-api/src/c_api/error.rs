@@ -36,6 +36,10 @@ pub enum Dltbc1ErrorCode {
     NullBuilderPointer = 11,
     /// Null pointer provided for manual builder output parameter
     NullManualBuilderOutputPointer = 12,
+    /// The transform was cancelled by its progress callback
+    Cancelled = 13,
+    /// Null pointer provided for DltAutoTransformWorkspace parameter
+    NullWorkspacePointer = 14,
 }
 
 /// C-compatible Result type for BC1 operations.
@@ -83,6 +87,7 @@ where
             Bc1Error::OutputBufferTooSmall { .. } => Dltbc1ErrorCode::OutputBufferTooSmall,
             Bc1Error::AllocationFailed => Dltbc1ErrorCode::AllocationFailed,
             Bc1Error::SizeEstimationFailed(_) => Dltbc1ErrorCode::SizeEstimationFailed,
+            Bc1Error::Cancelled => Dltbc1ErrorCode::Cancelled,
         };
         Self::from_error_code(error_code)
     }
@@ -115,6 +120,7 @@ where
                     Dltbc1ErrorCode::SizeEstimationFailed
                 }
                 DetermineBestTransformError::AllocateError(_) => Dltbc1ErrorCode::AllocationFailed,
+                DetermineBestTransformError::Cancelled => Dltbc1ErrorCode::Cancelled,
             },
         };
         Self::from_error_code(error_code)
@@ -168,6 +174,13 @@ pub unsafe extern "C" fn dltbc1_error_message(error_code: Dltbc1ErrorCode) -> *c
         Dltbc1ErrorCode::NullManualBuilderOutputPointer => {
             c"Null pointer provided for manual builder output parameter".as_ptr() as *const c_char
         }
+        Dltbc1ErrorCode::Cancelled => {
+            c"Transform was cancelled by its progress callback".as_ptr() as *const c_char
+        }
+        Dltbc1ErrorCode::NullWorkspacePointer => {
+            c"Null pointer provided for DltAutoTransformWorkspace parameter".as_ptr()
+                as *const c_char
+        }
     }
 }
 
@@ -371,6 +384,8 @@ mod tests {
             Dltbc1ErrorCode::NullManualTransformBuilderPointer,
             Dltbc1ErrorCode::NullBuilderPointer,
             Dltbc1ErrorCode::NullManualBuilderOutputPointer,
+            Dltbc1ErrorCode::Cancelled,
+            Dltbc1ErrorCode::NullWorkspacePointer,
         ];
 
         for &error_code in &error_codes {
@@ -7,6 +7,8 @@ use crate::c_api::error::{Dltbc1ErrorCode, Dltbc1Result};
 use crate::c_api::transform::manual_transform_builder::Dltbc1ManualTransformBuilder;
 use alloc::boxed::Box;
 use dxt_lossless_transform_api_common::c_api::size_estimation::DltSizeEstimator;
+use dxt_lossless_transform_api_common::c_api::workspace::DltAutoTransformWorkspace;
+use dxt_lossless_transform_api_common::estimate::AutoTransformWorkspace;
 
 /// Opaque handle for BC1 auto transform builder.
 ///
@@ -37,6 +39,22 @@ pub struct Dltbc1AutoTransformBuilder {
     use_all_decorrelation_modes: bool,
 }
 
+impl Dltbc1AutoTransformBuilder {
+    /// Creates the Rust builder equivalent to this handle, wrapping its estimator with `wrap`.
+    ///
+    /// Allows C APIs of other crates (e.g. the file formats API) to accept this handle.
+    pub fn builder<T>(
+        &self,
+        wrap: impl FnOnce(DltSizeEstimator) -> T,
+    ) -> crate::Bc1AutoTransformBuilder<T>
+    where
+        T: dxt_lossless_transform_api_common::estimate::SizeEstimationOperations,
+    {
+        crate::Bc1AutoTransformBuilder::new(wrap(self.estimator))
+            .use_all_decorrelation_modes(self.use_all_decorrelation_modes)
+    }
+}
+
 /// Create a new BC1 auto transform builder with the provided estimator.
 ///
 /// The estimator should have its compression level and other parameters already configured.
@@ -209,9 +227,121 @@ pub unsafe extern "C" fn dltbc1_AutoTransformBuilder_Transform(
         return Dltbc1Result::from_error_code(Dltbc1ErrorCode::NullManualBuilderOutputPointer);
     }
 
-    // Get settings from builder
-    let builder_impl = unsafe { &*builder };
+    unsafe {
+        transform(
+            &*builder,
+            data,
+            data_len,
+            output,
+            output_len,
+            out_manual_builder,
+            None,
+        )
+    }
+}
 
+/// Transform Bc1 data using automatically determined optimal settings, reusing the scratch memory
+/// held by `workspace`, and return a configured manual builder.
+///
+/// This is [`dltbc1_AutoTransformBuilder_Transform`], but the compression buffer used by the
+/// estimator is kept in the workspace between calls. When transforming many textures, reuse the
+/// same builder and workspace to avoid allocating for each one.
+///
+/// # Parameters
+/// - `builder`: The configured auto builder
+/// - `workspace`: The workspace to reuse, created with [`dlt_new_AutoTransformWorkspace`]
+/// - `data`: Pointer to Bc1 data to transform
+/// - `data_len`: Length of input data in bytes (must be divisible by 8)
+/// - `output`: Pointer to output buffer where transformed data will be written
+/// - `output_len`: Length of output buffer in bytes (must be at least `data_len`)
+/// - `out_manual_builder`: Output pointer where the configured manual builder will be written.
+///   On success, this will be set to a valid pointer that must be freed with [`dltbc1_free_ManualTransformBuilder`].
+///   On error, this will be set to null.
+///
+/// # Returns
+/// A [`Dltbc1Result`] indicating success or containing an error code.
+///
+/// # Safety
+/// - `builder` must be a valid pointer to a [`Dltbc1AutoTransformBuilder`]
+/// - `workspace` must be a valid pointer to a [`DltAutoTransformWorkspace`], not used by any
+///   other transform for the duration of the call
+/// - `data` must be valid for reads of `data_len` bytes
+/// - `output` must be valid for writes of `output_len` bytes
+/// - `out_manual_builder` must be a valid pointer to write the result
+/// - The estimator associated with the builder must remain valid for the duration of the call
+///
+/// # Examples
+///
+/// ```c
+/// DltAutoTransformWorkspace* workspace = dlt_new_AutoTransformWorkspace();
+/// for (size_t x = 0; x < num_textures; x++) {
+///     Dltbc1ManualTransformBuilder* manual_builder = NULL;
+///     Dltbc1Result result = dltbc1_AutoTransformBuilder_TransformWithWorkspace(
+///         auto_builder, workspace, textures[x].data, textures[x].len,
+///         outputs[x].data, outputs[x].len, &manual_builder);
+///     // ...
+/// }
+/// dlt_free_AutoTransformWorkspace(workspace);
+/// ```
+///
+/// # Remarks
+/// This function corresponds to [`crate::Bc1AutoTransformBuilder::transform_with_workspace`] in the Rust API.
+///
+/// [`dlt_new_AutoTransformWorkspace`]: dxt_lossless_transform_api_common::c_api::workspace::dlt_new_AutoTransformWorkspace
+/// [`dltbc1_free_ManualTransformBuilder`]: crate::c_api::transform::manual_transform_builder::dltbc1_free_ManualTransformBuilder
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dltbc1_AutoTransformBuilder_TransformWithWorkspace(
+    builder: *mut Dltbc1AutoTransformBuilder,
+    workspace: *mut DltAutoTransformWorkspace,
+    data: *const u8,
+    data_len: usize,
+    output: *mut u8,
+    output_len: usize,
+    out_manual_builder: *mut *mut Dltbc1ManualTransformBuilder,
+) -> Dltbc1Result {
+    // Validate required pointers
+    if builder.is_null() {
+        return Dltbc1Result::from_error_code(Dltbc1ErrorCode::NullBuilderPointer);
+    }
+    if workspace.is_null() {
+        return Dltbc1Result::from_error_code(Dltbc1ErrorCode::NullWorkspacePointer);
+    }
+    if data.is_null() {
+        return Dltbc1Result::from_error_code(Dltbc1ErrorCode::NullDataPointer);
+    }
+    if output.is_null() {
+        return Dltbc1Result::from_error_code(Dltbc1ErrorCode::NullOutputBufferPointer);
+    }
+    if out_manual_builder.is_null() {
+        return Dltbc1Result::from_error_code(Dltbc1ErrorCode::NullManualBuilderOutputPointer);
+    }
+
+    unsafe {
+        transform(
+            &*builder,
+            data,
+            data_len,
+            output,
+            output_len,
+            out_manual_builder,
+            Some(&mut (*workspace).workspace),
+        )
+    }
+}
+
+/// Shared implementation of the transform functions, after pointer validation.
+///
+/// # Safety
+/// Same requirements as [`dltbc1_AutoTransformBuilder_TransformWithWorkspace`].
+unsafe fn transform(
+    builder_impl: &Dltbc1AutoTransformBuilder,
+    data: *const u8,
+    data_len: usize,
+    output: *mut u8,
+    output_len: usize,
+    out_manual_builder: *mut *mut Dltbc1ManualTransformBuilder,
+    workspace: Option<&mut AutoTransformWorkspace>,
+) -> Dltbc1Result {
     // Create input and output slices
     let input_slice = unsafe { core::slice::from_raw_parts(data, data_len) };
     let output_slice = unsafe { core::slice::from_raw_parts_mut(output, output_len) };
@@ -221,7 +351,13 @@ pub unsafe extern "C" fn dltbc1_AutoTransformBuilder_Transform(
         .use_all_decorrelation_modes(builder_impl.use_all_decorrelation_modes);
 
     // Transform using the Rust API
-    match rust_auto_builder.transform(input_slice, output_slice) {
+    let result = match workspace {
+        Some(workspace) => {
+            rust_auto_builder.transform_with_workspace(input_slice, output_slice, workspace)
+        }
+        None => rust_auto_builder.transform(input_slice, output_slice),
+    };
+    match result {
         Ok(manual_builder) => {
             // Create the C API wrapper for the manual builder
             let inner = Box::new(
@@ -668,4 +804,83 @@ mod tests {
             dltbc1_free_AutoTransformBuilder(builder);
         }
     }
+
+    #[test]
+    fn test_dltbc1_auto_transform_builder_transform_with_workspace() {
+        use crate::c_api::transform::manual_transform_builder::dltbc1_free_ManualTransformBuilder;
+        use dxt_lossless_transform_api_common::c_api::workspace::{
+            dlt_free_AutoTransformWorkspace, dlt_new_AutoTransformWorkspace,
+        };
+
+        let estimator = create_dummy_estimator();
+        let test_data = create_test_bc1_data();
+
+        unsafe {
+            let builder = dltbc1_new_AutoTransformBuilder(&estimator);
+            let workspace = dlt_new_AutoTransformWorkspace();
+
+            let mut expected = vec![0u8; test_data.len()];
+            let mut manual_builder: *mut Dltbc1ManualTransformBuilder = ptr::null_mut();
+            let result = dltbc1_AutoTransformBuilder_Transform(
+                builder,
+                test_data.as_ptr(),
+                test_data.len(),
+                expected.as_mut_ptr(),
+                expected.len(),
+                &mut manual_builder,
+            );
+            assert!(result.is_success());
+            dltbc1_free_ManualTransformBuilder(manual_builder);
+
+            // The workspace buffer is allocated on first use, then reused for the second call
+            for _ in 0..2 {
+                let mut output = vec![0u8; test_data.len()];
+                let result = dltbc1_AutoTransformBuilder_TransformWithWorkspace(
+                    builder,
+                    workspace,
+                    test_data.as_ptr(),
+                    test_data.len(),
+                    output.as_mut_ptr(),
+                    output.len(),
+                    &mut manual_builder,
+                );
+
+                assert_eq!(result.error_code, Dltbc1ErrorCode::Success);
+                assert!(!manual_builder.is_null());
+                assert_eq!(output, expected);
+                assert_ne!((*workspace).workspace.capacity(), 0);
+                dltbc1_free_ManualTransformBuilder(manual_builder);
+            }
+
+            dlt_free_AutoTransformWorkspace(workspace);
+            dltbc1_free_AutoTransformBuilder(builder);
+        }
+    }
+
+    #[test]
+    fn test_dltbc1_auto_transform_builder_transform_with_workspace_null_workspace() {
+        let estimator = create_dummy_estimator();
+        let test_data = create_test_bc1_data();
+        let mut output = vec![0u8; test_data.len()];
+        let mut manual_builder: *mut Dltbc1ManualTransformBuilder = ptr::null_mut();
+
+        unsafe {
+            let builder = dltbc1_new_AutoTransformBuilder(&estimator);
+
+            let result = dltbc1_AutoTransformBuilder_TransformWithWorkspace(
+                builder,
+                ptr::null_mut(),
+                test_data.as_ptr(),
+                test_data.len(),
+                output.as_mut_ptr(),
+                output.len(),
+                &mut manual_builder,
+            );
+
+            assert_eq!(result.error_code, Dltbc1ErrorCode::NullWorkspacePointer);
+            assert!(manual_builder.is_null());
+
+            dltbc1_free_AutoTransformBuilder(builder);
+        }
+    }
 }
@@ -43,6 +43,15 @@ pub struct Dltbc1ManualTransformBuilder {
     pub(crate) builder: Bc1ManualTransformBuilder,
 }
 
+impl Dltbc1ManualTransformBuilder {
+    /// Returns the Rust builder behind this handle.
+    ///
+    /// Allows C APIs of other crates (e.g. the file formats API) to accept this handle.
+    pub fn builder(&self) -> Bc1ManualTransformBuilder {
+        self.builder
+    }
+}
+
 /// Get mutable access to the manual transform builder.
 ///
 /// # Safety
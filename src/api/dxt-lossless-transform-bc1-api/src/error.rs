@@ -32,6 +32,10 @@ where
     /// Size estimation failed during transform optimization.
     #[error("Size estimation failed: {0:?}")]
     SizeEstimationFailed(E),
+
+    /// The transform was cancelled by its progress callback.
+    #[error("Transform was cancelled")]
+    Cancelled,
 }
 
 // Internal conversion functions to avoid exposing core types in public From traits
@@ -62,6 +66,7 @@ where
                 DetermineBestTransformError::SizeEstimationError(est_err) => {
                     Bc1Error::SizeEstimationFailed(est_err)
                 }
+                DetermineBestTransformError::Cancelled => Bc1Error::Cancelled,
             },
         }
     }
@@ -14,7 +14,16 @@ use dxt_lossless_transform_bc1::{
 /// best for your specific use case.
 ///
 /// For automatic optimization, use [`crate::Bc1AutoTransformBuilder`].
+///
+/// With the `serde` feature, the builder (de)serializes as its settings, i.e.
+/// `{ "decorrelation_mode": "variant1", "split_colour_endpoints": true }`.
+/// Missing fields take their default values.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "ManualTransformSettings", into = "ManualTransformSettings")
+)]
 pub struct Bc1ManualTransformBuilder {
     settings: Bc1TransformSettings,
 }
@@ -154,6 +163,43 @@ impl Default for Bc1ManualTransformBuilder {
     }
 }
 
+/// Serialized form of [`Bc1ManualTransformBuilder`], using the stable [`YCoCgVariant`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ManualTransformSettings {
+    decorrelation_mode: YCoCgVariant,
+    split_colour_endpoints: bool,
+}
+
+#[cfg(feature = "serde")]
+impl Default for ManualTransformSettings {
+    fn default() -> Self {
+        Bc1ManualTransformBuilder::new().into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Bc1ManualTransformBuilder> for ManualTransformSettings {
+    fn from(builder: Bc1ManualTransformBuilder) -> Self {
+        Self {
+            decorrelation_mode: YCoCgVariant::from_internal_variant(
+                builder.settings.decorrelation_mode,
+            ),
+            split_colour_endpoints: builder.settings.split_colour_endpoints,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ManualTransformSettings> for Bc1ManualTransformBuilder {
+    fn from(settings: ManualTransformSettings) -> Self {
+        Self::new()
+            .decorrelation_mode(settings.decorrelation_mode)
+            .split_colour_endpoints(settings.split_colour_endpoints)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,4 +261,34 @@ mod tests {
             "Round-trip transform/untransform should restore original data"
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_manual_transform_builder_serde_roundtrip() {
+        let builder = Bc1ManualTransformBuilder::new()
+            .decorrelation_mode(YCoCgVariant::Variant3)
+            .split_colour_endpoints(false);
+
+        let json = serde_json::to_string(&builder).unwrap();
+        assert_eq!(
+            json,
+            r#"{"decorrelation_mode":"variant3","split_colour_endpoints":false}"#
+        );
+
+        let restored: Bc1ManualTransformBuilder = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.get_settings(), builder.get_settings());
+
+        // Missing fields use the defaults
+        let defaults: Bc1ManualTransformBuilder = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            defaults.get_settings(),
+            Bc1ManualTransformBuilder::new().get_settings()
+        );
+
+        // Misspelled fields are rejected rather than silently using the defaults
+        assert!(
+            serde_json::from_str::<Bc1ManualTransformBuilder>(r#"{"split_color_endpoints":false}"#)
+                .is_err()
+        );
+    }
 }
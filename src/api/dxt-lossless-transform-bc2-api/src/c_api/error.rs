@@ -36,6 +36,10 @@ pub enum Dltbc2ErrorCode {
     NullBuilderPointer = 11,
     /// Null pointer provided for manual builder output parameter
     NullManualBuilderOutputPointer = 12,
+    /// The transform was cancelled by its progress callback
+    Cancelled = 13,
+    /// Null pointer provided for DltAutoTransformWorkspace parameter
+    NullWorkspacePointer = 14,
 }
 
 /// C-compatible Result type for BC2 operations.
@@ -83,6 +87,7 @@ where
             Bc2Error::OutputBufferTooSmall { .. } => Dltbc2ErrorCode::OutputBufferTooSmall,
             Bc2Error::AllocationFailed => Dltbc2ErrorCode::AllocationFailed,
             Bc2Error::SizeEstimationFailed(_) => Dltbc2ErrorCode::SizeEstimationFailed,
+            Bc2Error::Cancelled => Dltbc2ErrorCode::Cancelled,
         };
         Self::from_error_code(error_code)
     }
@@ -115,6 +120,7 @@ where
                     Dltbc2ErrorCode::SizeEstimationFailed
                 }
                 DetermineBestTransformError::AllocateError(_) => Dltbc2ErrorCode::AllocationFailed,
+                DetermineBestTransformError::Cancelled => Dltbc2ErrorCode::Cancelled,
             },
         };
         Self::from_error_code(error_code)
@@ -168,5 +174,12 @@ pub unsafe extern "C" fn dltbc2_error_message(error_code: Dltbc2ErrorCode) -> *c
         Dltbc2ErrorCode::NullManualBuilderOutputPointer => {
             c"Null pointer provided for manual builder output parameter".as_ptr() as *const c_char
         }
+        Dltbc2ErrorCode::Cancelled => {
+            c"Transform was cancelled by its progress callback".as_ptr() as *const c_char
+        }
+        Dltbc2ErrorCode::NullWorkspacePointer => {
+            c"Null pointer provided for DltAutoTransformWorkspace parameter".as_ptr()
+                as *const c_char
+        }
     }
 }
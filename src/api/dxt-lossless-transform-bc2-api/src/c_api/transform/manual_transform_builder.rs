@@ -43,6 +43,15 @@ pub struct Dltbc2ManualTransformBuilder {
     pub(crate) builder: Bc2ManualTransformBuilder,
 }
 
+impl Dltbc2ManualTransformBuilder {
+    /// Returns the Rust builder behind this handle.
+    ///
+    /// Allows C APIs of other crates (e.g. the file formats API) to accept this handle.
+    pub fn builder(&self) -> Bc2ManualTransformBuilder {
+        self.builder
+    }
+}
+
 /// Get mutable access to the manual transform builder.
 ///
 /// # Safety
@@ -2,8 +2,14 @@
 
 use super::YCoCgVariant;
 use crate::{Bc2Error, Bc2ManualTransformBuilder};
-use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
-use dxt_lossless_transform_bc2::{Bc2EstimateSettings, transform_bc2_auto_safe};
+use core::ops::ControlFlow;
+use dxt_lossless_transform_api_common::estimate::{
+    AutoTransformWorkspace, EstimateProgress, ProgressCallback, SizeEstimationOperations,
+};
+use dxt_lossless_transform_bc2::{
+    Bc2EstimateSettings, Bc2TransformSettings, transform_bc2_auto_with_candidates_safe,
+    transform_bc2_auto_with_workspace_and_candidates_safe,
+};
 
 /// Automatic BC2 transform optimization builder.
 ///
@@ -38,6 +44,7 @@ where
             settings: Bc2EstimateSettings {
                 size_estimator: estimator,
                 use_all_decorrelation_modes: false, // Default value
+                progress: None,
             },
         }
     }
@@ -59,6 +66,7 @@ where
             settings: Bc2EstimateSettings {
                 size_estimator: estimator,
                 use_all_decorrelation_modes: true,
+                progress: None,
             },
         }
     }
@@ -77,6 +85,22 @@ where
         self
     }
 
+    /// Set a callback notified after each tested configuration.
+    ///
+    /// The callback receives the number of configurations tested so far, and the total.
+    /// Returning [`ControlFlow::Break`] cancels the transform, making [`Self::transform`]
+    /// return [`Bc2Error::Cancelled`]; the contents of the output buffer are then unspecified.
+    ///
+    /// Slow estimators (e.g. ZStandard on large textures) can take seconds to test every
+    /// configuration, so this is useful for keeping interactive applications responsive.
+    pub fn progress_callback(
+        mut self,
+        callback: impl Fn(EstimateProgress) -> ControlFlow<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.settings.progress = Some(ProgressCallback::new(callback));
+        self
+    }
+
     /// Transform BC2 data with automatically optimized settings and return a builder for untransformation.
     ///
     /// This method determines the best transform settings using the configured estimator,
@@ -125,25 +149,105 @@ where
         input: &[u8],
         output: &mut [u8],
     ) -> Result<Bc2ManualTransformBuilder, Bc2Error<T::Error>>
+    where
+        T::Error: core::fmt::Debug,
+    {
+        self.transform_with_candidates(input, output, |_, _| {})
+    }
+
+    /// Transform BC2 data with automatically optimized settings, reporting every tested candidate.
+    ///
+    /// This is [`Self::transform`], but `on_candidate` is called with a builder describing
+    /// each tested configuration and its estimated compressed size (in bytes, according to
+    /// the configured estimator), in the order they were tested. Useful for telemetry and
+    /// diagnostics.
+    ///
+    /// # Errors
+    /// Returns [`Bc2Error`] if the optimization or transformation fails.
+    pub fn transform_with_candidates(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        mut on_candidate: impl FnMut(&Bc2ManualTransformBuilder, usize),
+    ) -> Result<Bc2ManualTransformBuilder, Bc2Error<T::Error>>
     where
         T::Error: core::fmt::Debug,
     {
         // Use the configured settings directly
-        let optimal_settings = transform_bc2_auto_safe(input, output, &self.settings)
+        let optimal_settings =
+            transform_bc2_auto_with_candidates_safe(input, output, &self.settings, |x, size| {
+                on_candidate(&to_manual_builder(x), size)
+            })
             .map_err(Bc2Error::from_auto_transform_error)?;
 
         // Return a manual builder configured with these optimal settings
-        Ok(Bc2ManualTransformBuilder::new()
-            .decorrelation_mode(YCoCgVariant::from_internal_variant(
-                optimal_settings.decorrelation_mode,
-            ))
-            .split_colour_endpoints(optimal_settings.split_colour_endpoints))
+        Ok(to_manual_builder(optimal_settings))
+    }
+
+    /// Transform Bc2 data with automatically optimized settings, reusing the scratch memory
+    /// held by `workspace`.
+    ///
+    /// This is [`Self::transform`], but the compression buffer used by the estimator is kept
+    /// in the [`AutoTransformWorkspace`] between calls. When transforming many textures,
+    /// reuse the same builder and workspace to avoid allocating for each one.
+    ///
+    /// # Errors
+    /// Returns [`Bc2Error`] if the optimization or transformation fails.
+    pub fn transform_with_workspace(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        workspace: &mut AutoTransformWorkspace,
+    ) -> Result<Bc2ManualTransformBuilder, Bc2Error<T::Error>>
+    where
+        T::Error: core::fmt::Debug,
+    {
+        self.transform_with_workspace_and_candidates(input, output, workspace, |_, _| {})
+    }
+
+    /// Transform BC2 data with automatically optimized settings, reusing the scratch memory
+    /// held by `workspace` and reporting every tested candidate.
+    ///
+    /// This combines [`Self::transform_with_workspace`] and [`Self::transform_with_candidates`].
+    ///
+    /// # Errors
+    /// Returns [`Bc2Error`] if the optimization or transformation fails.
+    pub fn transform_with_workspace_and_candidates(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        workspace: &mut AutoTransformWorkspace,
+        mut on_candidate: impl FnMut(&Bc2ManualTransformBuilder, usize),
+    ) -> Result<Bc2ManualTransformBuilder, Bc2Error<T::Error>>
+    where
+        T::Error: core::fmt::Debug,
+    {
+        let optimal_settings = transform_bc2_auto_with_workspace_and_candidates_safe(
+            input,
+            output,
+            &self.settings,
+            workspace,
+            |x, size| on_candidate(&to_manual_builder(x), size),
+        )
+        .map_err(Bc2Error::from_auto_transform_error)?;
+
+        Ok(to_manual_builder(optimal_settings))
     }
 }
 
+/// Create a manual builder configured with the given (internal) settings.
+fn to_manual_builder(settings: Bc2TransformSettings) -> Bc2ManualTransformBuilder {
+    Bc2ManualTransformBuilder::new()
+        .decorrelation_mode(YCoCgVariant::from_internal_variant(
+            settings.decorrelation_mode,
+        ))
+        .split_colour_endpoints(settings.split_colour_endpoints)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
     use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 
     /// Dummy estimator for testing
@@ -206,4 +310,67 @@ mod tests {
             .use_all_decorrelation_modes(true)
             .use_all_decorrelation_modes(false);
     }
+
+    #[test]
+    fn test_auto_transform_builder_cancelled_by_progress_callback() {
+        let bc2_data = [0u8; 64];
+        let mut transformed = [0u8; 64];
+
+        let result = Bc2AutoTransformBuilder::new(DummyEstimator)
+            .progress_callback(|progress| {
+                assert_eq!(progress.completed, 1);
+                ControlFlow::Break(())
+            })
+            .transform(&bc2_data, &mut transformed);
+
+        assert!(matches!(result, Err(Bc2Error::Cancelled)));
+    }
+
+    #[test]
+    fn test_auto_transform_builder_transform_with_workspace() {
+        let data = [0x11u8; 16 * 4];
+        let builder = Bc2AutoTransformBuilder::new(DummyEstimator);
+        let mut workspace = AutoTransformWorkspace::new();
+
+        let mut expected = [0u8; 16 * 4];
+        builder.transform(&data, &mut expected).unwrap();
+
+        for _ in 0..2 {
+            let mut transformed = [0u8; 16 * 4];
+            let result = builder
+                .transform_with_workspace(&data, &mut transformed, &mut workspace)
+                .unwrap();
+            assert_eq!(transformed, expected);
+
+            let mut restored = [0u8; 16 * 4];
+            result.untransform(&transformed, &mut restored).unwrap();
+            assert_eq!(restored, data);
+        }
+    }
+
+    #[test]
+    fn test_auto_transform_builder_transform_with_workspace_and_candidates() {
+        let data = [0x11u8; 16 * 4];
+        let builder = Bc2AutoTransformBuilder::new(DummyEstimator);
+        let mut workspace = AutoTransformWorkspace::new();
+
+        let mut expected = [0u8; 16 * 4];
+        let mut expected_sizes = Vec::new();
+        builder
+            .transform_with_candidates(&data, &mut expected, |_, size| expected_sizes.push(size))
+            .unwrap();
+
+        let mut transformed = [0u8; 16 * 4];
+        let mut sizes = Vec::new();
+        builder
+            .transform_with_workspace_and_candidates(
+                &data,
+                &mut transformed,
+                &mut workspace,
+                |_, size| sizes.push(size),
+            )
+            .unwrap();
+        assert_eq!(transformed, expected);
+        assert_eq!(sizes, expected_sizes);
+    }
 }
@@ -14,7 +14,16 @@ use dxt_lossless_transform_bc2::{
 /// best for your specific use case.
 ///
 /// For automatic optimization, use [`crate::Bc2AutoTransformBuilder`].
+///
+/// With the `serde` feature, the builder (de)serializes as its settings, i.e.
+/// `{ "decorrelation_mode": "variant1", "split_colour_endpoints": true }`.
+/// Missing fields take their default values.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "ManualTransformSettings", into = "ManualTransformSettings")
+)]
 pub struct Bc2ManualTransformBuilder {
     settings: Bc2TransformSettings,
 }
@@ -154,6 +163,43 @@ impl Default for Bc2ManualTransformBuilder {
     }
 }
 
+/// Serialized form of [`Bc2ManualTransformBuilder`], using the stable [`YCoCgVariant`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ManualTransformSettings {
+    decorrelation_mode: YCoCgVariant,
+    split_colour_endpoints: bool,
+}
+
+#[cfg(feature = "serde")]
+impl Default for ManualTransformSettings {
+    fn default() -> Self {
+        Bc2ManualTransformBuilder::new().into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Bc2ManualTransformBuilder> for ManualTransformSettings {
+    fn from(builder: Bc2ManualTransformBuilder) -> Self {
+        Self {
+            decorrelation_mode: YCoCgVariant::from_internal_variant(
+                builder.settings.decorrelation_mode,
+            ),
+            split_colour_endpoints: builder.settings.split_colour_endpoints,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ManualTransformSettings> for Bc2ManualTransformBuilder {
+    fn from(settings: ManualTransformSettings) -> Self {
+        Self::new()
+            .decorrelation_mode(settings.decorrelation_mode)
+            .split_colour_endpoints(settings.split_colour_endpoints)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,4 +267,34 @@ mod tests {
             "Round-trip transform/untransform should restore original data"
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_manual_transform_builder_serde_roundtrip() {
+        let builder = Bc2ManualTransformBuilder::new()
+            .decorrelation_mode(YCoCgVariant::Variant3)
+            .split_colour_endpoints(false);
+
+        let json = serde_json::to_string(&builder).unwrap();
+        assert_eq!(
+            json,
+            r#"{"decorrelation_mode":"variant3","split_colour_endpoints":false}"#
+        );
+
+        let restored: Bc2ManualTransformBuilder = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.get_settings(), builder.get_settings());
+
+        // Missing fields use the defaults
+        let defaults: Bc2ManualTransformBuilder = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            defaults.get_settings(),
+            Bc2ManualTransformBuilder::new().get_settings()
+        );
+
+        // Misspelled fields are rejected rather than silently using the defaults
+        assert!(
+            serde_json::from_str::<Bc2ManualTransformBuilder>(r#"{"split_color_endpoints":false}"#)
+                .is_err()
+        );
+    }
 }
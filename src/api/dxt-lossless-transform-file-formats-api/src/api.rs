@@ -2,7 +2,10 @@
 
 use crate::bundle::TransformBundle;
 use crate::error::{FormatHandlerError, TransformError, TransformResult};
-use crate::handlers::{FileFormatDetection, FileFormatHandler, FileFormatUntransformDetection};
+use crate::handlers::{
+    verify_roundtrip, FileFormatDetection, FileFormatHandler, FileFormatUntransformDetection,
+};
+use crate::report::{Stopwatch, TransformReport};
 use core::fmt::Debug;
 use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 
@@ -17,9 +20,12 @@ use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 ///
 /// - `handler`: The file format handler (e.g., DdsHandler)
 /// - `input`: Input buffer containing the file data
-/// - `output`: Output buffer (must be at least the same size as input)
+/// - `output`: Output buffer (must be at least [`FileFormatHandler::transformed_size`] bytes)
 /// - `bundle`: Bundle containing transform builders for different BCx formats
 ///
+/// If [`TransformBundle::with_verify`] is enabled, the output is untransformed again and
+/// compared against the input; see [`TransformError::RoundtripMismatch`].
+///
 /// # Example
 ///
 /// ```
@@ -45,16 +51,92 @@ where
     T: SizeEstimationOperations,
     T::Error: Debug,
 {
-    if output.len() < input.len() {
+    transform_slice_reporting(
+        handler,
+        input,
+        output,
+        bundle,
+        &mut TransformReport::default(),
+    )
+}
+
+/// Transform a slice using the specified format handler and transform bundle,
+/// returning a report describing the transform.
+///
+/// Same as [`transform_slice_with_bundle`], but returns a [`TransformReport`] with the detected
+/// format, the chosen settings, the candidates tested by automatic builders (with their
+/// estimated sizes), the number of bytes processed and the time taken.
+///
+/// Handlers which do not override [`FileFormatHandler::transform_bundle_with_report`] only
+/// report the number of bytes processed and the time taken.
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::{TransformBundle, transform_slice_with_report};
+/// use dxt_lossless_transform_api_common::estimate::NoEstimation;
+/// use dxt_lossless_transform_dds::DdsHandler;
+/// use dxt_lossless_transform_file_formats_api::TransformResult;
+///
+/// fn example_transform(input: &[u8]) -> TransformResult<Vec<u8>> {
+///     let bundle = TransformBundle::<NoEstimation>::default_all();
+///     let mut output = vec![0u8; input.len()];
+///     let report = transform_slice_with_report(&DdsHandler, input, &mut output, &bundle)?;
+///     println!("{:?} transformed with {:?}", report.format, report.details);
+///     Ok(output)
+/// }
+/// ```
+pub fn transform_slice_with_report<H: FileFormatHandler, T>(
+    handler: &H,
+    input: &[u8],
+    output: &mut [u8],
+    bundle: &TransformBundle<T>,
+) -> TransformResult<TransformReport>
+where
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    let stopwatch = Stopwatch::start();
+    let mut report = TransformReport::default();
+    transform_slice_reporting(handler, input, output, bundle, &mut report)?;
+    report.elapsed = stopwatch.elapsed();
+    Ok(report)
+}
+
+fn transform_slice_reporting<H: FileFormatHandler, T>(
+    handler: &H,
+    input: &[u8],
+    output: &mut [u8],
+    bundle: &TransformBundle<T>,
+    report: &mut TransformReport,
+) -> TransformResult<()>
+where
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    let required = handler.transformed_size(input);
+    if output.len() < required {
         return Err(TransformError::FormatHandler(
             FormatHandlerError::OutputBufferTooSmall {
-                required: input.len(),
+                required,
                 actual: output.len(),
             },
         ));
     }
 
-    handler.transform_bundle(input, output, bundle)
+    handler.transform_bundle_with_report(input, output, bundle, report)?;
+    report.bytes_processed = input.len();
+    if bundle.verify() {
+        let transformed = &output[..required];
+        verify_roundtrip(
+            input,
+            transformed,
+            handler.untransformed_size(transformed),
+            |input, output| handler.untransform(input, output),
+        )?;
+    }
+
+    Ok(())
 }
 
 /// Untransform a slice using the specified format handler.
@@ -68,7 +150,7 @@ where
 ///
 /// - `handler`: The file format handler (e.g., DdsHandler)
 /// - `input`: Input buffer containing transformed data
-/// - `output`: Output buffer (must be at least the same size as input)
+/// - `output`: Output buffer (must be at least [`FileFormatHandler::untransformed_size`] bytes)
 ///
 /// # Example
 ///
@@ -88,10 +170,11 @@ pub fn untransform_slice<H: FileFormatHandler>(
     input: &[u8],
     output: &mut [u8],
 ) -> TransformResult<()> {
-    if output.len() < input.len() {
+    let required = handler.untransformed_size(input);
+    if output.len() < required {
         return Err(TransformError::FormatHandler(
             FormatHandlerError::OutputBufferTooSmall {
-                required: input.len(),
+                required,
                 actual: output.len(),
             },
         ));
@@ -109,7 +192,7 @@ pub fn untransform_slice<H: FileFormatHandler>(
 ///
 /// - `handlers`: Iterator of file format handlers that implement [`FileFormatDetection`]
 /// - `input`: Input buffer containing the file data
-/// - `output`: Output buffer (must be at least the same size as input)
+/// - `output`: Output buffer (must be at least [`FileFormatHandler::transformed_size`] bytes)
 /// - `bundle`: Bundle containing transform builders for different BCx formats
 ///
 /// # Returns
@@ -143,19 +226,10 @@ where
     SizeEstimator: SizeEstimationOperations,
     SizeEstimator::Error: Debug,
 {
-    if output.len() < input.len() {
-        return Err(TransformError::FormatHandler(
-            FormatHandlerError::OutputBufferTooSmall {
-                required: input.len(),
-                actual: output.len(),
-            },
-        ));
-    }
-
     // Try each handler until one accepts the file
     for handler in handlers {
         if handler.can_handle(input, None) {
-            handler.transform_bundle(input, output, bundle)?;
+            transform_slice_with_bundle(&handler, input, output, bundle)?;
             return Ok(handler);
         }
     }
@@ -173,7 +247,7 @@ where
 ///
 /// - `handlers`: Iterator of file format handlers that implement [`FileFormatUntransformDetection`]
 /// - `input`: Input buffer containing transformed data
-/// - `output`: Output buffer (must be at least the same size as input)
+/// - `output`: Output buffer (must be at least [`FileFormatHandler::untransformed_size`] bytes)
 ///
 /// # Returns
 ///
@@ -201,19 +275,179 @@ where
     HandlerIterator: IntoIterator<Item = Handler>,
     Handler: FileFormatUntransformDetection,
 {
-    if output.len() < input.len() {
-        return Err(TransformError::FormatHandler(
-            FormatHandlerError::OutputBufferTooSmall {
-                required: input.len(),
-                actual: output.len(),
-            },
-        ));
+    // Try each handler until one accepts the file
+    for handler in handlers {
+        if handler.can_handle_untransform(input, None) {
+            untransform_slice(&handler, input, output)?;
+            return Ok(handler);
+        }
     }
 
+    // No handler could process the file
+    Err(TransformError::NoSupportedHandler)
+}
+
+/// Transform a slice in place using the specified format handler and transform bundle.
+///
+/// Same as [`transform_slice_with_bundle`], but without a separate output buffer; useful for
+/// large files where a second copy of the data would double peak memory usage.
+/// Handlers may still use a scratch buffer sized to the texture data, see
+/// [`FileFormatHandler::transform_bundle_in_place`].
+///
+/// # Parameters
+///
+/// - `handler`: The file format handler (e.g., DdsHandler)
+/// - `data`: Buffer containing the file data, replaced with the transformed data on success.
+///   If an error is returned (including a failed verification), `data` is left unchanged.
+/// - `bundle`: Bundle containing transform builders for different BCx formats
+///
+/// # Returns
+///
+/// Ok(()) on success, or [`FormatHandlerError::InPlaceSizeMismatch`] if the handler changes the
+/// size of the data (e.g. [`RawBlocksHandler`]), in addition to the errors of
+/// [`transform_slice_with_bundle`].
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::{TransformBundle, transform_slice_in_place};
+/// use dxt_lossless_transform_api_common::estimate::NoEstimation;
+/// use dxt_lossless_transform_dds::DdsHandler;
+/// use dxt_lossless_transform_file_formats_api::TransformResult;
+///
+/// fn example_transform_in_place(data: &mut [u8]) -> TransformResult<()> {
+///     let bundle = TransformBundle::<NoEstimation>::default_all();
+///     transform_slice_in_place(&DdsHandler, data, &bundle)
+/// }
+/// ```
+///
+/// [`RawBlocksHandler`]: crate::raw::RawBlocksHandler
+pub fn transform_slice_in_place<H: FileFormatHandler, T>(
+    handler: &H,
+    data: &mut [u8],
+    bundle: &TransformBundle<T>,
+) -> TransformResult<()>
+where
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    let original = bundle.verify().then(|| data.to_vec());
+    handler.transform_bundle_in_place(data, bundle)?;
+    if let Some(original) = original {
+        let result = verify_roundtrip(
+            &original,
+            data,
+            handler.untransformed_size(data),
+            |input, output| handler.untransform(input, output),
+        );
+
+        // Don't leave data the caller can't restore behind
+        if result.is_err() {
+            data.copy_from_slice(&original);
+        }
+        result?;
+    }
+
+    Ok(())
+}
+
+/// Untransform a slice in place using the specified format handler.
+///
+/// Same as [`untransform_slice`], but without a separate output buffer.
+/// Handlers may still use a scratch buffer sized to the texture data, see
+/// [`FileFormatHandler::untransform_in_place`].
+///
+/// # Parameters
+///
+/// - `handler`: The file format handler (e.g., DdsHandler)
+/// - `data`: Buffer containing transformed data, replaced with the original data on success.
+///   If an error is returned, `data` is left unchanged.
+///
+/// # Returns
+///
+/// Ok(()) on success, or [`FormatHandlerError::InPlaceSizeMismatch`] if the handler changes the
+/// size of the data, in addition to the errors of [`untransform_slice`].
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::untransform_slice_in_place;
+/// use dxt_lossless_transform_dds::DdsHandler;
+/// use dxt_lossless_transform_file_formats_api::TransformResult;
+///
+/// fn example_untransform_in_place(data: &mut [u8]) -> TransformResult<()> {
+///     untransform_slice_in_place(&DdsHandler, data)
+/// }
+/// ```
+pub fn untransform_slice_in_place<H: FileFormatHandler>(
+    handler: &H,
+    data: &mut [u8],
+) -> TransformResult<()> {
+    handler.untransform_in_place(data)
+}
+
+/// Transform a slice in place using multiple handlers with automatic format detection.
+///
+/// In-place counterpart of [`transform_slice_with_multiple_handlers`].
+///
+/// # Parameters
+///
+/// - `handlers`: Iterator of file format handlers that implement [`FileFormatDetection`]
+/// - `data`: Buffer containing the file data, replaced with the transformed data on success.
+///   If an error is returned (including a failed verification), `data` is left unchanged.
+/// - `bundle`: Bundle containing transform builders for different BCx formats
+///
+/// # Returns
+///
+/// Result containing the handler that was used, or [`TransformError::NoSupportedHandler`] if no handler can process the data.
+pub fn transform_slice_in_place_with_multiple_handlers<HandlerIterator, Handler, SizeEstimator>(
+    handlers: HandlerIterator,
+    data: &mut [u8],
+    bundle: &TransformBundle<SizeEstimator>,
+) -> TransformResult<Handler>
+where
+    HandlerIterator: IntoIterator<Item = Handler>,
+    Handler: FileFormatDetection,
+    SizeEstimator: SizeEstimationOperations,
+    SizeEstimator::Error: Debug,
+{
     // Try each handler until one accepts the file
     for handler in handlers {
-        if handler.can_handle_untransform(input, None) {
-            handler.untransform(input, output)?;
+        if handler.can_handle(data, None) {
+            transform_slice_in_place(&handler, data, bundle)?;
+            return Ok(handler);
+        }
+    }
+
+    // No handler could process the file
+    Err(TransformError::NoSupportedHandler)
+}
+
+/// Untransform a slice in place using multiple handlers with automatic format detection.
+///
+/// In-place counterpart of [`untransform_slice_with_multiple_handlers`].
+///
+/// # Parameters
+///
+/// - `handlers`: Iterator of file format handlers that implement [`FileFormatUntransformDetection`]
+/// - `data`: Buffer containing transformed data, replaced with the original data on success.
+///   If an error is returned, `data` is left unchanged.
+///
+/// # Returns
+///
+/// Result containing the handler that was used, or [`TransformError::NoSupportedHandler`] if no handler can process the data.
+pub fn untransform_slice_in_place_with_multiple_handlers<HandlerIterator, Handler>(
+    handlers: HandlerIterator,
+    data: &mut [u8],
+) -> TransformResult<Handler>
+where
+    HandlerIterator: IntoIterator<Item = Handler>,
+    Handler: FileFormatUntransformDetection,
+{
+    // Try each handler until one accepts the file
+    for handler in handlers {
+        if handler.can_handle_untransform(data, None) {
+            untransform_slice_in_place(&handler, data)?;
             return Ok(handler);
         }
     }
@@ -225,9 +459,14 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::embed::{TransformDetails, TransformFormat};
+    use crate::raw::RawBlocksHandler;
     use crate::test_prelude::*;
     use alloc::vec;
     use dxt_lossless_transform_api_common::estimate::NoEstimation;
+    use dxt_lossless_transform_bc1::Bc1TransformSettings;
+    use dxt_lossless_transform_bc1_api::Bc1AutoTransformBuilder;
+    use dxt_lossless_transform_ltu::LosslessTransformUtilsSizeEstimation;
 
     #[test]
     fn test_transform_slice_with_bundle() {
@@ -379,4 +618,170 @@ mod tests {
         assert_eq!(calls.can_handle_untransform_calls.len(), 1);
         assert!(!calls.untransform_called);
     }
+
+    #[test]
+    fn test_transform_slice_in_place() {
+        let handler = MockHandler::new_extensionless_accepting();
+        let mut data = create_test_data(64);
+        let expected = data.clone();
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        transform_slice_in_place(&handler, &mut data, &bundle).unwrap();
+        assert!(handler.get_calls().transform_bundle_called);
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn test_untransform_slice_in_place() {
+        let handler = MockHandler::new_extensionless_accepting();
+        let mut data = create_test_data(64);
+
+        untransform_slice_in_place(&handler, &mut data).unwrap();
+        assert!(handler.get_calls().untransform_called);
+    }
+
+    #[test]
+    fn test_transform_slice_in_place_rejects_size_change() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let mut data = create_test_bc1_data(4);
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let result = transform_slice_in_place(&handler, &mut data, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::InPlaceSizeMismatch { actual: 32, .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_transform_slice_in_place_with_multiple_handlers_tries_all_handlers() {
+        let handler1 = MockHandler::new_rejecting();
+        let handler2 = MockHandler::new_extensionless_accepting();
+        let mut data = create_test_data(64);
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let result = transform_slice_in_place_with_multiple_handlers(
+            [handler1.clone(), handler2.clone()],
+            &mut data,
+            &bundle,
+        );
+        assert!(result.is_ok());
+        assert!(!handler1.get_calls().transform_bundle_called);
+        assert!(handler2.get_calls().transform_bundle_called);
+    }
+
+    #[test]
+    fn test_transform_slice_with_verify_accepts_lossless_transform() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let input = create_test_bc1_data(16);
+        let mut output = vec![0u8; handler.transformed_size(&input)];
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_verify(true);
+
+        transform_slice_with_bundle(&handler, &input, &mut output, &bundle).unwrap();
+    }
+
+    #[test]
+    fn test_transform_slice_with_verify_detects_mismatch() {
+        let handler = LossyHandler { corrupt_offset: 17 };
+        let input = create_test_data(64);
+        let mut output = vec![0u8; 64];
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        // Not verified by default
+        transform_slice_with_bundle(&handler, &input, &mut output, &bundle).unwrap();
+
+        let bundle = bundle.with_verify(true);
+        let result = transform_slice_with_bundle(&handler, &input, &mut output, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::RoundtripMismatch { offset: 17 })
+        ));
+    }
+
+    #[test]
+    fn test_transform_slice_in_place_with_verify_detects_mismatch() {
+        let handler = LossyHandler { corrupt_offset: 3 };
+        let mut data = create_test_data(64);
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_verify(true);
+
+        let result = transform_slice_in_place(&handler, &mut data, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::RoundtripMismatch { offset: 3 })
+        ));
+        assert_eq!(data, create_test_data(64));
+    }
+
+    #[test]
+    fn test_transform_slice_in_place_leaves_data_unchanged_on_estimator_error() {
+        let data: alloc::vec::Vec<u8> = (0..8 * 64).map(|x| (x * 7) as u8).collect();
+        let bundle =
+            TransformBundle::new().with_bc1_auto(Bc1AutoTransformBuilder::new(FailingEstimator));
+
+        let mut in_place = data.clone();
+        let result = transform_slice_in_place(&Bc1BlocksHandler, &mut in_place, &bundle);
+        assert!(result.is_err());
+        assert_eq!(in_place, data);
+    }
+
+    #[test]
+    fn test_untransform_slice_in_place_with_multiple_handlers_no_accepting_handler() {
+        let handler = MockHandler::new_rejecting();
+        let mut data = create_test_data(64);
+
+        let result =
+            untransform_slice_in_place_with_multiple_handlers([handler.clone()], &mut data);
+        assert!(matches!(result, Err(TransformError::NoSupportedHandler)));
+        assert!(!handler.get_calls().untransform_called);
+    }
+
+    #[test]
+    fn test_transform_slice_with_report_lists_auto_candidates() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let input = create_test_bc1_data(16);
+        let mut output = vec![0u8; handler.transformed_size(&input)];
+        let bundle = TransformBundle::new().with_bc1_auto(Bc1AutoTransformBuilder::new(
+            LosslessTransformUtilsSizeEstimation::new(),
+        ));
+
+        let report = transform_slice_with_report(&handler, &input, &mut output, &bundle).unwrap();
+        assert_eq!(report.format, Some(TransformFormat::Bc1));
+        assert!(matches!(report.details, Some(TransformDetails::Bc1(_))));
+        assert_eq!(report.candidates.len(), 4);
+        assert!(report.estimated_size().is_some());
+        assert_eq!(report.bytes_processed, input.len());
+        assert_eq!(report.elapsed.is_some(), cfg!(feature = "std"));
+    }
+
+    #[test]
+    fn test_transform_slice_with_report_has_no_candidates_for_manual_settings() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let input = create_test_bc1_data(16);
+        let mut output = vec![0u8; handler.transformed_size(&input)];
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let report = transform_slice_with_report(&handler, &input, &mut output, &bundle).unwrap();
+        assert_eq!(
+            report.details,
+            Some(TransformDetails::Bc1(Bc1TransformSettings::default()))
+        );
+        assert!(report.candidates.is_empty());
+        assert_eq!(report.estimated_size(), None);
+    }
+
+    #[test]
+    fn test_transform_slice_with_report_without_handler_support() {
+        let handler = MockHandler::new_extensionless_accepting();
+        let input = create_test_data(64);
+        let mut output = vec![0u8; 64];
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let report = transform_slice_with_report(&handler, &input, &mut output, &bundle).unwrap();
+        assert!(handler.get_calls().transform_bundle_called);
+        assert_eq!(report.format, None);
+        assert_eq!(report.details, None);
+        assert_eq!(report.bytes_processed, 64);
+    }
 }
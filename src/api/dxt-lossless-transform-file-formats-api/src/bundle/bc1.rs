@@ -37,6 +37,8 @@ where
     /// # Parameters
     /// - `input`: Input texture data to transform
     /// - `output`: Output buffer for transformed data (must be at least the same size as input)
+    /// - `on_candidate`: Called with each configuration tested by automatic builders,
+    ///   and its estimated compressed size
     ///
     /// # Returns
     /// The transform settings that were used, which can be embedded in the file header.
@@ -44,6 +46,7 @@ where
         &self,
         input: &[u8],
         output: &mut [u8],
+        mut on_candidate: impl FnMut(Bc1TransformSettings, usize),
     ) -> Result<Bc1TransformSettings, TransformError> {
         match self {
             Bc1Builder::Manual(builder) => {
@@ -53,18 +56,25 @@ where
                 Ok(settings)
             }
             Bc1Builder::Auto(builder) => {
-                let settings = builder.transform(input, output).map_err(|e| match e {
-                    Bc1Error::InvalidLength(len) => {
-                        TransformError::Bc1(Bc1Error::InvalidLength(len))
-                    }
-                    Bc1Error::OutputBufferTooSmall { needed, actual } => {
-                        TransformError::Bc1(Bc1Error::OutputBufferTooSmall { needed, actual })
-                    }
-                    Bc1Error::AllocationFailed => TransformError::Bc1(Bc1Error::AllocationFailed),
-                    Bc1Error::SizeEstimationFailed(err) => TransformError::Bc1(
-                        Bc1Error::SizeEstimationFailed(alloc::format!("{err:?}")),
-                    ),
-                })?; // This mapping is a bit nasty but forced by the generic on Bc1Error deep down.
+                let settings = builder
+                    .transform_with_candidates(input, output, |x, size| {
+                        on_candidate(x.get_settings(), size)
+                    })
+                    .map_err(|e| match e {
+                        Bc1Error::InvalidLength(len) => {
+                            TransformError::Bc1(Bc1Error::InvalidLength(len))
+                        }
+                        Bc1Error::OutputBufferTooSmall { needed, actual } => {
+                            TransformError::Bc1(Bc1Error::OutputBufferTooSmall { needed, actual })
+                        }
+                        Bc1Error::AllocationFailed => {
+                            TransformError::Bc1(Bc1Error::AllocationFailed)
+                        }
+                        Bc1Error::SizeEstimationFailed(err) => TransformError::Bc1(
+                            Bc1Error::SizeEstimationFailed(alloc::format!("{err:?}")),
+                        ),
+                        Bc1Error::Cancelled => TransformError::Bc1(Bc1Error::Cancelled),
+                    })?; // This mapping is a bit nasty but forced by the generic on Bc1Error deep down.
                 Ok(settings.get_settings())
             }
         }
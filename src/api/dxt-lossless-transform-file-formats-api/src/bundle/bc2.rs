@@ -37,6 +37,8 @@ where
     /// # Parameters
     /// - `input`: Input texture data to transform
     /// - `output`: Output buffer for transformed data (must be at least the same size as input)
+    /// - `on_candidate`: Called with each configuration tested by automatic builders,
+    ///   and its estimated compressed size
     ///
     /// # Returns
     /// The transform settings that were used, which can be embedded in the file header.
@@ -44,6 +46,7 @@ where
         &self,
         input: &[u8],
         output: &mut [u8],
+        mut on_candidate: impl FnMut(Bc2TransformSettings, usize),
     ) -> Result<Bc2TransformSettings, TransformError> {
         match self {
             Bc2Builder::Manual(builder) => {
@@ -53,18 +56,25 @@ where
                 Ok(settings)
             }
             Bc2Builder::Auto(builder) => {
-                let settings = builder.transform(input, output).map_err(|e| match e {
-                    Bc2Error::InvalidLength(len) => {
-                        TransformError::Bc2(Bc2Error::InvalidLength(len))
-                    }
-                    Bc2Error::OutputBufferTooSmall { needed, actual } => {
-                        TransformError::Bc2(Bc2Error::OutputBufferTooSmall { needed, actual })
-                    }
-                    Bc2Error::AllocationFailed => TransformError::Bc2(Bc2Error::AllocationFailed),
-                    Bc2Error::SizeEstimationFailed(err) => TransformError::Bc2(
-                        Bc2Error::SizeEstimationFailed(alloc::format!("{err:?}")),
-                    ),
-                })?;
+                let settings = builder
+                    .transform_with_candidates(input, output, |x, size| {
+                        on_candidate(x.get_settings(), size)
+                    })
+                    .map_err(|e| match e {
+                        Bc2Error::InvalidLength(len) => {
+                            TransformError::Bc2(Bc2Error::InvalidLength(len))
+                        }
+                        Bc2Error::OutputBufferTooSmall { needed, actual } => {
+                            TransformError::Bc2(Bc2Error::OutputBufferTooSmall { needed, actual })
+                        }
+                        Bc2Error::AllocationFailed => {
+                            TransformError::Bc2(Bc2Error::AllocationFailed)
+                        }
+                        Bc2Error::SizeEstimationFailed(err) => TransformError::Bc2(
+                            Bc2Error::SizeEstimationFailed(alloc::format!("{err:?}")),
+                        ),
+                        Bc2Error::Cancelled => TransformError::Bc2(Bc2Error::Cancelled),
+                    })?;
                 Ok(settings.get_settings())
             }
         }
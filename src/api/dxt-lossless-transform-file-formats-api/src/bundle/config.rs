@@ -0,0 +1,259 @@
+//! Serializable transform configuration, for loading [`TransformBundle`]s from TOML/JSON etc.
+
+use super::{PresetEstimator, PresetEstimatorError, TransformBundle};
+use dxt_lossless_transform_bc1_api::{Bc1AutoTransformBuilder, Bc1ManualTransformBuilder};
+use dxt_lossless_transform_bc2_api::{Bc2AutoTransformBuilder, Bc2ManualTransformBuilder};
+use dxt_lossless_transform_ltu::LosslessTransformUtilsSizeEstimation;
+use dxt_lossless_transform_zstd::ZStandardSizeEstimation;
+use serde::{Deserialize, Serialize};
+
+/// Serializable description of a [`TransformBundle`].
+///
+/// Every field is optional when deserializing; missing fields take the values of
+/// [`TransformBundleConfig::default`], which matches [`TransformPreset::Optimal`].
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::bundle::TransformBundleConfig;
+///
+/// let config: TransformBundleConfig = serde_json::from_str(r#"{
+///     "estimator": { "type": "zstd", "level": 3 },
+///     "bc1": { "mode": "manual", "decorrelation_mode": "variant1", "split_colour_endpoints": true },
+///     "bc2": { "mode": "auto", "ultra": true },
+///     "passthrough_unsupported": true
+/// }"#).unwrap();
+///
+/// let bundle = config.bundle().unwrap();
+/// ```
+///
+/// [`TransformPreset::Optimal`]: super::TransformPreset::Optimal
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TransformBundleConfig {
+    /// Size estimator used by formats in [`FormatConfig::Auto`] mode
+    pub estimator: EstimatorConfig,
+    /// BC1 transform settings
+    pub bc1: FormatConfig<Bc1ManualTransformBuilder>,
+    /// BC2 transform settings
+    pub bc2: FormatConfig<Bc2ManualTransformBuilder>,
+    /// See [`TransformBundle::with_passthrough_unsupported`]
+    pub passthrough_unsupported: bool,
+    /// See [`TransformBundle::with_verify`]
+    pub verify: bool,
+    /// See [`TransformBundle::with_checksum`]
+    pub checksum: bool,
+    /// See [`TransformBundle::with_allow_truncated`]
+    pub allow_truncated: bool,
+}
+
+impl TransformBundleConfig {
+    /// Create the [`TransformBundle`] described by this configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PresetEstimatorError`] if the estimator could not be created,
+    /// e.g. because of an invalid ZStandard compression level.
+    pub fn bundle(&self) -> Result<TransformBundle<PresetEstimator>, PresetEstimatorError> {
+        let mut bundle = TransformBundle::new()
+            .with_passthrough_unsupported(self.passthrough_unsupported)
+            .with_verify(self.verify)
+            .with_checksum(self.checksum)
+            .with_allow_truncated(self.allow_truncated);
+
+        bundle = match self.bc1 {
+            FormatConfig::Disabled => bundle,
+            FormatConfig::Manual(builder) => bundle.with_bc1_manual(builder),
+            FormatConfig::Auto { ultra: false } => {
+                bundle.with_bc1_auto(Bc1AutoTransformBuilder::new(self.estimator.create()?))
+            }
+            FormatConfig::Auto { ultra: true } => {
+                bundle.with_bc1_auto(Bc1AutoTransformBuilder::new_ultra(self.estimator.create()?))
+            }
+        };
+
+        bundle = match self.bc2 {
+            FormatConfig::Disabled => bundle,
+            FormatConfig::Manual(builder) => bundle.with_bc2_manual(builder),
+            FormatConfig::Auto { ultra: false } => {
+                bundle.with_bc2_auto(Bc2AutoTransformBuilder::new(self.estimator.create()?))
+            }
+            FormatConfig::Auto { ultra: true } => {
+                bundle.with_bc2_auto(Bc2AutoTransformBuilder::new_ultra(self.estimator.create()?))
+            }
+        };
+
+        Ok(bundle)
+    }
+}
+
+/// How a single format is transformed, selected by the `mode` field.
+///
+/// `M` is the format's manual transform builder, whose settings are stored inline
+/// when using [`FormatConfig::Manual`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum FormatConfig<M> {
+    /// No builder; the format is rejected, or stored unchanged with
+    /// [`TransformBundleConfig::passthrough_unsupported`].
+    Disabled,
+    /// Transform with fixed settings.
+    Manual(M),
+    /// Pick the best settings using the configured [`EstimatorConfig`].
+    Auto {
+        /// Test every setting combination, see e.g. [`Bc1AutoTransformBuilder::new_ultra`].
+        #[serde(default)]
+        ultra: bool,
+    },
+}
+
+impl<M> Default for FormatConfig<M> {
+    fn default() -> Self {
+        Self::Auto { ultra: false }
+    }
+}
+
+/// Size estimator selection, selected by the `type` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EstimatorConfig {
+    /// Lossless-transform-utils estimator, see [`LosslessTransformUtilsSizeEstimation`].
+    Ltu,
+    /// ZStandard estimator, see [`ZStandardSizeEstimation`].
+    Zstd {
+        /// ZStandard compression level; defaults to 1.
+        #[serde(default = "default_zstd_level")]
+        level: i32,
+    },
+}
+
+impl Default for EstimatorConfig {
+    fn default() -> Self {
+        Self::Zstd {
+            level: default_zstd_level(),
+        }
+    }
+}
+
+impl EstimatorConfig {
+    fn create(self) -> Result<PresetEstimator, PresetEstimatorError> {
+        Ok(match self {
+            Self::Ltu => PresetEstimator::Ltu(LosslessTransformUtilsSizeEstimation::new()),
+            Self::Zstd { level } => {
+                PresetEstimator::ZStandard(ZStandardSizeEstimation::new(level)?)
+            }
+        })
+    }
+}
+
+fn default_zstd_level() -> i32 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{transform_slice_with_bundle, untransform_slice};
+    use crate::embed::TransformFormat;
+    use crate::handlers::FileFormatHandler;
+    use crate::raw::RawBlocksHandler;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use dxt_lossless_transform_api_common::reexports::color_565::YCoCgVariant;
+    use dxt_lossless_transform_zstd::ZStandardError;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::defaults("{}")]
+    #[case::ltu_auto(r#"{ "estimator": { "type": "ltu" } }"#)]
+    #[case::zstd_ultra(
+        r#"{ "estimator": { "type": "zstd", "level": 3 }, "bc1": { "mode": "auto", "ultra": true }, "bc2": { "mode": "auto", "ultra": true } }"#
+    )]
+    #[case::manual(
+        r#"{ "bc1": { "mode": "manual", "decorrelation_mode": "variant2" }, "bc2": { "mode": "manual", "split_colour_endpoints": false } }"#
+    )]
+    fn config_bundles_roundtrip_every_supported_format(#[case] json: &str) {
+        let config: TransformBundleConfig = serde_json::from_str(json).unwrap();
+        let bundle = config.bundle().unwrap();
+        let input: Vec<u8> = (0..16 * 64).map(|x| (x * 7 % 251) as u8).collect();
+
+        for format in [TransformFormat::Bc1, TransformFormat::Bc2] {
+            let handler = RawBlocksHandler::new(format);
+            let mut transformed = vec![0u8; handler.transformed_size(&input)];
+            transform_slice_with_bundle(&handler, &input, &mut transformed, &bundle).unwrap();
+
+            let mut restored = vec![0u8; input.len()];
+            untransform_slice(&handler, &transformed, &mut restored).unwrap();
+            assert_eq!(restored, input);
+        }
+    }
+
+    #[test]
+    fn config_applies_manual_settings_and_policies() {
+        let config: TransformBundleConfig = serde_json::from_str(
+            r#"{
+                "bc1": { "mode": "manual", "decorrelation_mode": "variant3", "split_colour_endpoints": false },
+                "bc2": { "mode": "disabled" },
+                "passthrough_unsupported": true,
+                "checksum": true
+            }"#,
+        )
+        .unwrap();
+
+        let FormatConfig::Manual(bc1) = config.bc1 else {
+            panic!("expected manual BC1 config, got {:?}", config.bc1);
+        };
+        let expected = Bc1ManualTransformBuilder::new()
+            .decorrelation_mode(YCoCgVariant::Variant3)
+            .split_colour_endpoints(false);
+        assert_eq!(bc1.get_settings(), expected.get_settings());
+        assert!(matches!(config.bc2, FormatConfig::Disabled));
+
+        let bundle = config.bundle().unwrap();
+        assert!(bundle.passthrough_unsupported());
+        assert!(bundle.checksum());
+        assert!(!bundle.verify());
+    }
+
+    #[test]
+    fn config_survives_serialization() {
+        let config = TransformBundleConfig {
+            estimator: EstimatorConfig::Ltu,
+            bc1: FormatConfig::Manual(Bc1ManualTransformBuilder::new()),
+            bc2: FormatConfig::Auto { ultra: true },
+            allow_truncated: true,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        let restored: TransformBundleConfig = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.estimator, EstimatorConfig::Ltu);
+        assert!(matches!(restored.bc1, FormatConfig::Manual(_)));
+        assert!(matches!(restored.bc2, FormatConfig::Auto { ultra: true }));
+        assert!(restored.allow_truncated);
+    }
+
+    #[rstest]
+    #[case::bundle(r#"{ "bc3": {} }"#)]
+    #[case::manual_settings(r#"{ "bc1": { "mode": "manual", "split_color_endpoints": false } }"#)]
+    fn config_rejects_unknown_fields(#[case] json: &str) {
+        let result = serde_json::from_str::<TransformBundleConfig>(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bundle_fails_for_invalid_zstd_level() {
+        let config = TransformBundleConfig {
+            estimator: EstimatorConfig::Zstd { level: 1000 },
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            config.bundle(),
+            Err(PresetEstimatorError::ZStandard(
+                ZStandardError::InvalidLevel(1000)
+            ))
+        ));
+    }
+}
@@ -7,8 +7,9 @@ use dxt_lossless_transform_api_common::estimate::{NoEstimation, SizeEstimationOp
 use dxt_lossless_transform_bc1_api::Bc1ManualTransformBuilder;
 use dxt_lossless_transform_bc2_api::Bc2ManualTransformBuilder;
 
-use crate::embed::{TransformFormat, TransformHeader};
+use crate::embed::{TransformDetails, TransformFormat, TransformHeader};
 use crate::error::{FormatHandlerError, TransformError, TransformResult};
+use crate::report::{TransformCandidate, TransformReport};
 
 // Re-export BC1 and BC2 builders (used externally)
 use bc1::Bc1Builder;
@@ -23,6 +24,15 @@ mod bc1; // BC1 module stays lic (Bc1Builder is used externally)
 mod bc2; // BC2-7 modules are private (builders not used externally)
 mod bc3;
 mod bc7;
+#[cfg(feature = "serde")]
+mod config;
+#[cfg(feature = "presets")]
+mod preset;
+
+#[cfg(feature = "serde")]
+pub use config::{EstimatorConfig, FormatConfig, TransformBundleConfig};
+#[cfg(feature = "presets")]
+pub use preset::{ParsePresetError, PresetEstimator, PresetEstimatorError, TransformPreset};
 
 /// Bundle of transform builders for different BCx formats.
 ///
@@ -46,6 +56,14 @@ where
     bc3: PhantomData<Bc3TransformBuilder>,
     /// BC7 transform builder (placeholder for future implementation)
     bc7: PhantomData<Bc7TransformBuilder>,
+    /// Whether formats without a builder are stored unchanged rather than rejected
+    passthrough_unsupported: bool,
+    /// Whether transformed data is untransformed again and compared against the input
+    verify: bool,
+    /// Whether a checksum of the original data is stored where the file format allows
+    checksum: bool,
+    /// Whether files shorter than their headers state are transformed rather than rejected
+    allow_truncated: bool,
 }
 
 impl<T> Default for TransformBundle<T>
@@ -58,6 +76,10 @@ where
             bc2: None,
             bc3: PhantomData,
             bc7: PhantomData,
+            passthrough_unsupported: false,
+            verify: false,
+            checksum: false,
+            allow_truncated: false,
         }
     }
 }
@@ -110,6 +132,99 @@ where
         self
     }
 
+    /// Set whether formats that cannot be transformed are stored unchanged.
+    ///
+    /// When enabled, data in a format without a configured builder (or one which is not
+    /// yet supported at all) is copied to the output as-is and tagged with
+    /// [`TransformFormat::None`], instead of failing the operation. This allows every
+    /// valid file to go through the same transform and untransform path.
+    ///
+    /// Disabled by default.
+    pub fn with_passthrough_unsupported(mut self, enabled: bool) -> Self {
+        self.passthrough_unsupported = enabled;
+        self
+    }
+
+    /// Returns whether formats that cannot be transformed are stored unchanged.
+    ///
+    /// See [`Self::with_passthrough_unsupported`].
+    pub fn passthrough_unsupported(&self) -> bool {
+        self.passthrough_unsupported
+    }
+
+    /// Set whether transforms are verified to restore the original data.
+    ///
+    /// When enabled, the slice and file transform APIs untransform the result into a scratch
+    /// buffer after transforming, and compare it against the input. If the two differ,
+    /// [`TransformError::RoundtripMismatch`] is returned with the offset of the first difference.
+    ///
+    /// Use this when the originals are discarded after transforming. Verification roughly
+    /// doubles the time taken, and needs a scratch buffer the size of the input.
+    /// It is not applied by the streaming (`Read`/`Write`) APIs.
+    ///
+    /// Disabled by default.
+    pub fn with_verify(mut self, enabled: bool) -> Self {
+        self.verify = enabled;
+        self
+    }
+
+    /// Returns whether transforms are verified to restore the original data.
+    ///
+    /// See [`Self::with_verify`].
+    pub fn verify(&self) -> bool {
+        self.verify
+    }
+
+    /// Set whether a checksum of the original texture data is stored in transformed files.
+    ///
+    /// When enabled, file format handlers store an xxh3 hash of the original data where the
+    /// format allows (e.g. unused DDS header fields, or the raw block container). Untransform
+    /// then verifies the restored data against it, and returns [`TransformError::ChecksumMismatch`]
+    /// if the transformed file was corrupted.
+    ///
+    /// Handlers without room for a checksum in a given file fail with
+    /// [`FormatHandlerError::ChecksumUnsupported`] rather than dropping it; see the documentation
+    /// of each handler for where (and when) the checksum is stored. Files transformed with a
+    /// checksum are always verified on untransform, regardless of this setting.
+    ///
+    /// Disabled by default.
+    pub fn with_checksum(mut self, enabled: bool) -> Self {
+        self.checksum = enabled;
+        self
+    }
+
+    /// Returns whether a checksum of the original texture data is stored in transformed files.
+    ///
+    /// See [`Self::with_checksum`].
+    pub fn checksum(&self) -> bool {
+        self.checksum
+    }
+
+    /// Set whether files with less texture data than their headers state are transformed.
+    ///
+    /// Such files are common in modded content, and usually still load. When enabled, file format
+    /// handlers transform the whole blocks present in the file, copy the remaining bytes verbatim
+    /// and record the truncation in the [`TransformHeader`] (see [`TransformHeader::is_truncated`]),
+    /// so untransform restores the file exactly. When disabled, such files are rejected with
+    /// [`FormatHandlerError::InputTooShortForStatedTextureSize`].
+    ///
+    /// Handlers which do not read a texture size from file headers ignore this setting.
+    /// The streaming (`Read`/`Write`) transform APIs read the texture data into memory when this
+    /// is enabled, as they can't know the length of the input up front.
+    ///
+    /// Disabled by default.
+    pub fn with_allow_truncated(mut self, enabled: bool) -> Self {
+        self.allow_truncated = enabled;
+        self
+    }
+
+    /// Returns whether files with less texture data than their headers state are transformed.
+    ///
+    /// See [`Self::with_allow_truncated`].
+    pub fn allow_truncated(&self) -> bool {
+        self.allow_truncated
+    }
+
     /// Dispatch transform operation based on the detected format.
     ///
     /// This method handles the transform operation and returns the transform header
@@ -119,6 +234,7 @@ where
     /// - `format`: The detected texture format to transform
     /// - `input_texture_data`: Input texture data to transform
     /// - `output_texture_data`: Output buffer for transformed data (must be at least the same size as input)
+    /// - `report`: Receives the detected format, chosen settings and tested candidates
     ///
     /// # Returns
     /// A [`TransformHeader`] containing the transform details for embedding.
@@ -127,6 +243,7 @@ where
         format: TransformFormat,
         input_texture_data: &[u8],
         output_texture_data: &mut [u8],
+        report: &mut TransformReport,
     ) -> TransformResult<TransformHeader> {
         if output_texture_data.len() < input_texture_data.len() {
             return Err(TransformError::FormatHandler(
@@ -137,36 +254,59 @@ where
             ));
         }
 
-        let header = match format {
-            TransformFormat::Bc1 => {
-                let builder = self
-                    .bc1
-                    .as_ref()
-                    .ok_or(FormatHandlerError::NoBuilderForFormat(TransformFormat::Bc1))?;
-
-                let details = builder
-                    .transform_slice_with_details(input_texture_data, output_texture_data)?;
+        let header = match (format, &self.bc1, &self.bc2) {
+            (TransformFormat::Bc1, Some(builder), _) => {
+                let details = builder.transform_slice_with_details(
+                    input_texture_data,
+                    output_texture_data,
+                    |x, estimated_size| {
+                        report.candidates.push(TransformCandidate {
+                            details: TransformDetails::Bc1(x),
+                            estimated_size,
+                        })
+                    },
+                )?;
 
                 crate::embed::EmbeddableBc1Details::from_settings(details).to_header()
             }
-            TransformFormat::Bc2 => {
-                let builder = self
-                    .bc2
-                    .as_ref()
-                    .ok_or(FormatHandlerError::NoBuilderForFormat(TransformFormat::Bc2))?;
-
-                let details = builder
-                    .transform_slice_with_details(input_texture_data, output_texture_data)?;
+            (TransformFormat::Bc2, _, Some(builder)) => {
+                let details = builder.transform_slice_with_details(
+                    input_texture_data,
+                    output_texture_data,
+                    |x, estimated_size| {
+                        report.candidates.push(TransformCandidate {
+                            details: TransformDetails::Bc2(x),
+                            estimated_size,
+                        })
+                    },
+                )?;
 
                 crate::embed::EmbeddableBc2Details::from_settings(details).to_header()
             }
+            (TransformFormat::None, _, _) => {
+                Self::passthrough(input_texture_data, output_texture_data)
+            }
+            _ if self.passthrough_unsupported => {
+                Self::passthrough(input_texture_data, output_texture_data)
+            }
+            (TransformFormat::Bc1 | TransformFormat::Bc2, _, _) => {
+                return Err(FormatHandlerError::NoBuilderForFormat(format).into());
+            }
             _ => {
                 return Err(TransformError::UnknownTransformFormat);
             }
         };
 
+        report.format = Some(format);
+        report.details = Some(TransformDetails::from_header(header));
         Ok(header)
     }
+
+    /// Copy the data unchanged, returning a [`TransformFormat::None`] header.
+    fn passthrough(input_texture_data: &[u8], output_texture_data: &mut [u8]) -> TransformHeader {
+        output_texture_data[..input_texture_data.len()].copy_from_slice(input_texture_data);
+        crate::embed::EmbeddableNoneDetails.to_header()
+    }
 }
 
 impl TransformBundle<NoEstimation> {
@@ -183,6 +323,10 @@ impl TransformBundle<NoEstimation> {
             bc2: Some(Bc2Builder::Manual(Bc2ManualTransformBuilder::new())),
             bc3: PhantomData,
             bc7: PhantomData,
+            passthrough_unsupported: false,
+            verify: false,
+            checksum: false,
+            allow_truncated: false,
         }
     }
 }
@@ -0,0 +1,214 @@
+//! Ready-made transform bundles, trading transform speed for compression ratio.
+
+use super::TransformBundle;
+use alloc::string::String;
+use core::str::FromStr;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use dxt_lossless_transform_bc1_api::{Bc1AutoTransformBuilder, Bc1ManualTransformBuilder};
+use dxt_lossless_transform_bc2_api::{Bc2AutoTransformBuilder, Bc2ManualTransformBuilder};
+use dxt_lossless_transform_ltu::{
+    LosslessTransformUtilsError, LosslessTransformUtilsSizeEstimation,
+};
+use dxt_lossless_transform_zstd::{ZStandardError, ZStandardSizeEstimation};
+use thiserror::Error;
+
+/// Ready-made transform configurations, from fastest to best compression ratio.
+///
+/// Use [`TransformPreset::bundle`] to create a [`TransformBundle`] with builders for every
+/// supported format. These are the presets used by the CLI.
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::bundle::TransformPreset;
+///
+/// let bundle = TransformPreset::Optimal.bundle().with_checksum(true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransformPreset {
+    /// Default manual settings; no size estimation, fastest.
+    Low,
+    /// Automatic optimization using the lossless-transform-utils estimator.
+    Medium,
+    /// Automatic optimization using ZStandard level 1.
+    #[default]
+    Optimal,
+    /// Automatic optimization testing every setting combination ("ultra" mode) using ZStandard level 1.
+    Max,
+}
+
+impl TransformPreset {
+    /// All presets, from fastest to best compression ratio.
+    pub const ALL: [Self; 4] = [Self::Low, Self::Medium, Self::Optimal, Self::Max];
+
+    /// Create a [`TransformBundle`] with this preset's builders for every supported format.
+    ///
+    /// Other bundle settings (e.g. [`TransformBundle::with_checksum`]) are left at their defaults.
+    pub fn bundle(self) -> TransformBundle<PresetEstimator> {
+        self.bundle_with(|estimator| estimator)
+    }
+
+    /// Create this preset's [`TransformBundle`], wrapping each estimator with `wrap`.
+    ///
+    /// Used where the bundle's estimator type is fixed, e.g. the C API.
+    #[cfg_attr(not(feature = "c-exports"), allow(dead_code))]
+    pub(crate) fn bundle_with<T>(self, wrap: impl Fn(PresetEstimator) -> T) -> TransformBundle<T>
+    where
+        T: SizeEstimationOperations,
+        T::Error: core::fmt::Debug,
+    {
+        let bundle = TransformBundle::new();
+        match self {
+            Self::Low => bundle
+                .with_bc1_manual(Bc1ManualTransformBuilder::new())
+                .with_bc2_manual(Bc2ManualTransformBuilder::new()),
+            Self::Medium => bundle
+                .with_bc1_auto(Bc1AutoTransformBuilder::new(wrap(PresetEstimator::ltu())))
+                .with_bc2_auto(Bc2AutoTransformBuilder::new(wrap(PresetEstimator::ltu()))),
+            Self::Optimal => bundle
+                .with_bc1_auto(Bc1AutoTransformBuilder::new(wrap(PresetEstimator::zstd())))
+                .with_bc2_auto(Bc2AutoTransformBuilder::new(wrap(PresetEstimator::zstd()))),
+            Self::Max => bundle
+                .with_bc1_auto(Bc1AutoTransformBuilder::new_ultra(wrap(
+                    PresetEstimator::zstd(),
+                )))
+                .with_bc2_auto(Bc2AutoTransformBuilder::new_ultra(wrap(
+                    PresetEstimator::zstd(),
+                ))),
+        }
+    }
+
+    /// Name of the preset, as accepted by [`TransformPreset::from_str`].
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::Optimal => "optimal",
+            Self::Max => "max",
+        }
+    }
+}
+
+impl FromStr for TransformPreset {
+    type Err = ParsePresetError;
+
+    /// Parse a preset from its [name](TransformPreset::name), ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParsePresetError(s.into()))
+    }
+}
+
+/// Error returned when parsing an unknown [`TransformPreset`] name.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Unknown preset: {0}. Valid options: low, medium, optimal, max")]
+pub struct ParsePresetError(pub String);
+
+/// Size estimator used by the bundles created from a [`TransformPreset`] or a `TransformBundleConfig`.
+///
+/// Allows all presets to share a single [`TransformBundle`] type.
+pub enum PresetEstimator {
+    /// Lossless-transform-utils estimator, see [`LosslessTransformUtilsSizeEstimation`].
+    Ltu(LosslessTransformUtilsSizeEstimation),
+    /// ZStandard estimator, see [`ZStandardSizeEstimation`].
+    ZStandard(ZStandardSizeEstimation),
+}
+
+impl PresetEstimator {
+    fn ltu() -> Self {
+        Self::Ltu(LosslessTransformUtilsSizeEstimation::new())
+    }
+
+    /// ZStandard level 1.
+    fn zstd() -> Self {
+        Self::ZStandard(ZStandardSizeEstimation::new_fast())
+    }
+}
+
+/// Errors returned by a [`PresetEstimator`].
+#[derive(Debug, Error)]
+pub enum PresetEstimatorError {
+    /// Error from the lossless-transform-utils estimator
+    #[error(transparent)]
+    Ltu(#[from] LosslessTransformUtilsError),
+    /// Error from the ZStandard estimator
+    #[error(transparent)]
+    ZStandard(#[from] ZStandardError),
+}
+
+impl SizeEstimationOperations for PresetEstimator {
+    type Error = PresetEstimatorError;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        match self {
+            Self::Ltu(estimator) => Ok(estimator.max_compressed_size(len_bytes)?),
+            Self::ZStandard(estimator) => Ok(estimator.max_compressed_size(len_bytes)?),
+        }
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        input_ptr: *const u8,
+        len_bytes: usize,
+        output_ptr: *mut u8,
+        output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        match self {
+            Self::Ltu(estimator) => {
+                Ok(estimator
+                    .estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len)?)
+            }
+            Self::ZStandard(estimator) => {
+                Ok(estimator
+                    .estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len)?)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{transform_slice_with_bundle, untransform_slice};
+    use crate::embed::TransformFormat;
+    use crate::handlers::FileFormatHandler;
+    use crate::raw::RawBlocksHandler;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::low(TransformPreset::Low)]
+    #[case::medium(TransformPreset::Medium)]
+    #[case::optimal(TransformPreset::Optimal)]
+    #[case::max(TransformPreset::Max)]
+    fn preset_bundles_roundtrip_every_supported_format(#[case] preset: TransformPreset) {
+        let bundle = preset.bundle();
+        let input: Vec<u8> = (0..16 * 64).map(|x| (x * 7 % 251) as u8).collect();
+
+        for format in [TransformFormat::Bc1, TransformFormat::Bc2] {
+            let handler = RawBlocksHandler::new(format);
+            let mut transformed = vec![0u8; handler.transformed_size(&input)];
+            transform_slice_with_bundle(&handler, &input, &mut transformed, &bundle).unwrap();
+
+            let mut restored = vec![0u8; input.len()];
+            untransform_slice(&handler, &transformed, &mut restored).unwrap();
+            assert_eq!(restored, input);
+        }
+    }
+
+    #[test]
+    fn presets_parse_from_name() {
+        for preset in TransformPreset::ALL {
+            assert_eq!(preset.name().parse(), Ok(preset));
+        }
+
+        assert_eq!("MAX".parse(), Ok(TransformPreset::Max));
+        assert_eq!(
+            "ultra".parse::<TransformPreset>(),
+            Err(ParsePresetError("ultra".into()))
+        );
+    }
+}
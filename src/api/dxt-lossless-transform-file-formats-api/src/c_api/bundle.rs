@@ -0,0 +1,386 @@
+//! Transform bundle for the C API.
+
+use crate::bundle::TransformBundle;
+use crate::c_api::error::{DltffErrorCode, DltffResult};
+use alloc::boxed::Box;
+use dxt_lossless_transform_api_common::c_api::size_estimation::{
+    CSizeEstimationError, DltSizeEstimator,
+};
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use dxt_lossless_transform_bc1_api::c_api::transform::auto_transform_builder::Dltbc1AutoTransformBuilder;
+use dxt_lossless_transform_bc1_api::c_api::transform::manual_transform_builder::Dltbc1ManualTransformBuilder;
+use dxt_lossless_transform_bc2_api::c_api::transform::auto_transform_builder::Dltbc2AutoTransformBuilder;
+use dxt_lossless_transform_bc2_api::c_api::transform::manual_transform_builder::Dltbc2ManualTransformBuilder;
+
+/// Opaque handle for a transform bundle.
+///
+/// Holds the transform builders used for each BCx format, see [`TransformBundle`].
+///
+/// **Usage Pattern:**
+/// 1. Create an empty bundle with [`dltff_new_TransformBundle`], or a ready-made one with
+///    `dltff_new_TransformBundle_FromPreset` (requires the `presets` feature)
+/// 2. Add builders with e.g. [`dltff_TransformBundle_SetBc1Manual`] or [`dltff_TransformBundle_SetBc1Auto`]
+/// 3. Pass to [`dltff_transform_slice`] or `dltff_transform_file`
+/// 4. Free with [`dltff_free_TransformBundle`]
+///
+/// Builders are copied into the bundle, so they may be freed once added.
+/// Estimators of auto builders must remain valid for the lifetime of the bundle.
+///
+/// # Remarks
+/// This type corresponds to [`TransformBundle`] in the Rust API.
+///
+/// This struct intentionally lacks `#[repr(C)]` to ensure it generates as an opaque forward declaration.
+///
+/// [`dltff_transform_slice`]: crate::c_api::registry::dltff_transform_slice
+pub struct DltffTransformBundle {
+    pub(crate) bundle: TransformBundle<BundleEstimator>,
+}
+
+impl DltffTransformBundle {
+    fn update(
+        &mut self,
+        f: impl FnOnce(TransformBundle<BundleEstimator>) -> TransformBundle<BundleEstimator>,
+    ) {
+        let bundle = core::mem::replace(&mut self.bundle, TransformBundle::new());
+        self.bundle = f(bundle);
+    }
+}
+
+/// Size estimator of bundles created through the C API.
+///
+/// Either the caller's callbacks, or the estimators of a preset.
+pub(crate) enum BundleEstimator {
+    Callbacks(DltSizeEstimator),
+    #[cfg(feature = "presets")]
+    Preset(crate::bundle::PresetEstimator),
+}
+
+#[derive(Debug)]
+pub(crate) enum BundleEstimatorError {
+    Callbacks(#[allow(dead_code)] CSizeEstimationError),
+    #[cfg(feature = "presets")]
+    Preset(#[allow(dead_code)] crate::bundle::PresetEstimatorError),
+}
+
+impl SizeEstimationOperations for BundleEstimator {
+    type Error = BundleEstimatorError;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        match self {
+            Self::Callbacks(estimator) => estimator
+                .max_compressed_size(len_bytes)
+                .map_err(BundleEstimatorError::Callbacks),
+            #[cfg(feature = "presets")]
+            Self::Preset(estimator) => estimator
+                .max_compressed_size(len_bytes)
+                .map_err(BundleEstimatorError::Preset),
+        }
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        input_ptr: *const u8,
+        len_bytes: usize,
+        output_ptr: *mut u8,
+        output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        match self {
+            Self::Callbacks(estimator) => estimator
+                .estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len)
+                .map_err(BundleEstimatorError::Callbacks),
+            #[cfg(feature = "presets")]
+            Self::Preset(estimator) => estimator
+                .estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len)
+                .map_err(BundleEstimatorError::Preset),
+        }
+    }
+}
+
+/// Get mutable access to the bundle, or an error result if the pointer is null.
+///
+/// # Safety
+/// - `bundle` must be null or a valid pointer to a [`DltffTransformBundle`]
+unsafe fn get_bundle_mut<'a>(
+    bundle: *mut DltffTransformBundle,
+) -> Result<&'a mut DltffTransformBundle, DltffResult> {
+    unsafe { bundle.as_mut() }.ok_or(DltffResult::from_error_code(
+        DltffErrorCode::NullBundlePointer,
+    ))
+}
+
+// =============================================================================
+// Lifecycle Functions
+// =============================================================================
+
+/// Create a new empty transform bundle.
+///
+/// Without any builders, transforming a file fails with
+/// [`DltffErrorCode::NoBuilderForFormat`], unless passthrough is enabled via
+/// [`dltff_TransformBundle_SetPassthroughUnsupported`].
+///
+/// The returned bundle must be freed with [`dltff_free_TransformBundle`].
+///
+/// # Remarks
+/// This function corresponds to [`TransformBundle::new`] in the Rust API.
+#[no_mangle]
+pub extern "C" fn dltff_new_TransformBundle() -> *mut DltffTransformBundle {
+    Box::into_raw(Box::new(DltffTransformBundle {
+        bundle: TransformBundle::new(),
+    }))
+}
+
+/// Ready-made transform configurations, see [`crate::bundle::TransformPreset`].
+#[cfg(feature = "presets")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DltffTransformPreset {
+    /// Default manual settings; no size estimation, fastest.
+    Low = 0,
+    /// Automatic optimization using the lossless-transform-utils estimator.
+    Medium = 1,
+    /// Automatic optimization using ZStandard level 1.
+    Optimal = 2,
+    /// Automatic optimization testing every setting combination using ZStandard level 1.
+    Max = 3,
+}
+
+#[cfg(feature = "presets")]
+impl From<DltffTransformPreset> for crate::bundle::TransformPreset {
+    fn from(preset: DltffTransformPreset) -> Self {
+        match preset {
+            DltffTransformPreset::Low => Self::Low,
+            DltffTransformPreset::Medium => Self::Medium,
+            DltffTransformPreset::Optimal => Self::Optimal,
+            DltffTransformPreset::Max => Self::Max,
+        }
+    }
+}
+
+/// Create a transform bundle with a preset's builders for every supported format.
+///
+/// The returned bundle must be freed with [`dltff_free_TransformBundle`].
+///
+/// # Remarks
+/// This function corresponds to [`crate::bundle::TransformPreset::bundle`] in the Rust API.
+#[cfg(feature = "presets")]
+#[no_mangle]
+pub extern "C" fn dltff_new_TransformBundle_FromPreset(
+    preset: DltffTransformPreset,
+) -> *mut DltffTransformBundle {
+    let preset = crate::bundle::TransformPreset::from(preset);
+    Box::into_raw(Box::new(DltffTransformBundle {
+        bundle: preset.bundle_with(BundleEstimator::Preset),
+    }))
+}
+
+/// Free a transform bundle.
+///
+/// # Safety
+/// - `bundle` must be a valid pointer returned by [`dltff_new_TransformBundle`]
+///   or `dltff_new_TransformBundle_FromPreset`
+/// - `bundle` must not have been freed already
+/// - After calling this function, `bundle` becomes invalid
+#[no_mangle]
+pub unsafe extern "C" fn dltff_free_TransformBundle(bundle: *mut DltffTransformBundle) {
+    if !bundle.is_null() {
+        unsafe {
+            drop(Box::from_raw(bundle));
+        }
+    }
+}
+
+// =============================================================================
+// Builder Functions
+// =============================================================================
+
+/// Use the given manual settings for BC1 textures, replacing any previous BC1 builder.
+///
+/// # Safety
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+/// - `builder` must be a valid pointer to a [`Dltbc1ManualTransformBuilder`]
+///
+/// # Remarks
+/// This function corresponds to [`TransformBundle::with_bc1_manual`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_TransformBundle_SetBc1Manual(
+    bundle: *mut DltffTransformBundle,
+    builder: *const Dltbc1ManualTransformBuilder,
+) -> DltffResult {
+    let bundle = match unsafe { get_bundle_mut(bundle) } {
+        Ok(bundle) => bundle,
+        Err(result) => return result,
+    };
+    let Some(builder) = (unsafe { builder.as_ref() }) else {
+        return DltffResult::from_error_code(DltffErrorCode::NullBuilderPointer);
+    };
+
+    bundle.update(|b| b.with_bc1_manual(builder.builder()));
+    DltffResult::success()
+}
+
+/// Optimize BC1 textures using the given auto builder, replacing any previous BC1 builder.
+///
+/// # Safety
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+/// - `builder` must be a valid pointer to a [`Dltbc1AutoTransformBuilder`]
+/// - The builder's estimator must remain valid for the lifetime of the bundle
+///
+/// # Remarks
+/// This function corresponds to [`TransformBundle::with_bc1_auto`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_TransformBundle_SetBc1Auto(
+    bundle: *mut DltffTransformBundle,
+    builder: *const Dltbc1AutoTransformBuilder,
+) -> DltffResult {
+    let bundle = match unsafe { get_bundle_mut(bundle) } {
+        Ok(bundle) => bundle,
+        Err(result) => return result,
+    };
+    let Some(builder) = (unsafe { builder.as_ref() }) else {
+        return DltffResult::from_error_code(DltffErrorCode::NullBuilderPointer);
+    };
+
+    let builder = builder.builder(BundleEstimator::Callbacks);
+    bundle.update(|b| b.with_bc1_auto(builder));
+    DltffResult::success()
+}
+
+/// Use the given manual settings for BC2 textures, replacing any previous BC2 builder.
+///
+/// # Safety
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+/// - `builder` must be a valid pointer to a [`Dltbc2ManualTransformBuilder`]
+///
+/// # Remarks
+/// This function corresponds to [`TransformBundle::with_bc2_manual`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_TransformBundle_SetBc2Manual(
+    bundle: *mut DltffTransformBundle,
+    builder: *const Dltbc2ManualTransformBuilder,
+) -> DltffResult {
+    let bundle = match unsafe { get_bundle_mut(bundle) } {
+        Ok(bundle) => bundle,
+        Err(result) => return result,
+    };
+    let Some(builder) = (unsafe { builder.as_ref() }) else {
+        return DltffResult::from_error_code(DltffErrorCode::NullBuilderPointer);
+    };
+
+    bundle.update(|b| b.with_bc2_manual(builder.builder()));
+    DltffResult::success()
+}
+
+/// Optimize BC2 textures using the given auto builder, replacing any previous BC2 builder.
+///
+/// # Safety
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+/// - `builder` must be a valid pointer to a [`Dltbc2AutoTransformBuilder`]
+/// - The builder's estimator must remain valid for the lifetime of the bundle
+///
+/// # Remarks
+/// This function corresponds to [`TransformBundle::with_bc2_auto`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_TransformBundle_SetBc2Auto(
+    bundle: *mut DltffTransformBundle,
+    builder: *const Dltbc2AutoTransformBuilder,
+) -> DltffResult {
+    let bundle = match unsafe { get_bundle_mut(bundle) } {
+        Ok(bundle) => bundle,
+        Err(result) => return result,
+    };
+    let Some(builder) = (unsafe { builder.as_ref() }) else {
+        return DltffResult::from_error_code(DltffErrorCode::NullBuilderPointer);
+    };
+
+    let builder = builder.builder(BundleEstimator::Callbacks);
+    bundle.update(|b| b.with_bc2_auto(builder));
+    DltffResult::success()
+}
+
+// =============================================================================
+// Policy Functions
+// =============================================================================
+
+/// Set whether formats without a builder are stored unchanged rather than rejected.
+///
+/// # Safety
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+///
+/// # Remarks
+/// This function corresponds to [`TransformBundle::with_passthrough_unsupported`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_TransformBundle_SetPassthroughUnsupported(
+    bundle: *mut DltffTransformBundle,
+    enabled: bool,
+) -> DltffResult {
+    match unsafe { get_bundle_mut(bundle) } {
+        Ok(bundle) => {
+            bundle.update(|b| b.with_passthrough_unsupported(enabled));
+            DltffResult::success()
+        }
+        Err(result) => result,
+    }
+}
+
+/// Set whether transformed data is untransformed again and compared against the input.
+///
+/// # Safety
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+///
+/// # Remarks
+/// This function corresponds to [`TransformBundle::with_verify`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_TransformBundle_SetVerify(
+    bundle: *mut DltffTransformBundle,
+    enabled: bool,
+) -> DltffResult {
+    match unsafe { get_bundle_mut(bundle) } {
+        Ok(bundle) => {
+            bundle.update(|b| b.with_verify(enabled));
+            DltffResult::success()
+        }
+        Err(result) => result,
+    }
+}
+
+/// Set whether a checksum of the original data is stored where the file format allows.
+///
+/// # Safety
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+///
+/// # Remarks
+/// This function corresponds to [`TransformBundle::with_checksum`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_TransformBundle_SetChecksum(
+    bundle: *mut DltffTransformBundle,
+    enabled: bool,
+) -> DltffResult {
+    match unsafe { get_bundle_mut(bundle) } {
+        Ok(bundle) => {
+            bundle.update(|b| b.with_checksum(enabled));
+            DltffResult::success()
+        }
+        Err(result) => result,
+    }
+}
+
+/// Set whether files shorter than their header states are transformed, rather than rejected.
+///
+/// # Safety
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+///
+/// # Remarks
+/// This function corresponds to [`TransformBundle::with_allow_truncated`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_TransformBundle_SetAllowTruncated(
+    bundle: *mut DltffTransformBundle,
+    enabled: bool,
+) -> DltffResult {
+    match unsafe { get_bundle_mut(bundle) } {
+        Ok(bundle) => {
+            bundle.update(|b| b.with_allow_truncated(enabled));
+            DltffResult::success()
+        }
+        Err(result) => result,
+    }
+}
@@ -0,0 +1,304 @@
+//! C API error handling for file format operations.
+
+use crate::embed::EmbedError;
+use crate::error::{FormatHandlerError, TransformError};
+use core::ffi::c_char;
+
+/// C-compatible error codes for file format operations.
+///
+/// Codes 1-99 are C API usage errors, 100-199 mirror [`FormatHandlerError`],
+/// 200-299 mirror [`TransformError`] and 300-399 are file I/O errors.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DltffErrorCode {
+    /// Operation succeeded
+    Success = 0,
+
+    /// Null pointer provided for [`DltffHandlerRegistry`] parameter
+    ///
+    /// [`DltffHandlerRegistry`]: crate::c_api::registry::DltffHandlerRegistry
+    NullRegistryPointer = 1,
+    /// Null pointer provided for [`DltffTransformBundle`] parameter
+    ///
+    /// [`DltffTransformBundle`]: crate::c_api::bundle::DltffTransformBundle
+    NullBundlePointer = 2,
+    /// Null pointer provided for transform builder parameter
+    NullBuilderPointer = 3,
+    /// Null pointer provided for input parameter
+    NullInputPointer = 4,
+    /// Null pointer provided for output buffer parameter
+    NullOutputBufferPointer = 5,
+    /// Null pointer provided for output size parameter
+    NullOutputSizePointer = 6,
+    /// Null pointer provided for file path parameter
+    NullPathPointer = 7,
+    /// A string parameter (file path or extension) is not valid UTF-8
+    InvalidString = 8,
+
+    /// See [`FormatHandlerError::UnknownFileFormat`]
+    UnknownFileFormat = 100,
+    /// See [`FormatHandlerError::InvalidInputFileHeader`]
+    InvalidInputFileHeader = 101,
+    /// See [`FormatHandlerError::InvalidRestoredFileHeader`]
+    InvalidRestoredFileHeader = 102,
+    /// See [`FormatHandlerError::AlreadyTransformed`]
+    AlreadyTransformed = 103,
+    /// See [`FormatHandlerError::NotTransformed`]
+    NotTransformed = 104,
+    /// See [`FormatHandlerError::FormatNotImplemented`]
+    FormatNotImplemented = 105,
+    /// See [`FormatHandlerError::NoBuilderForFormat`]
+    NoBuilderForFormat = 106,
+    /// See [`FormatHandlerError::OutputBufferTooSmall`]
+    OutputBufferTooSmall = 107,
+    /// See [`FormatHandlerError::InputTooShort`]
+    InputTooShort = 108,
+    /// See [`FormatHandlerError::InputTooShortForStatedTextureSize`]
+    InputTooShortForStatedTextureSize = 109,
+    /// See [`FormatHandlerError::UnsupportedContainerVersion`]
+    UnsupportedContainerVersion = 110,
+    /// See [`FormatHandlerError::ExtendedHeaderNotSupported`]
+    ExtendedHeaderNotSupported = 111,
+    /// See [`FormatHandlerError::InPlaceSizeMismatch`]
+    InPlaceSizeMismatch = 112,
+    /// See [`FormatHandlerError::ChecksumUnsupported`]
+    ChecksumUnsupported = 113,
+
+    /// Corrupted embedded transform data, see [`TransformError::Embed`]
+    CorruptedEmbeddedData = 200,
+    /// Unknown transform format in header, see [`TransformError::Embed`] and
+    /// [`TransformError::UnknownTransformFormat`]
+    UnknownTransformFormat = 201,
+    /// See [`TransformError::Bc1`]
+    Bc1TransformFailed = 202,
+    /// See [`TransformError::Bc2`]
+    Bc2TransformFailed = 203,
+    /// See [`TransformError::InvalidDataAlignment`]
+    InvalidDataAlignment = 204,
+    /// See [`TransformError::NoSupportedHandler`]
+    NoSupportedHandler = 205,
+    /// See [`TransformError::ChecksumMismatch`]
+    ChecksumMismatch = 206,
+    /// See [`TransformError::RoundtripMismatch`]
+    RoundtripMismatch = 207,
+
+    /// Reading or writing a file failed
+    IoError = 300,
+    /// The operation was cancelled
+    Cancelled = 301,
+}
+
+/// C-compatible Result type for file format operations.
+#[repr(C)]
+pub struct DltffResult {
+    /// Error code (0 = success, non-zero = error)
+    pub error_code: DltffErrorCode,
+}
+
+impl DltffResult {
+    /// Create a success result
+    pub const fn success() -> Self {
+        Self {
+            error_code: DltffErrorCode::Success,
+        }
+    }
+
+    /// Create an error result from an error code
+    pub const fn from_error_code(error_code: DltffErrorCode) -> Self {
+        Self { error_code }
+    }
+
+    /// Check if the result is successful
+    pub fn is_success(&self) -> bool {
+        matches!(self.error_code, DltffErrorCode::Success)
+    }
+}
+
+impl<T, E> From<Result<T, E>> for DltffResult
+where
+    E: Into<DltffResult>,
+{
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self::success(),
+            Err(e) => e.into(),
+        }
+    }
+}
+
+impl From<FormatHandlerError> for DltffResult {
+    fn from(error: FormatHandlerError) -> Self {
+        let error_code = match error {
+            FormatHandlerError::UnknownFileFormat => DltffErrorCode::UnknownFileFormat,
+            FormatHandlerError::InvalidInputFileHeader => DltffErrorCode::InvalidInputFileHeader,
+            FormatHandlerError::InvalidRestoredFileHeader => {
+                DltffErrorCode::InvalidRestoredFileHeader
+            }
+            FormatHandlerError::AlreadyTransformed => DltffErrorCode::AlreadyTransformed,
+            FormatHandlerError::NotTransformed => DltffErrorCode::NotTransformed,
+            FormatHandlerError::FormatNotImplemented(_) => DltffErrorCode::FormatNotImplemented,
+            FormatHandlerError::NoBuilderForFormat(_) => DltffErrorCode::NoBuilderForFormat,
+            FormatHandlerError::OutputBufferTooSmall { .. } => DltffErrorCode::OutputBufferTooSmall,
+            FormatHandlerError::InputTooShort { .. } => DltffErrorCode::InputTooShort,
+            FormatHandlerError::InputTooShortForStatedTextureSize { .. } => {
+                DltffErrorCode::InputTooShortForStatedTextureSize
+            }
+            FormatHandlerError::UnsupportedContainerVersion(_) => {
+                DltffErrorCode::UnsupportedContainerVersion
+            }
+            FormatHandlerError::ExtendedHeaderNotSupported => {
+                DltffErrorCode::ExtendedHeaderNotSupported
+            }
+            FormatHandlerError::InPlaceSizeMismatch { .. } => DltffErrorCode::InPlaceSizeMismatch,
+            FormatHandlerError::ChecksumUnsupported => DltffErrorCode::ChecksumUnsupported,
+        };
+        Self::from_error_code(error_code)
+    }
+}
+
+impl From<TransformError> for DltffResult {
+    fn from(error: TransformError) -> Self {
+        let error_code = match error {
+            TransformError::Embed(EmbedError::CorruptedEmbeddedData) => {
+                DltffErrorCode::CorruptedEmbeddedData
+            }
+            TransformError::Embed(EmbedError::UnknownFormat) => {
+                DltffErrorCode::UnknownTransformFormat
+            }
+            TransformError::FormatHandler(inner) => return inner.into(),
+            TransformError::Bc1(_) => DltffErrorCode::Bc1TransformFailed,
+            TransformError::Bc2(_) => DltffErrorCode::Bc2TransformFailed,
+            TransformError::UnknownTransformFormat => DltffErrorCode::UnknownTransformFormat,
+            TransformError::InvalidDataAlignment { .. } => DltffErrorCode::InvalidDataAlignment,
+            TransformError::NoSupportedHandler => DltffErrorCode::NoSupportedHandler,
+            TransformError::ChecksumMismatch { .. } => DltffErrorCode::ChecksumMismatch,
+            TransformError::RoundtripMismatch { .. } => DltffErrorCode::RoundtripMismatch,
+        };
+        Self::from_error_code(error_code)
+    }
+}
+
+#[cfg(feature = "file-io")]
+impl From<crate::file_io::FileOperationError> for DltffResult {
+    fn from(error: crate::file_io::FileOperationError) -> Self {
+        use crate::file_io::FileOperationError;
+        match error {
+            FileOperationError::Io(_) => Self::from_error_code(DltffErrorCode::IoError),
+            FileOperationError::Transform(inner) => inner.into(),
+            FileOperationError::Cancelled => Self::from_error_code(DltffErrorCode::Cancelled),
+        }
+    }
+}
+
+/// Get a null-terminated string description of the error code.
+///
+/// The returned string is a static string literal that does not need to be freed.
+///
+/// # Safety
+/// This function is safe to call with any error code value.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_error_message(error_code: DltffErrorCode) -> *const c_char {
+    let message = match error_code {
+        DltffErrorCode::Success => c"Success",
+        DltffErrorCode::NullRegistryPointer => {
+            c"Null pointer provided for DltffHandlerRegistry parameter"
+        }
+        DltffErrorCode::NullBundlePointer => {
+            c"Null pointer provided for DltffTransformBundle parameter"
+        }
+        DltffErrorCode::NullBuilderPointer => c"Null pointer provided for builder parameter",
+        DltffErrorCode::NullInputPointer => c"Null pointer provided for input parameter",
+        DltffErrorCode::NullOutputBufferPointer => c"Null pointer provided for output parameter",
+        DltffErrorCode::NullOutputSizePointer => c"Null pointer provided for output size parameter",
+        DltffErrorCode::NullPathPointer => c"Null pointer provided for file path parameter",
+        DltffErrorCode::InvalidString => c"String parameter is not valid UTF-8",
+        DltffErrorCode::UnknownFileFormat => c"Unknown file format",
+        DltffErrorCode::InvalidInputFileHeader => c"Invalid input file header during transform",
+        DltffErrorCode::InvalidRestoredFileHeader => {
+            c"Invalid restored file header during untransform"
+        }
+        DltffErrorCode::AlreadyTransformed => c"Input is already transformed",
+        DltffErrorCode::NotTransformed => c"Input is not transformed",
+        DltffErrorCode::FormatNotImplemented => c"Texture format not yet implemented",
+        DltffErrorCode::NoBuilderForFormat => c"No transform builder provided for format",
+        DltffErrorCode::OutputBufferTooSmall => c"Output buffer too small for the operation",
+        DltffErrorCode::InputTooShort => c"Input buffer too short",
+        DltffErrorCode::InputTooShortForStatedTextureSize => {
+            c"Input buffer too short for stated texture size in header"
+        }
+        DltffErrorCode::UnsupportedContainerVersion => c"Unsupported container version",
+        DltffErrorCode::ExtendedHeaderNotSupported => {
+            c"Extended transform headers are not supported by this file format"
+        }
+        DltffErrorCode::InPlaceSizeMismatch => {
+            c"In-place operation not supported: output size differs from buffer size"
+        }
+        DltffErrorCode::ChecksumUnsupported => {
+            c"Checksum requested, but this file has no room to store it"
+        }
+        DltffErrorCode::CorruptedEmbeddedData => c"Corrupted embedded transform data",
+        DltffErrorCode::UnknownTransformFormat => {
+            c"Unrecognized or unsupported transform format in header"
+        }
+        DltffErrorCode::Bc1TransformFailed => c"BC1 transform failed",
+        DltffErrorCode::Bc2TransformFailed => c"BC2 transform failed",
+        DltffErrorCode::InvalidDataAlignment => {
+            c"Invalid data alignment: size is not divisible by the block size"
+        }
+        DltffErrorCode::NoSupportedHandler => c"No file format handler can process the file",
+        DltffErrorCode::ChecksumMismatch => c"Checksum mismatch",
+        DltffErrorCode::RoundtripMismatch => {
+            c"Roundtrip verification failed: restored data differs from the input"
+        }
+        DltffErrorCode::IoError => c"File I/O operation failed",
+        DltffErrorCode::Cancelled => c"Operation was cancelled",
+    };
+    message.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embed::TransformFormat;
+    use core::ffi::CStr;
+
+    #[test]
+    fn transform_errors_map_to_error_codes() {
+        let cases = [
+            (
+                TransformError::from(FormatHandlerError::NoBuilderForFormat(TransformFormat::Bc1)),
+                DltffErrorCode::NoBuilderForFormat,
+            ),
+            (
+                TransformError::from(EmbedError::CorruptedEmbeddedData),
+                DltffErrorCode::CorruptedEmbeddedData,
+            ),
+            (
+                TransformError::NoSupportedHandler,
+                DltffErrorCode::NoSupportedHandler,
+            ),
+            (
+                TransformError::ChecksumMismatch {
+                    expected: 1,
+                    actual: 2,
+                },
+                DltffErrorCode::ChecksumMismatch,
+            ),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(DltffResult::from(error).error_code, expected);
+        }
+    }
+
+    #[test]
+    fn error_message_is_valid_utf8() {
+        unsafe {
+            let message = CStr::from_ptr(dltff_error_message(DltffErrorCode::Success));
+            assert_eq!(message.to_str().unwrap(), "Success");
+
+            let message = CStr::from_ptr(dltff_error_message(DltffErrorCode::AlreadyTransformed));
+            assert_eq!(message.to_str().unwrap(), "Input is already transformed");
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! # C API (FFI) Documentation
+//!
+//! *Note: The C API is only available when the `c-exports` feature is enabled.*
+//!
+//! Transforms whole files (e.g. DDS) from C, C++ or other languages with C FFI support,
+//! handling format detection and header embedding, unlike the raw block functions of the
+//! BCx crates (e.g. `dltbc1_ManualTransformBuilder_Transform`).
+//!
+//! - [`registry::DltffHandlerRegistry`] - The file format handlers to try, e.g. DDS via
+//!   `dltdds_register_DdsHandler` from the `dxt-lossless-transform-dds` crate
+//! - [`bundle::DltffTransformBundle`] - The transform builders to use for each BCx format,
+//!   built from the BCx crates' builders or a preset
+//! - [`registry::dltff_transform_slice`] / [`registry::dltff_untransform_slice`] - In-memory
+//!   operations; `dltff_transform_file` / `dltff_untransform_file` operate on file paths
+//!   (with the `file-io` feature)
+//!
+//! Errors are reported as [`error::DltffErrorCode`], mapped from the Rust API's
+//! [`TransformError`] and [`FormatHandlerError`].
+//!
+//! ## Example Usage
+//!
+//! **📝 Note: The transform operation should be performed *before* compression, and untransform should be performed *after* decompression.**
+//!
+//! ```c
+//! // Handlers: DDS
+//! DltffHandlerRegistry* registry = dltff_new_HandlerRegistry();
+//! dltdds_register_DdsHandler(registry);
+//!
+//! // Bundle: manual BC1 settings (builders are copied; free them whenever)
+//! DltffTransformBundle* bundle = dltff_new_TransformBundle();
+//! Dltbc1ManualTransformBuilder* bc1 = dltbc1_new_ManualTransformBuilder();
+//! dltff_TransformBundle_SetBc1Manual(bundle, bc1);
+//! dltbc1_free_ManualTransformBuilder(bc1);
+//!
+//! // Transform
+//! size_t transformed_len = 0;
+//! DltffResult result = dltff_transformed_size(registry, file_data, file_len, "dds", &transformed_len);
+//! if (result.error_code == DLTFF_SUCCESS) {
+//!     uint8_t* transformed = malloc(transformed_len);
+//!     result = dltff_transform_slice(registry, bundle, file_data, file_len,
+//!                                    transformed, transformed_len, "dds");
+//!     if (result.error_code != DLTFF_SUCCESS)
+//!         printf("Transform failed: %s\n", dltff_error_message(result.error_code));
+//!     // Now compress 'transformed' with your compressor...
+//! }
+//!
+//! // Clean up
+//! dltff_free_TransformBundle(bundle);
+//! dltff_free_HandlerRegistry(registry);
+//! ```
+//!
+//! [`TransformError`]: crate::TransformError
+//! [`FormatHandlerError`]: crate::FormatHandlerError
+
+// Module declarations
+pub mod bundle;
+pub mod error;
+pub mod registry;
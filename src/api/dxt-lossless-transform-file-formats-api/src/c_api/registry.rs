@@ -0,0 +1,605 @@
+//! Handler registry and whole-file transform functions for the C API.
+
+use crate::c_api::bundle::{BundleEstimator, DltffTransformBundle};
+use crate::c_api::error::{DltffErrorCode, DltffResult};
+use crate::handlers::{FileFormatDetection, FileFormatUntransformDetection, HandlerRegistry};
+use alloc::boxed::Box;
+use core::ffi::{c_char, CStr};
+
+/// Opaque handle for a collection of file format handlers.
+///
+/// Handlers are added by the crates implementing them, e.g. `dltdds_register_DdsHandler`
+/// from `dxt-lossless-transform-dds`. The first handler accepting a file is used, preferring
+/// handlers registered for the file's extension.
+///
+/// **Usage Pattern:**
+/// 1. Create with [`dltff_new_HandlerRegistry`]
+/// 2. Add handlers, e.g. with `dltdds_register_DdsHandler`
+/// 3. Transform with [`dltff_transform_slice`] / `dltff_transform_file`,
+///    untransform with [`dltff_untransform_slice`] / `dltff_untransform_file`
+/// 4. Free with [`dltff_free_HandlerRegistry`]
+///
+/// # Remarks
+/// This type corresponds to [`HandlerRegistry`] in the Rust API.
+///
+/// This struct intentionally lacks `#[repr(C)]` to ensure it generates as an opaque forward declaration.
+pub struct DltffHandlerRegistry {
+    registry: HandlerRegistry<BundleEstimator>,
+}
+
+impl DltffHandlerRegistry {
+    /// Register a handler, preferred for the given file extensions (lowercase, without leading dot).
+    ///
+    /// Used by crates implementing file format handlers to expose them to the C API.
+    /// See [`HandlerRegistry::register`].
+    pub fn register<H>(&mut self, handler: H, extensions: &[&str])
+    where
+        H: FileFormatDetection + FileFormatUntransformDetection + 'static,
+    {
+        self.registry.register(handler, extensions);
+    }
+}
+
+/// Create a new empty handler registry.
+///
+/// The returned registry must be freed with [`dltff_free_HandlerRegistry`].
+///
+/// # Remarks
+/// This function corresponds to [`HandlerRegistry::new`] in the Rust API.
+#[no_mangle]
+pub extern "C" fn dltff_new_HandlerRegistry() -> *mut DltffHandlerRegistry {
+    Box::into_raw(Box::new(DltffHandlerRegistry {
+        registry: HandlerRegistry::new(),
+    }))
+}
+
+/// Free a handler registry.
+///
+/// # Safety
+/// - `registry` must be a valid pointer returned by [`dltff_new_HandlerRegistry`]
+/// - `registry` must not have been freed already
+/// - After calling this function, `registry` becomes invalid
+#[no_mangle]
+pub unsafe extern "C" fn dltff_free_HandlerRegistry(registry: *mut DltffHandlerRegistry) {
+    if !registry.is_null() {
+        unsafe {
+            drop(Box::from_raw(registry));
+        }
+    }
+}
+
+/// Read an optional, null-terminated UTF-8 string.
+///
+/// # Safety
+/// - `string` must be null or a valid null-terminated string
+unsafe fn optional_str<'a>(string: *const c_char) -> Result<Option<&'a str>, DltffResult> {
+    if string.is_null() {
+        return Ok(None);
+    }
+
+    unsafe { CStr::from_ptr(string) }
+        .to_str()
+        .map(Some)
+        .map_err(|_| DltffResult::from_error_code(DltffErrorCode::InvalidString))
+}
+
+/// Evaluates to the value of a `Result<T, DltffResult>`, returning the error result otherwise.
+macro_rules! try_c {
+    ($expr:expr) => {
+        match $expr {
+            Ok(value) => value,
+            Err(result) => return result,
+        }
+    };
+}
+
+/// Returns the error result for a null pointer, if `$ptr` is null.
+macro_rules! ensure_not_null {
+    ($ptr:expr, $code:ident) => {
+        if $ptr.is_null() {
+            return DltffResult::from_error_code(DltffErrorCode::$code);
+        }
+    };
+}
+
+// =============================================================================
+// Slice Functions
+// =============================================================================
+
+/// Determine the buffer size required by [`dltff_transform_slice`].
+///
+/// # Parameters
+/// - `registry`: The handlers to try
+/// - `input`: Pointer to the file data
+/// - `input_len`: Length of the file data in bytes
+/// - `file_extension`: *Optional* (nullable) file extension, without leading dot
+/// - `out_size`: Receives the required output size in bytes
+///
+/// # Returns
+/// A [`DltffResult`]; [`DltffErrorCode::NoSupportedHandler`] if no handler accepts the file.
+///
+/// # Safety
+/// - `registry` must be a valid pointer to a [`DltffHandlerRegistry`]
+/// - `input` must be valid for reads of `input_len` bytes
+/// - `file_extension` must be null or a valid null-terminated string
+/// - `out_size` must be valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn dltff_transformed_size(
+    registry: *const DltffHandlerRegistry,
+    input: *const u8,
+    input_len: usize,
+    file_extension: *const c_char,
+    out_size: *mut usize,
+) -> DltffResult {
+    ensure_not_null!(registry, NullRegistryPointer);
+    ensure_not_null!(input, NullInputPointer);
+    ensure_not_null!(out_size, NullOutputSizePointer);
+    let file_extension = try_c!(unsafe { optional_str(file_extension) });
+
+    let registry = unsafe { &(*registry).registry };
+    let input = unsafe { core::slice::from_raw_parts(input, input_len) };
+    match registry.find_transform_handler(input, file_extension) {
+        Some(handler) => {
+            unsafe { *out_size = handler.dyn_transformed_size(input) };
+            DltffResult::success()
+        }
+        None => DltffResult::from_error_code(DltffErrorCode::NoSupportedHandler),
+    }
+}
+
+/// Determine the buffer size required by [`dltff_untransform_slice`].
+///
+/// # Parameters
+/// - `registry`: The handlers to try
+/// - `input`: Pointer to the transformed file data
+/// - `input_len`: Length of the transformed file data in bytes
+/// - `file_extension`: *Optional* (nullable) file extension, without leading dot
+/// - `out_size`: Receives the required output size in bytes
+///
+/// # Returns
+/// A [`DltffResult`]; [`DltffErrorCode::NoSupportedHandler`] if no handler accepts the file.
+///
+/// # Safety
+/// - `registry` must be a valid pointer to a [`DltffHandlerRegistry`]
+/// - `input` must be valid for reads of `input_len` bytes
+/// - `file_extension` must be null or a valid null-terminated string
+/// - `out_size` must be valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn dltff_untransformed_size(
+    registry: *const DltffHandlerRegistry,
+    input: *const u8,
+    input_len: usize,
+    file_extension: *const c_char,
+    out_size: *mut usize,
+) -> DltffResult {
+    ensure_not_null!(registry, NullRegistryPointer);
+    ensure_not_null!(input, NullInputPointer);
+    ensure_not_null!(out_size, NullOutputSizePointer);
+    let file_extension = try_c!(unsafe { optional_str(file_extension) });
+
+    let registry = unsafe { &(*registry).registry };
+    let input = unsafe { core::slice::from_raw_parts(input, input_len) };
+    match registry.find_untransform_handler(input, file_extension) {
+        Some(handler) => {
+            unsafe { *out_size = handler.dyn_untransformed_size(input) };
+            DltffResult::success()
+        }
+        None => DltffResult::from_error_code(DltffErrorCode::NoSupportedHandler),
+    }
+}
+
+/// Transform a file in memory, using the first handler in the registry that accepts it.
+///
+/// The file's transform settings are embedded in the output, so it can be untransformed
+/// with [`dltff_untransform_slice`] without further configuration.
+///
+/// # Parameters
+/// - `registry`: The handlers to try
+/// - `bundle`: The transform builders to use for each BCx format
+/// - `input`: Pointer to the file data
+/// - `input_len`: Length of the file data in bytes
+/// - `output`: Pointer to the output buffer
+/// - `output_len`: Length of the output buffer; at least the size from [`dltff_transformed_size`]
+/// - `file_extension`: *Optional* (nullable) file extension, without leading dot
+///
+/// # Returns
+/// A [`DltffResult`] indicating success or containing an error code.
+///
+/// # Safety
+/// - `registry` must be a valid pointer to a [`DltffHandlerRegistry`]
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+/// - `input` must be valid for reads of `input_len` bytes
+/// - `output` must be valid for writes of `output_len` bytes, and not overlap `input`
+/// - `file_extension` must be null or a valid null-terminated string
+///
+/// # Remarks
+/// This function corresponds to [`HandlerRegistry::transform_slice`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_transform_slice(
+    registry: *const DltffHandlerRegistry,
+    bundle: *const DltffTransformBundle,
+    input: *const u8,
+    input_len: usize,
+    output: *mut u8,
+    output_len: usize,
+    file_extension: *const c_char,
+) -> DltffResult {
+    ensure_not_null!(registry, NullRegistryPointer);
+    ensure_not_null!(bundle, NullBundlePointer);
+    ensure_not_null!(input, NullInputPointer);
+    ensure_not_null!(output, NullOutputBufferPointer);
+    let file_extension = try_c!(unsafe { optional_str(file_extension) });
+
+    let registry = unsafe { &(*registry).registry };
+    let bundle = unsafe { &(*bundle).bundle };
+    let input = unsafe { core::slice::from_raw_parts(input, input_len) };
+    let output = unsafe { core::slice::from_raw_parts_mut(output, output_len) };
+    registry
+        .transform_slice(input, output, file_extension, bundle)
+        .into()
+}
+
+/// Untransform a file in memory, using the first handler in the registry that accepts it.
+///
+/// # Parameters
+/// - `registry`: The handlers to try
+/// - `input`: Pointer to the transformed file data
+/// - `input_len`: Length of the transformed file data in bytes
+/// - `output`: Pointer to the output buffer
+/// - `output_len`: Length of the output buffer; at least the size from [`dltff_untransformed_size`]
+/// - `file_extension`: *Optional* (nullable) file extension, without leading dot
+///
+/// # Returns
+/// A [`DltffResult`] indicating success or containing an error code.
+///
+/// # Safety
+/// - `registry` must be a valid pointer to a [`DltffHandlerRegistry`]
+/// - `input` must be valid for reads of `input_len` bytes
+/// - `output` must be valid for writes of `output_len` bytes, and not overlap `input`
+/// - `file_extension` must be null or a valid null-terminated string
+///
+/// # Remarks
+/// This function corresponds to [`HandlerRegistry::untransform_slice`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltff_untransform_slice(
+    registry: *const DltffHandlerRegistry,
+    input: *const u8,
+    input_len: usize,
+    output: *mut u8,
+    output_len: usize,
+    file_extension: *const c_char,
+) -> DltffResult {
+    ensure_not_null!(registry, NullRegistryPointer);
+    ensure_not_null!(input, NullInputPointer);
+    ensure_not_null!(output, NullOutputBufferPointer);
+    let file_extension = try_c!(unsafe { optional_str(file_extension) });
+
+    let registry = unsafe { &(*registry).registry };
+    let input = unsafe { core::slice::from_raw_parts(input, input_len) };
+    let output = unsafe { core::slice::from_raw_parts_mut(output, output_len) };
+    registry
+        .untransform_slice(input, output, file_extension)
+        .into()
+}
+
+// =============================================================================
+// File Functions
+// =============================================================================
+
+/// Read a required, null-terminated UTF-8 file path.
+///
+/// # Safety
+/// - `path` must be null or a valid null-terminated string
+#[cfg(feature = "file-io")]
+unsafe fn required_path<'a>(path: *const c_char) -> Result<&'a std::path::Path, DltffResult> {
+    match unsafe { optional_str(path) }? {
+        Some(path) => Ok(std::path::Path::new(path)),
+        None => Err(DltffResult::from_error_code(
+            DltffErrorCode::NullPathPointer,
+        )),
+    }
+}
+
+/// Transform a file on disk, using the first handler in the registry that accepts it.
+///
+/// Handlers registered for the input file's extension are tried first.
+///
+/// # Parameters
+/// - `registry`: The handlers to try
+/// - `bundle`: The transform builders to use for each BCx format
+/// - `input_path`: Null-terminated UTF-8 path of the file to transform
+/// - `output_path`: Null-terminated UTF-8 path of the output file (will be created)
+///
+/// # Returns
+/// A [`DltffResult`] indicating success or containing an error code.
+///
+/// # Safety
+/// - `registry` must be a valid pointer to a [`DltffHandlerRegistry`]
+/// - `bundle` must be a valid pointer to a [`DltffTransformBundle`]
+/// - `input_path` and `output_path` must be valid null-terminated strings
+///
+/// # Remarks
+/// This function corresponds to [`crate::file_io::transform_file_with_registry`] in the Rust API.
+#[cfg(feature = "file-io")]
+#[no_mangle]
+pub unsafe extern "C" fn dltff_transform_file(
+    registry: *const DltffHandlerRegistry,
+    bundle: *const DltffTransformBundle,
+    input_path: *const c_char,
+    output_path: *const c_char,
+) -> DltffResult {
+    ensure_not_null!(registry, NullRegistryPointer);
+    ensure_not_null!(bundle, NullBundlePointer);
+    let input_path = try_c!(unsafe { required_path(input_path) });
+    let output_path = try_c!(unsafe { required_path(output_path) });
+
+    let registry = unsafe { &(*registry).registry };
+    let bundle = unsafe { &(*bundle).bundle };
+    crate::file_io::transform_file_with_registry(registry, input_path, output_path, bundle).into()
+}
+
+/// Untransform a file on disk, using the first handler in the registry that accepts it.
+///
+/// Handlers registered for the input file's extension are tried first.
+///
+/// # Parameters
+/// - `registry`: The handlers to try
+/// - `input_path`: Null-terminated UTF-8 path of the transformed file
+/// - `output_path`: Null-terminated UTF-8 path of the output file (will be created)
+///
+/// # Returns
+/// A [`DltffResult`] indicating success or containing an error code.
+///
+/// # Safety
+/// - `registry` must be a valid pointer to a [`DltffHandlerRegistry`]
+/// - `input_path` and `output_path` must be valid null-terminated strings
+///
+/// # Remarks
+/// This function corresponds to [`crate::file_io::untransform_file_with_registry`] in the Rust API.
+#[cfg(feature = "file-io")]
+#[no_mangle]
+pub unsafe extern "C" fn dltff_untransform_file(
+    registry: *const DltffHandlerRegistry,
+    input_path: *const c_char,
+    output_path: *const c_char,
+) -> DltffResult {
+    ensure_not_null!(registry, NullRegistryPointer);
+    let input_path = try_c!(unsafe { required_path(input_path) });
+    let output_path = try_c!(unsafe { required_path(output_path) });
+
+    let registry = unsafe { &(*registry).registry };
+    crate::file_io::untransform_file_with_registry(registry, input_path, output_path).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::bundle::*;
+    use crate::embed::TransformFormat;
+    use crate::RawBlocksHandler;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::ptr;
+    use dxt_lossless_transform_bc1_api::c_api::transform::manual_transform_builder::{
+        dltbc1_free_ManualTransformBuilder, dltbc1_new_ManualTransformBuilder,
+    };
+
+    fn create_registry() -> *mut DltffHandlerRegistry {
+        let registry = dltff_new_HandlerRegistry();
+        unsafe { &mut *registry }.register(RawBlocksHandler::new(TransformFormat::Bc1), &["bc1"]);
+        registry
+    }
+
+    fn create_block_data() -> Vec<u8> {
+        (0..8 * 64).map(|x| (x * 7 % 251) as u8).collect()
+    }
+
+    /// Transform and untransform `input` through the C API, returning the restored data.
+    unsafe fn roundtrip(
+        registry: *const DltffHandlerRegistry,
+        bundle: *const DltffTransformBundle,
+        input: &[u8],
+    ) -> Vec<u8> {
+        let extension = c"bc1".as_ptr();
+        unsafe {
+            let mut size = 0;
+            let result =
+                dltff_transformed_size(registry, input.as_ptr(), input.len(), extension, &mut size);
+            assert_eq!(result.error_code, DltffErrorCode::Success);
+
+            let mut transformed = vec![0u8; size];
+            let result = dltff_transform_slice(
+                registry,
+                bundle,
+                input.as_ptr(),
+                input.len(),
+                transformed.as_mut_ptr(),
+                transformed.len(),
+                extension,
+            );
+            assert_eq!(result.error_code, DltffErrorCode::Success);
+
+            let result = dltff_untransformed_size(
+                registry,
+                transformed.as_ptr(),
+                transformed.len(),
+                ptr::null(),
+                &mut size,
+            );
+            assert_eq!(result.error_code, DltffErrorCode::Success);
+
+            let mut restored = vec![0u8; size];
+            let result = dltff_untransform_slice(
+                registry,
+                transformed.as_ptr(),
+                transformed.len(),
+                restored.as_mut_ptr(),
+                restored.len(),
+                ptr::null(),
+            );
+            assert_eq!(result.error_code, DltffErrorCode::Success);
+            restored
+        }
+    }
+
+    #[test]
+    fn slice_roundtrip_with_manual_builder() {
+        let input = create_block_data();
+
+        unsafe {
+            let registry = create_registry();
+            let bundle = dltff_new_TransformBundle();
+            let bc1 = dltbc1_new_ManualTransformBuilder();
+            let result = dltff_TransformBundle_SetBc1Manual(bundle, bc1);
+            assert!(result.is_success());
+            dltbc1_free_ManualTransformBuilder(bc1);
+
+            assert_eq!(roundtrip(registry, bundle, &input), input);
+
+            dltff_free_TransformBundle(bundle);
+            dltff_free_HandlerRegistry(registry);
+        }
+    }
+
+    #[cfg(feature = "presets")]
+    #[test]
+    fn slice_roundtrip_with_preset() {
+        let input = create_block_data();
+
+        unsafe {
+            let registry = create_registry();
+            let bundle = dltff_new_TransformBundle_FromPreset(DltffTransformPreset::Medium);
+            assert!(dltff_TransformBundle_SetVerify(bundle, true).is_success());
+
+            assert_eq!(roundtrip(registry, bundle, &input), input);
+
+            dltff_free_TransformBundle(bundle);
+            dltff_free_HandlerRegistry(registry);
+        }
+    }
+
+    #[test]
+    fn transform_reports_handler_errors() {
+        let input = create_block_data();
+        let mut output = vec![0u8; input.len() * 2];
+
+        unsafe {
+            let registry = create_registry();
+            let bundle = dltff_new_TransformBundle();
+
+            // No builder for BC1
+            let result = dltff_transform_slice(
+                registry,
+                bundle,
+                input.as_ptr(),
+                input.len(),
+                output.as_mut_ptr(),
+                output.len(),
+                c"bc1".as_ptr(),
+            );
+            assert_eq!(result.error_code, DltffErrorCode::NoBuilderForFormat);
+
+            // No handler for the extension
+            let result = dltff_transform_slice(
+                registry,
+                bundle,
+                input.as_ptr(),
+                input.len(),
+                output.as_mut_ptr(),
+                output.len(),
+                c"png".as_ptr(),
+            );
+            assert_eq!(result.error_code, DltffErrorCode::NoSupportedHandler);
+
+            dltff_free_TransformBundle(bundle);
+            dltff_free_HandlerRegistry(registry);
+        }
+    }
+
+    #[test]
+    fn null_pointers_are_rejected() {
+        let input = create_block_data();
+        let mut size = 0;
+
+        unsafe {
+            let result = dltff_transformed_size(
+                ptr::null(),
+                input.as_ptr(),
+                input.len(),
+                ptr::null(),
+                &mut size,
+            );
+            assert_eq!(result.error_code, DltffErrorCode::NullRegistryPointer);
+
+            let registry = create_registry();
+            let result = dltff_transform_slice(
+                registry,
+                ptr::null(),
+                input.as_ptr(),
+                input.len(),
+                ptr::null_mut(),
+                0,
+                ptr::null(),
+            );
+            assert_eq!(result.error_code, DltffErrorCode::NullBundlePointer);
+
+            let result = dltff_TransformBundle_SetBc1Manual(ptr::null_mut(), ptr::null());
+            assert_eq!(result.error_code, DltffErrorCode::NullBundlePointer);
+
+            let bundle = dltff_new_TransformBundle();
+            let result = dltff_TransformBundle_SetBc1Manual(bundle, ptr::null());
+            assert_eq!(result.error_code, DltffErrorCode::NullBuilderPointer);
+
+            dltff_free_TransformBundle(bundle);
+            dltff_free_HandlerRegistry(registry);
+
+            // Freeing null is a no-op
+            dltff_free_TransformBundle(ptr::null_mut());
+            dltff_free_HandlerRegistry(ptr::null_mut());
+        }
+    }
+
+    #[cfg(feature = "file-io")]
+    #[test]
+    fn file_roundtrip() {
+        use std::ffi::CString;
+
+        let input = create_block_data();
+        let dir = tempfile::tempdir().unwrap();
+        let input_path = dir.path().join("texture.bc1");
+        let transformed_path = dir.path().join("texture.transformed.bc1");
+        let restored_path = dir.path().join("texture.restored.bc1");
+        std::fs::write(&input_path, &input).unwrap();
+
+        let c_path = |path: &std::path::Path| CString::new(path.to_str().unwrap()).unwrap();
+        let (input_c, transformed_c, restored_c) = (
+            c_path(&input_path),
+            c_path(&transformed_path),
+            c_path(&restored_path),
+        );
+
+        unsafe {
+            let registry = create_registry();
+            let bundle = dltff_new_TransformBundle();
+            let bc1 = dltbc1_new_ManualTransformBuilder();
+            dltff_TransformBundle_SetBc1Manual(bundle, bc1);
+            dltbc1_free_ManualTransformBuilder(bc1);
+
+            let result =
+                dltff_transform_file(registry, bundle, input_c.as_ptr(), transformed_c.as_ptr());
+            assert_eq!(result.error_code, DltffErrorCode::Success);
+
+            let result =
+                dltff_untransform_file(registry, transformed_c.as_ptr(), restored_c.as_ptr());
+            assert_eq!(result.error_code, DltffErrorCode::Success);
+
+            let result = dltff_untransform_file(registry, ptr::null(), restored_c.as_ptr());
+            assert_eq!(result.error_code, DltffErrorCode::NullPathPointer);
+
+            dltff_free_TransformBundle(bundle);
+            dltff_free_HandlerRegistry(registry);
+        }
+
+        assert_eq!(std::fs::read(&restored_path).unwrap(), input);
+    }
+}
@@ -38,7 +38,9 @@ bitfield! {
     /// - Bits 0-1: Header version (2 bits)
     /// - Bit 2: Split colour endpoints flag (1 bit)
     /// - Bits 3-4: Decorrelation variant (2 bits)
-    /// - Bits 5-27: Reserved for future use (23 bits)
+    /// - Bits 5-25: Reserved for future use (21 bits)
+    /// - Bit 26: Truncated flag of the `TransformHeader`, cleared before unpacking
+    /// - Bit 27: Checksum flag of the `TransformHeader`, cleared before unpacking
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
      struct Bc1TransformHeaderData(u32);
     impl Debug;
@@ -50,8 +52,8 @@ bitfield! {
     split_colour_endpoints, set_split_colour_endpoints: 2;
     /// YCoCg decorrelation variant (0=Variant1, 1=Variant2, 2=Variant3, 3=None) (2 bits)
     decorrelation_variant, set_decorrelation_variant: 4, 3;
-    /// Reserved bits for future use (23 bits)
-    reserved, set_reserved: 27, 5;
+    /// Reserved bits for future use (21 bits)
+    reserved, set_reserved: 25, 5;
 }
 
 impl Bc1TransformHeaderData {
@@ -34,7 +34,9 @@ bitfield! {
     /// - Bits 0-1: Header version (2 bits)
     /// - Bit 2: Split colour endpoints (1 bit)
     /// - Bits 3-4: Decorrelation mode (2 bits, [`YCoCgVariant`] as u8)
-    /// - Bits 5-27: Reserved for future use (23 bits)
+    /// - Bits 5-25: Reserved for future use (21 bits)
+    /// - Bit 26: Truncated flag of the `TransformHeader`, cleared before unpacking
+    /// - Bit 27: Checksum flag of the `TransformHeader`, cleared before unpacking
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
      struct Bc2TransformHeaderData(u32);
     impl Debug;
@@ -46,8 +48,8 @@ bitfield! {
     split_colour_endpoints, set_split_colour_endpoints: 2;
     /// Decorrelation mode (2 bits)
     decorrelation_mode, set_decorrelation_mode: 4, 3;
-    /// Reserved bits for future use (23 bits)
-    reserved, set_reserved: 27, 5;
+    /// Reserved bits for future use (21 bits)
+    reserved, set_reserved: 25, 5;
 }
 
 /// BC2 transform details that can be stored in file headers
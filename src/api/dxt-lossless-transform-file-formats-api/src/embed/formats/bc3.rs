@@ -30,7 +30,9 @@ bitfield! {
     ///
     /// Bit layout (within the 28-bit format data):
     /// - Bits 0-1: Header version (2 bits)
-    /// - Bits 2-27: Reserved for future use (26 bits)
+    /// - Bits 2-25: Reserved for future use (24 bits)
+    /// - Bit 26: Truncated flag of the `TransformHeader`, cleared before unpacking
+    /// - Bit 27: Checksum flag of the `TransformHeader`, cleared before unpacking
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
     struct Bc3TransformHeaderData(u32);
     impl Debug;
@@ -38,8 +40,8 @@ bitfield! {
 
     /// Header version (2 bits)
     header_version, set_header_version: 1, 0;
-    /// Reserved bits for future use (26 bits)
-    reserved, set_reserved: 27, 2;
+    /// Reserved bits for future use (24 bits)
+    reserved, set_reserved: 25, 2;
 }
 
 /// BC3 transform details that can be stored in file headers
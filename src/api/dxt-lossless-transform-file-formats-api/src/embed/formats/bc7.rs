@@ -37,7 +37,9 @@ bitfield! {
     ///
     /// Bit layout (within the 28-bit format data):
     /// - Bits 0-1: Header version (2 bits)
-    /// - Bits 2-27: Reserved for BC7 mode masks and settings (26 bits)
+    /// - Bits 2-25: Reserved for BC7 mode masks and settings (24 bits)
+    /// - Bit 26: Truncated flag of the `TransformHeader`, cleared before unpacking
+    /// - Bit 27: Checksum flag of the `TransformHeader`, cleared before unpacking
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
     struct Bc7TransformHeaderData(u32);
     impl Debug;
@@ -45,8 +47,8 @@ bitfield! {
 
     /// Header version (2 bits)
     header_version, set_header_version: 1, 0;
-    /// Reserved bits for future BC7 implementation (26 bits)
-    reserved, set_reserved: 27, 2;
+    /// Reserved bits for future BC7 implementation (24 bits)
+    reserved, set_reserved: 25, 2;
 }
 
 /// Wrapper type for BC7 untransform details that can be stored in file headers
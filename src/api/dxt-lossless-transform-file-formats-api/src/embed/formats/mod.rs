@@ -14,6 +14,7 @@ mod bc7;
 mod bgr888;
 #[allow(dead_code)] // BGRA8888 embed support ready but not yet integrated into public API.
 mod bgra8888;
+mod none;
 #[allow(dead_code)] // RGBA8888 embed support ready but not yet integrated into public API.
 mod rgba8888;
 
@@ -23,6 +24,7 @@ pub(crate) use bc4::EmbeddableBc4Details;
 pub(crate) use bc5::EmbeddableBc5Details;
 pub(crate) use bgr888::EmbeddableBgr888Details;
 pub(crate) use bgra8888::EmbeddableBgra8888Details;
+pub(crate) use none::EmbeddableNoneDetails;
 pub(crate) use rgba8888::EmbeddableRgba8888Details;
 
 use super::{EmbedError, TransformFormat, TransformHeader};
@@ -30,21 +32,30 @@ use super::{EmbedError, TransformFormat, TransformHeader};
 /// Trait for transform details that can be embedded in a 4-byte header.
 ///
 /// Each BCx format implements this trait to define how its transform details
-/// are packed into the 28 bits of format-specific data in the header.
+/// are packed into the 26 bits of format-specific data in the header.
+///
+/// The two bits above those (bit 26, the truncated flag, and bit 27, the checksum flag) are
+/// shared by all formats; they are cleared before [`Self::unpack`] is called, and must never be set by
+/// [`Self::pack`].
 pub(crate) trait EmbeddableTransformDetails: Sized {
     /// The transform format this implementation is for
     const FORMAT: TransformFormat;
 
-    /// Pack the transform details into a 28-bit value
+    /// Pack the transform details into a 26-bit value
     fn pack(&self) -> u32;
 
-    /// Unpack transform details from a 28-bit value
+    /// Unpack transform details from a 26-bit value
     fn unpack(data: u32) -> Result<Self, EmbedError>;
 
     /// Extract from a complete transform header
     fn from_header(header: TransformHeader) -> Result<Self, EmbedError> {
         match header.format() {
-            Some(format) if format == Self::FORMAT => Self::unpack(header.format_data()),
+            Some(format) if format == Self::FORMAT => Self::unpack(
+                header
+                    .with_checksum(false)
+                    .with_truncated(false)
+                    .format_data(),
+            ),
             Some(_) => Err(EmbedError::UnknownFormat),
             None => Err(EmbedError::UnknownFormat),
         }
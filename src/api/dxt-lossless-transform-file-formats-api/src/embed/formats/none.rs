@@ -0,0 +1,131 @@
+//! Passthrough ('None') format support.
+//!
+//! This module provides the embeddable details for data that was stored unchanged,
+//! i.e. formats which could not be transformed but were still processed by a handler.
+
+use super::EmbeddableTransformDetails;
+use crate::embed::{EmbedError, TransformFormat, TransformHeader};
+use bitfield::bitfield;
+
+/// Header version for the passthrough format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum NoneHeaderVersion {
+    /// Initial version - data is copied verbatim
+    InitialVersion = 0,
+}
+
+impl NoneHeaderVersion {
+    /// Convert from u32 value
+    fn from_u32(value: u32) -> Result<Self, EmbedError> {
+        match value {
+            0 => Ok(Self::InitialVersion),
+            _ => Err(EmbedError::CorruptedEmbeddedData),
+        }
+    }
+
+    /// Convert to u32 value
+    fn to_u32(self) -> u32 {
+        self as u32
+    }
+}
+
+bitfield! {
+    /// Packed passthrough data for storage in headers.
+    ///
+    /// Bit layout (within the 28-bit format data):
+    /// - Bits 0-1: Header version (2 bits)
+    /// - Bits 2-25: Reserved for future use (24 bits)
+    /// - Bit 26: Truncated flag of the `TransformHeader`, cleared before unpacking
+    /// - Bit 27: Checksum flag of the `TransformHeader`, cleared before unpacking
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+    struct NoneTransformHeaderData(u32);
+    impl Debug;
+    u32;
+
+    /// Header version (2 bits)
+    header_version, set_header_version: 1, 0;
+    /// Reserved for future use (24 bits)
+    reserved, set_reserved: 25, 2;
+}
+
+/// Passthrough details for embedding in headers.
+///
+/// There are no settings; the data is stored unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct EmbeddableNoneDetails;
+
+impl EmbeddableTransformDetails for EmbeddableNoneDetails {
+    const FORMAT: TransformFormat = TransformFormat::None;
+
+    fn pack(&self) -> u32 {
+        let mut header = NoneTransformHeaderData::default();
+        header.set_header_version(NoneHeaderVersion::InitialVersion.to_u32());
+        header.set_reserved(0);
+        header.0
+    }
+
+    fn unpack(data: u32) -> Result<Self, EmbedError> {
+        let header = NoneTransformHeaderData(data);
+
+        // Validate version (from_u32 will error on invalid version)
+        let _version = NoneHeaderVersion::from_u32(header.header_version())?;
+
+        // Reserved bits should be zero for forward compatibility
+        if header.reserved() != 0 {
+            return Err(EmbedError::CorruptedEmbeddedData);
+        }
+
+        Ok(Self)
+    }
+}
+
+impl EmbeddableNoneDetails {
+    /// Convert to a [`TransformHeader`]
+    pub fn to_header(self) -> TransformHeader {
+        crate::embed::TransformHeader::new(Self::FORMAT, self.pack())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_none_header_roundtrip() {
+        let header = EmbeddableNoneDetails.to_header();
+
+        assert_eq!(header.format(), Some(TransformFormat::None));
+        assert_eq!(
+            EmbeddableNoneDetails::from_header(header),
+            Ok(EmbeddableNoneDetails)
+        );
+    }
+
+    #[test]
+    fn test_invalid_header_version() {
+        let mut invalid_header = NoneTransformHeaderData::default();
+        invalid_header.set_header_version(3); // Invalid version (only 0 is valid)
+
+        assert_eq!(
+            EmbeddableNoneDetails::unpack(invalid_header.0),
+            Err(EmbedError::CorruptedEmbeddedData)
+        );
+    }
+
+    #[test]
+    fn test_invalid_reserved_bits() {
+        let mut invalid_header = NoneTransformHeaderData::default();
+        invalid_header.set_reserved(1); // Should be zero
+
+        assert_eq!(
+            EmbeddableNoneDetails::unpack(invalid_header.0),
+            Err(EmbedError::CorruptedEmbeddedData)
+        );
+    }
+
+    #[test]
+    fn test_format_association() {
+        assert_eq!(EmbeddableNoneDetails::FORMAT, TransformFormat::None);
+    }
+}
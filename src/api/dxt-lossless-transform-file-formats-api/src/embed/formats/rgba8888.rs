@@ -37,7 +37,9 @@ bitfield! {
     /// Bit layout (within the 28-bit format data):
     /// - Bits 0-1: Header version (2 bits)
     /// - Bit 2: Decorrelation flag (1 bit)
-    /// - Bits 3-27: Reserved for future use (25 bits)
+    /// - Bits 3-25: Reserved for future use (23 bits)
+    /// - Bit 26: Truncated flag of the `TransformHeader`, cleared before unpacking
+    /// - Bit 27: Checksum flag of the `TransformHeader`, cleared before unpacking
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
      struct Rgba8888TransformHeaderData(u32);
     impl Debug;
@@ -47,8 +49,8 @@ bitfield! {
     header_version, set_header_version: 1, 0;
     /// Whether to apply decorrelation (1 bit)
     decorrelation, set_decorrelation: 2;
-    /// Reserved for future use (25 bits)
-    reserved, set_reserved: 27, 3;
+    /// Reserved for future use (23 bits)
+    reserved, set_reserved: 25, 3;
 }
 
 /// RGBA8888 transform details for embedding in headers.
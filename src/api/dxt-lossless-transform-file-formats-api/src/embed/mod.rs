@@ -41,18 +41,62 @@
 //! The bits are represented as the following.
 //!
 //! `u4`  - Transform Format [`TransformFormat`]
-//! `u28` - Transform format specific data.
+//! `u26` - Transform format specific data.
+//! `u1`  - Truncated flag, see [below](self#truncated-flag).
+//! `u1`  - Checksum flag, see [below](self#checksum-flag).
 //!
 //! Each Transform Format is responsible for versioning itself; this header merely stores the
 //! format type itself.
 //!
-//! Generally it's expected that changes to existing formats will be rare, 28 bits is quite rich,
-//! most transforms will not even use half the space. This number was chosen as it's sufficient
-//! to store 3 bits for each of BC7's 8 modes, with 4 bits left over for miscellaneous use.
+//! Generally it's expected that changes to existing formats will be rare, 26 bits is quite rich,
+//! most transforms will not even use half the space. This is sufficient to store 3 bits for each
+//! of BC7's 8 modes, with 2 bits left over for miscellaneous use.
 //!
 //! There is no 'MAGIC' number here or anything to identify the header; the user has to know
 //! in context we're dealing with a valid header.
 //!
+//! ## Checksum Flag
+//!
+//! The highest bit of the format specific data (bit 31 of a standard header) is reserved for all
+//! formats. When set, the file format handler stored a checksum of the original texture data
+//! somewhere else in the file (e.g. unused header fields); see [`TransformHeader::has_checksum`].
+//! Formats never see this bit when unpacking their data.
+//!
+//! ## Truncated Flag
+//!
+//! The next highest bit (bit 30 of a standard header) is also reserved for all formats. When set,
+//! the file was shorter than its headers state, and the file format handler only transformed the
+//! whole blocks present in the file; see [`TransformHeader::is_truncated`].
+//! Like the checksum flag, formats never see this bit when unpacking their data.
+//!
+//! ## Extended Headers
+//!
+//! 4 bits only allow for 16 transform formats. To allow for more, the format value
+//! [`EXTENDED_FORMAT_ESCAPE`] (`0xF`) is reserved as an escape; it signals that an
+//! extended header follows. Extended headers are [`EXTENDED_TRANSFORM_HEADER_SIZE`] (8) bytes,
+//! packed as a [`u64`] little endian integer:
+//!
+//! `u4`  - [`EXTENDED_FORMAT_ESCAPE`]
+//! `u12` - Extended transform format code.
+//! `u16` - Reserved, must be zero.
+//! `u32` - Transform format specific data.
+//!
+//! Use [`TransformHeader::size`] (or [`TransformHeader::size_from_first_byte`] when reading)
+//! to determine how many bytes a header occupies.
+//!
+//! ### Compatibility Rules
+//!
+//! - Format values `0x0`-`0xE` always use the standard 4 byte header. Their layout never changes,
+//!   so existing transformed files continue to decode as before.
+//! - New formats are assigned a standard code while codes remain, and extended codes afterwards.
+//!   A format never moves between the standard and extended code space once assigned.
+//! - Readers that predate the escape treat `0xF` as an unrecognized format and fail with
+//!   an error; they never misinterpret an extended header as a standard one.
+//! - Handlers with room for only 4 bytes (e.g. the DDS magic) must reject extended headers with
+//!   [`FormatHandlerError::ExtendedHeaderNotSupported`] rather than truncating them.
+//!
+//! [`FormatHandlerError::ExtendedHeaderNotSupported`]: crate::error::FormatHandlerError::ExtendedHeaderNotSupported
+//!
 //! ### Transform Specific Data Representation
 //!
 //! The transform specific data is represented using bitfield structures.
@@ -63,9 +107,11 @@ use bitfield::bitfield;
 // Sub-modules
 mod embed_error; // Internal error handling only
 pub(super) mod formats; // Internal format implementations only
+mod transform_details; // Public - used by external crates
 mod transform_format; // Public - used by external crates
 
 // Public re-exports (used by external crates)
+pub use transform_details::TransformDetails;
 pub use transform_format::TransformFormat;
 
 // Internal re-exports (used only within file-formats-api crate)
@@ -80,16 +126,27 @@ pub(super) use formats::EmbeddableBc5Details;
 pub(super) use formats::EmbeddableBgr888Details;
 #[allow(unused_imports)]
 pub(super) use formats::EmbeddableBgra8888Details;
+pub(super) use formats::EmbeddableNoneDetails;
 #[allow(unused_imports)]
 pub(super) use formats::EmbeddableRgba8888Details;
 
-/// Size of the transform header in bytes.
+/// Size of the standard transform header in bytes.
 ///
-/// The transform header is always 4 bytes (32 bits) containing:
+/// The standard transform header is 4 bytes (32 bits) containing:
 /// - 4 bits for transform format type
-/// - 28 bits for format-specific data
+/// - 26 bits for format-specific data
+/// - 2 bits for the truncated and checksum flags shared by all formats
 pub const TRANSFORM_HEADER_SIZE: usize = 4;
 
+/// Size of the extended transform header in bytes.
+///
+/// Used when the format value of the header is [`EXTENDED_FORMAT_ESCAPE`].
+/// See the [module level documentation](self#extended-headers) for details.
+pub const EXTENDED_TRANSFORM_HEADER_SIZE: usize = 8;
+
+/// Format value signalling that an extended header follows.
+pub const EXTENDED_FORMAT_ESCAPE: u8 = 0x0F;
+
 /// Additional space required for BC7 transform details beyond the header.
 ///
 /// BC7 transform details require more space than fits in the 4-byte header,
@@ -105,18 +162,36 @@ pub const BC6H_ADDITIONAL_SPACE: usize = 80;
 bitfield! {
     /// Common header structure for all transform formats.
     ///
-    /// This is a 32-bit header where:
+    /// Standard headers are 32 bits where:
     /// - Bits 0-3: Transform format type
-    /// - Bits 4-31: Format-specific data
+    /// - Bits 4-29: Format-specific data
+    /// - Bit 30: Truncated flag, shared by all formats (see [`TransformHeader::is_truncated`])
+    /// - Bit 31: Checksum flag, shared by all formats (see [`TransformHeader::has_checksum`])
+    ///
+    /// Extended headers (format type [`EXTENDED_FORMAT_ESCAPE`]) are 64 bits where:
+    /// - Bits 0-3: [`EXTENDED_FORMAT_ESCAPE`]
+    /// - Bits 4-15: Extended transform format type
+    /// - Bits 16-31: Reserved (zero)
+    /// - Bits 32-63: Format-specific data
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
-    pub struct TransformHeader(u32);
+    pub struct TransformHeader(u64);
     impl Debug;
     u32;
 
     /// Transform format type (4 bits)
     format_raw, set_format_raw: 3, 0;
-    /// Format-specific data (28 bits)
+    /// Format-specific data (28 bits), of which the top 2 bits are the flags shared by all formats
     format_data, set_format_data: 31, 4;
+    /// Extended transform format type (12 bits), only valid for extended headers
+    extended_format_raw, set_extended_format_raw: 15, 4;
+    /// Reserved bits of an extended header (16 bits)
+    extended_reserved, set_extended_reserved: 31, 16;
+    /// Format-specific data of an extended header (32 bits)
+    extended_data, set_extended_data: 63, 32;
+    /// Whether a checksum was stored by the file format handler (1 bit), standard headers only
+    checksum_flag, set_checksum_flag: 31;
+    /// Whether the file format handler only transformed part of the texture data (1 bit), standard headers only
+    truncated_flag, set_truncated_flag: 30;
 }
 
 impl TransformHeader {
@@ -128,36 +203,132 @@ impl TransformHeader {
         header
     }
 
+    /// Create a new extended transform header with the given extended format code and data.
+    #[cfg(test)]
+    fn new_extended(extended_format: u16, data: u32) -> Self {
+        let mut header = Self::default();
+        header.set_format_raw(EXTENDED_FORMAT_ESCAPE as u32);
+        header.set_extended_format_raw(extended_format as u32);
+        header.set_extended_data(data);
+        header
+    }
+
     /// Get the transform format from the header.
     ///
     /// Returns [`None`] if the format value in the header is not recognized.
     /// This can happen when reading files created with newer versions that
     /// support additional transform formats.
     pub(super) fn format(&self) -> Option<TransformFormat> {
+        if self.is_extended() {
+            if self.extended_reserved() != 0 {
+                return None;
+            }
+
+            return TransformFormat::from_extended_code(self.extended_format_raw() as u16);
+        }
+
         TransformFormat::from_u8(self.format_raw() as u8)
     }
 
+    /// Returns `true` if the file format handler stored a checksum of the original texture data
+    /// alongside this header.
+    ///
+    /// See the [module level documentation](self#checksum-flag) for details.
+    pub fn has_checksum(&self) -> bool {
+        !self.is_extended() && self.checksum_flag()
+    }
+
+    /// Returns a copy of this header with the checksum flag set to `has_checksum`.
+    ///
+    /// Only standard headers have a checksum flag; extended headers are returned unchanged.
+    pub fn with_checksum(mut self, has_checksum: bool) -> Self {
+        if !self.is_extended() {
+            self.set_checksum_flag(has_checksum);
+        }
+        self
+    }
+
+    /// Returns `true` if the file was shorter than its headers state when transformed, so only the
+    /// whole blocks present in the file were transformed.
+    ///
+    /// See the [module level documentation](self#truncated-flag) for details.
+    pub fn is_truncated(&self) -> bool {
+        !self.is_extended() && self.truncated_flag()
+    }
+
+    /// Returns a copy of this header with the truncated flag set to `is_truncated`.
+    ///
+    /// Only standard headers have a truncated flag; extended headers are returned unchanged.
+    pub fn with_truncated(mut self, is_truncated: bool) -> Self {
+        if !self.is_extended() {
+            self.set_truncated_flag(is_truncated);
+        }
+        self
+    }
+
+    /// Returns `true` if this is an extended ([`EXTENDED_TRANSFORM_HEADER_SIZE`] byte) header.
+    pub fn is_extended(&self) -> bool {
+        self.format_raw() as u8 == EXTENDED_FORMAT_ESCAPE
+    }
+
+    /// Size of this header in bytes when written out.
+    ///
+    /// Either [`TRANSFORM_HEADER_SIZE`] or [`EXTENDED_TRANSFORM_HEADER_SIZE`].
+    pub fn size(&self) -> usize {
+        if self.is_extended() {
+            EXTENDED_TRANSFORM_HEADER_SIZE
+        } else {
+            TRANSFORM_HEADER_SIZE
+        }
+    }
+
+    /// Size of the header in bytes, determined from its first byte.
+    ///
+    /// Use this before [`TransformHeader::read_from_ptr`] to check enough bytes are available.
+    pub fn size_from_first_byte(first_byte: u8) -> usize {
+        if first_byte & 0x0F == EXTENDED_FORMAT_ESCAPE {
+            EXTENDED_TRANSFORM_HEADER_SIZE
+        } else {
+            TRANSFORM_HEADER_SIZE
+        }
+    }
+
     /// Read a transform header from a byte pointer.
     ///
     /// Reads the header as a little-endian [`u32`] value as specified in the format.
+    /// If the header is extended, the following 4 bytes are read as well.
     ///
     /// # Safety
     ///
     /// - `ptr` must be valid for reads of at least [`TRANSFORM_HEADER_SIZE`] bytes
+    /// - `ptr` must be valid for reads of [`TransformHeader::size_from_first_byte`] bytes
+    ///   (i.e. [`EXTENDED_TRANSFORM_HEADER_SIZE`] bytes if the header is extended)
     pub unsafe fn read_from_ptr(ptr: *const u8) -> Self {
-        let value = (ptr as *const u32).read_unaligned();
-        Self(u32::from_le(value))
+        let low = u32::from_le((ptr as *const u32).read_unaligned()) as u64;
+        let mut header = Self(low);
+        if header.is_extended() {
+            let high =
+                u32::from_le((ptr.add(TRANSFORM_HEADER_SIZE) as *const u32).read_unaligned());
+            header.0 |= (high as u64) << 32;
+        }
+
+        header
     }
 
     /// Write a transform header to a byte pointer.
     ///
     /// Writes the header as a little-endian [`u32`] value as specified in the format.
+    /// Extended headers are written as a little-endian [`u64`] value.
     ///
     /// # Safety
     ///
-    /// - `ptr` must be valid for writes of at least [`TRANSFORM_HEADER_SIZE`] bytes
+    /// - `ptr` must be valid for writes of at least [`TransformHeader::size`] bytes
     pub unsafe fn write_to_ptr(&self, ptr: *mut u8) {
-        (ptr as *mut u32).write_unaligned(self.0.to_le());
+        if self.is_extended() {
+            (ptr as *mut u64).write_unaligned(self.0.to_le());
+        } else {
+            (ptr as *mut u32).write_unaligned((self.0 as u32).to_le());
+        }
     }
 }
 
@@ -186,6 +357,7 @@ mod tests {
         );
         assert_eq!(TransformFormat::from_u8(0x08), Some(TransformFormat::Bc4));
         assert_eq!(TransformFormat::from_u8(0x09), Some(TransformFormat::Bc5));
+        assert_eq!(TransformFormat::from_u8(0x0A), Some(TransformFormat::None));
         assert_eq!(TransformFormat::from_u8(0x0F), None);
 
         assert_eq!(TransformFormat::Bc1.to_u8(), 0x00);
@@ -198,6 +370,7 @@ mod tests {
         assert_eq!(TransformFormat::Bgr888.to_u8(), 0x07);
         assert_eq!(TransformFormat::Bc4.to_u8(), 0x08);
         assert_eq!(TransformFormat::Bc5.to_u8(), 0x09);
+        assert_eq!(TransformFormat::None.to_u8(), 0x0A);
     }
 
     #[test]
@@ -254,4 +427,123 @@ mod tests {
             assert_eq!(read_back.format_data(), 0x1234567);
         }
     }
+
+    #[test]
+    fn test_standard_headers_decode_unchanged() {
+        // Bytes as written by versions predating extended headers.
+        let buffer = [0x73u8, 0x56, 0x34, 0x12];
+
+        let header = unsafe { TransformHeader::read_from_ptr(buffer.as_ptr()) };
+        assert!(!header.is_extended());
+        assert_eq!(header.size(), TRANSFORM_HEADER_SIZE);
+        assert_eq!(
+            TransformHeader::size_from_first_byte(buffer[0]),
+            TRANSFORM_HEADER_SIZE
+        );
+        assert_eq!(header.format(), Some(TransformFormat::Bc7));
+        assert_eq!(header.format_data(), 0x1234567);
+    }
+
+    #[test]
+    fn test_standard_header_write_only_touches_4_bytes() {
+        let mut buffer = [0xAAu8; EXTENDED_TRANSFORM_HEADER_SIZE];
+        let header = TransformHeader::new(TransformFormat::Bc1, 0x0FFFFFFF);
+
+        unsafe {
+            header.write_to_ptr(buffer.as_mut_ptr());
+        }
+
+        assert_eq!(&buffer[TRANSFORM_HEADER_SIZE..], &[0xAA; 4]);
+    }
+
+    #[test]
+    fn test_extended_header_read_write() {
+        let mut buffer = [0u8; EXTENDED_TRANSFORM_HEADER_SIZE];
+        let original = TransformHeader::new_extended(0x123, 0xDEADBEEF);
+        assert!(original.is_extended());
+        assert_eq!(original.size(), EXTENDED_TRANSFORM_HEADER_SIZE);
+
+        unsafe {
+            original.write_to_ptr(buffer.as_mut_ptr());
+        }
+
+        // Escape in the low nibble, extended code in the following 12 bits, data in the high u32
+        assert_eq!(buffer, [0x3F, 0x12, 0x00, 0x00, 0xEF, 0xBE, 0xAD, 0xDE]);
+        assert_eq!(
+            TransformHeader::size_from_first_byte(buffer[0]),
+            EXTENDED_TRANSFORM_HEADER_SIZE
+        );
+
+        let read_back = unsafe { TransformHeader::read_from_ptr(buffer.as_ptr()) };
+        assert_eq!(read_back, original);
+        assert_eq!(read_back.extended_format_raw(), 0x123);
+        assert_eq!(read_back.extended_data(), 0xDEADBEEF);
+    }
+
+    #[test]
+    fn test_extended_header_with_unknown_code_has_no_format() {
+        // No formats are assigned extended codes yet, so readers must not guess one.
+        let header = TransformHeader::new_extended(0x000, 0);
+        assert_eq!(header.format(), None);
+    }
+
+    #[test]
+    fn test_extended_header_with_reserved_bits_has_no_format() {
+        let mut header = TransformHeader::new_extended(0x000, 0);
+        header.set_extended_reserved(1);
+        assert_eq!(header.format(), None);
+    }
+
+    #[test]
+    fn test_checksum_flag_does_not_affect_format_details() {
+        use dxt_lossless_transform_bc1::Bc1TransformSettings;
+
+        let settings = Bc1TransformSettings::default();
+        let header = EmbeddableBc1Details::from_settings(settings).to_header();
+        assert!(!header.has_checksum());
+
+        let flagged = header.with_checksum(true);
+        assert!(flagged.has_checksum());
+        assert_eq!(flagged.format(), Some(TransformFormat::Bc1));
+        assert_eq!(
+            TransformDetails::from_header(flagged),
+            TransformDetails::Bc1(settings)
+        );
+        assert_eq!(flagged.with_checksum(false), header);
+
+        // Passthrough rejects non-zero reserved bits, so it must not see the flag either
+        let none = EmbeddableNoneDetails.to_header().with_checksum(true);
+        assert_eq!(TransformDetails::from_header(none), TransformDetails::None);
+    }
+
+    #[test]
+    fn test_truncated_flag_does_not_affect_format_details() {
+        use dxt_lossless_transform_bc2::Bc2TransformSettings;
+
+        let settings = Bc2TransformSettings::default();
+        let header = EmbeddableBc2Details::from_settings(settings).to_header();
+        assert!(!header.is_truncated());
+
+        let flagged = header.with_truncated(true).with_checksum(true);
+        assert!(flagged.is_truncated());
+        assert!(flagged.has_checksum());
+        assert_eq!(
+            TransformDetails::from_header(flagged),
+            TransformDetails::Bc2(settings)
+        );
+        assert_eq!(flagged.with_truncated(false).with_checksum(false), header);
+
+        let none = EmbeddableNoneDetails.to_header().with_truncated(true);
+        assert_eq!(TransformDetails::from_header(none), TransformDetails::None);
+        assert!(!TransformHeader::new_extended(0x000, 0)
+            .with_truncated(true)
+            .is_truncated());
+    }
+
+    #[test]
+    fn test_extended_header_has_no_checksum_flag() {
+        let header = TransformHeader::new_extended(0x000, 0).with_checksum(true);
+        assert!(!header.has_checksum());
+        assert_eq!(header.extended_reserved(), 0);
+    }
 }
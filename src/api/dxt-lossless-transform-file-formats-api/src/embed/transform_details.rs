@@ -0,0 +1,118 @@
+//! Decoded transform details, as stored in a [`TransformHeader`].
+
+use super::formats::{
+    EmbeddableBc1Details, EmbeddableBc2Details, EmbeddableNoneDetails, EmbeddableTransformDetails,
+};
+use super::{TransformFormat, TransformHeader};
+use dxt_lossless_transform_bc1::Bc1TransformSettings;
+use dxt_lossless_transform_bc2::Bc2TransformSettings;
+
+/// Transform settings decoded from a [`TransformHeader`].
+///
+/// This is mainly intended for inspecting already transformed files,
+/// see [`FileFormatInspection`].
+///
+/// [`FileFormatInspection`]: crate::handlers::FileFormatInspection
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformDetails {
+    /// BC1 data, transformed with the given settings
+    Bc1(Bc1TransformSettings),
+    /// BC2 data, transformed with the given settings
+    Bc2(Bc2TransformSettings),
+    /// Data was stored unchanged, see [`TransformFormat::None`]
+    None,
+    /// The header could not be decoded by this version of the library.
+    ///
+    /// Either the format is not known (e.g. written by a newer version), or the header is corrupted.
+    Unknown(TransformHeader),
+}
+
+impl TransformDetails {
+    /// Decode the transform details stored in a [`TransformHeader`].
+    ///
+    /// Headers which cannot be decoded are returned as [`TransformDetails::Unknown`].
+    pub fn from_header(header: TransformHeader) -> Self {
+        let details = match header.format() {
+            Some(TransformFormat::Bc1) => {
+                EmbeddableBc1Details::from_header(header).map(|x| Self::Bc1(x.to_settings()))
+            }
+            Some(TransformFormat::Bc2) => {
+                EmbeddableBc2Details::from_header(header).map(|x| Self::Bc2(x.to_settings()))
+            }
+            Some(TransformFormat::None) => {
+                EmbeddableNoneDetails::from_header(header).map(|_| Self::None)
+            }
+            _ => return Self::Unknown(header),
+        };
+
+        details.unwrap_or(Self::Unknown(header))
+    }
+
+    /// Returns the transform format of the details, or [`None`] if unknown.
+    pub fn format(&self) -> Option<TransformFormat> {
+        match self {
+            Self::Bc1(_) => Some(TransformFormat::Bc1),
+            Self::Bc2(_) => Some(TransformFormat::Bc2),
+            Self::None => Some(TransformFormat::None),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embed::EXTENDED_FORMAT_ESCAPE;
+
+    #[test]
+    fn decodes_bc1_settings() {
+        let settings = Bc1TransformSettings::default();
+        let header = EmbeddableBc1Details::from_settings(settings).to_header();
+
+        let details = TransformDetails::from_header(header);
+        assert_eq!(details, TransformDetails::Bc1(settings));
+        assert_eq!(details.format(), Some(TransformFormat::Bc1));
+    }
+
+    #[test]
+    fn decodes_bc2_settings() {
+        let settings = Bc2TransformSettings::default();
+        let header = EmbeddableBc2Details::from_settings(settings).to_header();
+
+        assert_eq!(
+            TransformDetails::from_header(header),
+            TransformDetails::Bc2(settings)
+        );
+    }
+
+    #[test]
+    fn decodes_passthrough() {
+        let header = EmbeddableNoneDetails.to_header();
+        assert_eq!(
+            TransformDetails::from_header(header),
+            TransformDetails::None
+        );
+    }
+
+    #[test]
+    fn unknown_headers_are_preserved() {
+        let mut buffer = [0u8; 8];
+        buffer[0] = EXTENDED_FORMAT_ESCAPE;
+        let header = unsafe { TransformHeader::read_from_ptr(buffer.as_ptr()) };
+
+        let details = TransformDetails::from_header(header);
+        assert_eq!(details, TransformDetails::Unknown(header));
+        assert_eq!(details.format(), None);
+    }
+
+    #[test]
+    fn corrupted_headers_are_unknown() {
+        // Passthrough header with reserved bits set
+        let header = TransformHeader::new(TransformFormat::None, 0x0FFF_FFF0);
+        assert_eq!(
+            TransformDetails::from_header(header),
+            TransformDetails::Unknown(header)
+        );
+    }
+}
@@ -2,8 +2,12 @@
 
 /// Represents the different transform formats that can be embedded.
 ///
-/// This enum uses 4 bits in the header, allowing for up to 16 different formats.
+/// Standard headers use 4 bits for the format, allowing for up to 15 different formats;
+/// the value [`EXTENDED_FORMAT_ESCAPE`] is reserved to signal an extended header, which
+/// provides a further 12 bits of extended format codes.
 /// Additional formats may be added in future versions.
+///
+/// [`EXTENDED_FORMAT_ESCAPE`]: super::EXTENDED_FORMAT_ESCAPE
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -28,6 +32,13 @@ pub enum TransformFormat {
     Bc4 = 0x08,
     /// BC5 format transform
     Bc5 = 0x09,
+    /// Passthrough; the data is stored unchanged.
+    ///
+    /// Used for formats which cannot be transformed, when enabled via
+    /// [`TransformBundle::with_passthrough_unsupported`].
+    ///
+    /// [`TransformBundle::with_passthrough_unsupported`]: crate::bundle::TransformBundle::with_passthrough_unsupported
+    None = 0x0A,
 }
 
 impl TransformFormat {
@@ -48,10 +59,33 @@ impl TransformFormat {
             0x07 => Some(Self::Bgr888),
             0x08 => Some(Self::Bc4),
             0x09 => Some(Self::Bc5),
+            0x0A => Some(Self::None),
             _ => None,
         }
     }
 
+    /// Convert from an extended format code (stored in extended headers).
+    ///
+    /// Returns [`None`] if the code does not correspond to a known transform format.
+    pub(super) fn from_extended_code(_code: u16) -> Option<Self> {
+        // No formats have been assigned extended codes yet.
+        None
+    }
+
+    /// Returns the size of a single block (or pixel, for uncompressed formats) in bytes.
+    ///
+    /// Texture data is transformed in units of this size. [`TransformFormat::None`] has a
+    /// block size of 1, as passthrough data is stored unchanged.
+    pub fn block_size(self) -> usize {
+        match self {
+            Self::Bc1 | Self::Bc4 => 8,
+            Self::Bc2 | Self::Bc3 | Self::Bc5 | Self::Bc6H | Self::Bc7 => 16,
+            Self::Rgba8888 | Self::Bgra8888 => 4,
+            Self::Bgr888 => 3,
+            Self::None => 1,
+        }
+    }
+
     /// Convert to u8 value
     pub(super) fn to_u8(self) -> u8 {
         match self {
@@ -65,6 +99,7 @@ impl TransformFormat {
             Self::Bgr888 => 0x07,
             Self::Bc4 => 0x08,
             Self::Bc5 => 0x09,
+            Self::None => 0x0A,
         }
     }
 }
@@ -27,6 +27,14 @@ pub enum FormatHandlerError {
     #[error("Invalid restored file header during untransform - file may be corrupted or wrong handler used")]
     InvalidRestoredFileHeader,
 
+    /// The input has already been transformed; transforming it again would make it unrecoverable
+    #[error("Input is already transformed")]
+    AlreadyTransformed,
+
+    /// The input has not been transformed (e.g. an original file); untransforming it would corrupt it
+    #[error("Input is not transformed")]
+    NotTransformed,
+
     /// Transform format is not yet implemented by this handler
     #[error("{0:?} format not yet implemented")]
     FormatNotImplemented(TransformFormat),
@@ -44,8 +52,32 @@ pub enum FormatHandlerError {
     InputTooShort { required: usize, actual: usize },
 
     /// Input buffer is too short for the texture size stated in the file header
+    ///
+    /// See [`TransformBundle::with_allow_truncated`] to transform such files anyway.
+    ///
+    /// [`TransformBundle::with_allow_truncated`]: crate::TransformBundle::with_allow_truncated
     #[error("Input buffer too short for stated texture size in header: required {required} bytes for texture data, got {actual} bytes")]
     InputTooShortForStatedTextureSize { required: usize, actual: usize },
+
+    /// The container header was written by a newer version of the library
+    #[error("Unsupported container version: {0}")]
+    UnsupportedContainerVersion(u8),
+
+    /// The handler only has room for a standard transform header, but an extended one was encountered
+    #[error("Extended transform headers are not supported by this file format")]
+    ExtendedHeaderNotSupported,
+
+    /// A checksum was requested, but the handler has no room to store it in this file
+    ///
+    /// See [`TransformBundle::with_checksum`].
+    ///
+    /// [`TransformBundle::with_checksum`]: crate::TransformBundle::with_checksum
+    #[error("Checksum requested, but this file has no room to store it")]
+    ChecksumUnsupported,
+
+    /// The handler changes the size of the data, so it cannot operate in place
+    #[error("In-place operation not supported: output would be {output} bytes, but buffer is {actual} bytes")]
+    InPlaceSizeMismatch { output: usize, actual: usize },
 }
 
 /// Errors that can occur during core transform operations
@@ -84,4 +116,18 @@ pub enum TransformError {
     /// No file format handler can process this file
     #[error("No file format handler can process the file")]
     NoSupportedHandler,
+
+    /// Checksum of the restored data does not match the checksum stored during transform
+    #[error("Checksum mismatch: expected {expected:#018x}, got {actual:#018x}")]
+    ChecksumMismatch { expected: u64, actual: u64 },
+
+    /// Untransforming the transformed data did not restore the original input.
+    ///
+    /// Returned when verification is enabled via [`TransformBundle::with_verify`].
+    ///
+    /// [`TransformBundle::with_verify`]: crate::bundle::TransformBundle::with_verify
+    #[error(
+        "Roundtrip verification failed: restored data differs from the input at offset {offset}"
+    )]
+    RoundtripMismatch { offset: usize },
 }
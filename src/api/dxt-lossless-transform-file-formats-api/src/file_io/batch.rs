@@ -0,0 +1,411 @@
+//! Batch operations for transforming many files at once.
+//!
+//! These take a list of [`BatchJob`]s (or a directory, see [`BatchJob::from_directory`]), and
+//! process each one with the first accepting handler of a [`HandlerRegistry`]. With the `rayon`
+//! feature, files are processed in parallel.
+//!
+//! Failures do not stop the batch; the outcome of every job is returned as a [`BatchFileResult`].
+
+use super::{transform_file_with_registry, untransform_file_with_registry};
+use crate::bundle::TransformBundle;
+use crate::file_io::{FileOperationError, FileOperationResult};
+use crate::handlers::HandlerRegistry;
+use core::fmt::Debug;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::vec::Vec;
+
+/// A single file to process in a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchJob {
+    /// Path of the file to read.
+    pub input: PathBuf,
+    /// Path of the file to write. Missing parent directories are created.
+    pub output: PathBuf,
+}
+
+impl BatchJob {
+    /// Create a new job processing `input` into `output`.
+    pub fn new(input: impl Into<PathBuf>, output: impl Into<PathBuf>) -> Self {
+        Self {
+            input: input.into(),
+            output: output.into(),
+        }
+    }
+
+    /// Create jobs for every file in `input_dir` (recursively), writing each one to the same
+    /// relative path in `output_dir`.
+    ///
+    /// Entries that cannot be read (e.g. due to permissions) are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input_dir` itself cannot be read.
+    pub fn from_directory(input_dir: &Path, output_dir: &Path) -> io::Result<Vec<Self>> {
+        let mut jobs = Vec::new();
+        collect_jobs(input_dir, input_dir, output_dir, &mut jobs, true)?;
+        Ok(jobs)
+    }
+}
+
+fn collect_jobs(
+    dir: &Path,
+    input_dir: &Path,
+    output_dir: &Path,
+    jobs: &mut Vec<BatchJob>,
+    is_root: bool,
+) -> io::Result<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if is_root => return Err(e),
+        Err(_) => return Ok(()), // Skip subdirectories that can't be read
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_jobs(&path, input_dir, output_dir, jobs, false)?;
+        } else if let Ok(relative) = path.strip_prefix(input_dir) {
+            let output = output_dir.join(relative);
+            jobs.push(BatchJob::new(path, output));
+        }
+    }
+    Ok(())
+}
+
+/// The outcome of a single [`BatchJob`].
+#[derive(Debug)]
+pub struct BatchFileResult {
+    /// The job that was processed.
+    pub job: BatchJob,
+    /// Size of the input file in bytes on success, or the error that occurred.
+    ///
+    /// Jobs skipped due to cancellation fail with [`FileOperationError::Cancelled`].
+    pub result: FileOperationResult<u64>,
+}
+
+/// Progress of a running batch, passed to the callback set via [`BatchOptions::with_progress`].
+#[derive(Debug, Clone, Copy)]
+pub struct BatchProgress<'a> {
+    /// Number of jobs finished so far (including this one).
+    pub completed: usize,
+    /// Total number of jobs in the batch.
+    pub total: usize,
+    /// The job that just finished.
+    pub job: &'a BatchJob,
+    /// Whether the job that just finished succeeded.
+    pub succeeded: bool,
+}
+
+/// Callback invoked after each job of a batch finishes.
+pub type BatchProgressCallback<'a> = &'a (dyn Fn(BatchProgress<'_>) + Sync);
+
+/// Callback polled before each job of a batch starts; returning `true` cancels the remaining jobs.
+pub type BatchCancellationCallback<'a> = &'a (dyn Fn() -> bool + Sync);
+
+/// Options for [`transform_batch`] and [`untransform_batch`].
+#[derive(Clone, Copy)]
+pub struct BatchOptions<'a> {
+    progress: Option<BatchProgressCallback<'a>>,
+    cancellation: Option<BatchCancellationCallback<'a>>,
+    parallel: bool,
+}
+
+impl Default for BatchOptions<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> BatchOptions<'a> {
+    /// Create options with no callbacks. Jobs are processed in parallel if the `rayon`
+    /// feature is enabled.
+    pub fn new() -> Self {
+        Self {
+            progress: None,
+            cancellation: None,
+            parallel: true,
+        }
+    }
+
+    /// Set a callback to be invoked after each job finishes.
+    ///
+    /// With the `rayon` feature, this may be called from multiple threads at once.
+    pub fn with_progress(mut self, progress: BatchProgressCallback<'a>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Set a callback which is polled before each job starts.
+    ///
+    /// Once it returns `true`, all jobs which have not started yet fail with
+    /// [`FileOperationError::Cancelled`]. Jobs already in progress are completed.
+    pub fn with_cancellation(mut self, cancellation: BatchCancellationCallback<'a>) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    /// Set whether jobs may be processed in parallel. Has no effect without the `rayon` feature.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancellation.is_some_and(|cancelled| cancelled())
+    }
+}
+
+/// Transform a batch of files using the first accepting handler in a [`HandlerRegistry`].
+///
+/// # Arguments
+///
+/// * `jobs` - The files to transform
+/// * `registry` - The registry of file format handlers to try, see [`transform_file_with_registry`]
+/// * `bundle` - The transform bundle containing BCx builders
+/// * `options` - Progress and cancellation callbacks
+///
+/// # Returns
+///
+/// One [`BatchFileResult`] per job, in the same order as `jobs`.
+///
+/// # Example
+///
+/// ```no_run
+/// use dxt_lossless_transform_file_formats_api::{
+///     file_io::{transform_batch, BatchJob, BatchOptions},
+///     HandlerRegistry, TransformBundle
+/// };
+/// use dxt_lossless_transform_dds::DdsHandler;
+/// use dxt_lossless_transform_api_common::estimate::NoEstimation;
+/// use std::path::Path;
+///
+/// # fn example() -> std::io::Result<()> {
+/// let jobs = BatchJob::from_directory(Path::new("textures"), Path::new("transformed"))?;
+/// let registry = HandlerRegistry::<NoEstimation>::new().with_handler(DdsHandler, &["dds"]);
+/// let bundle = TransformBundle::<NoEstimation>::default_all();
+/// let progress = |p: dxt_lossless_transform_file_formats_api::file_io::BatchProgress<'_>| {
+///     println!("{}/{}: {}", p.completed, p.total, p.job.input.display());
+/// };
+///
+/// let results = transform_batch(&jobs, &registry, &bundle, &BatchOptions::new().with_progress(&progress));
+/// for failed in results.iter().filter(|x| x.result.is_err()) {
+///     eprintln!("{}: {:?}", failed.job.input.display(), failed.result);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn transform_batch<T>(
+    jobs: &[BatchJob],
+    registry: &HandlerRegistry<T>,
+    bundle: &TransformBundle<T>,
+    options: &BatchOptions<'_>,
+) -> Vec<BatchFileResult>
+where
+    T: SizeEstimationOperations + Sync,
+    T::Error: Debug,
+{
+    run_batch(jobs, options, |job| {
+        transform_file_with_registry(registry, &job.input, &job.output, bundle).map(|_| ())
+    })
+}
+
+/// Untransform a batch of files using the first accepting handler in a [`HandlerRegistry`].
+///
+/// # Arguments
+///
+/// * `jobs` - The files to untransform
+/// * `registry` - The registry of file format handlers to try, see [`untransform_file_with_registry`]
+/// * `options` - Progress and cancellation callbacks
+///
+/// # Returns
+///
+/// One [`BatchFileResult`] per job, in the same order as `jobs`.
+pub fn untransform_batch<T>(
+    jobs: &[BatchJob],
+    registry: &HandlerRegistry<T>,
+    options: &BatchOptions<'_>,
+) -> Vec<BatchFileResult>
+where
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    run_batch(jobs, options, |job| {
+        untransform_file_with_registry(registry, &job.input, &job.output).map(|_| ())
+    })
+}
+
+fn run_batch<F>(jobs: &[BatchJob], options: &BatchOptions<'_>, process: F) -> Vec<BatchFileResult>
+where
+    F: Fn(&BatchJob) -> FileOperationResult<()> + Sync,
+{
+    let completed = AtomicUsize::new(0);
+    let run_job = |job: &BatchJob| {
+        let result = if options.is_cancelled() {
+            Err(FileOperationError::Cancelled)
+        } else {
+            process_job(job, &process)
+        };
+
+        if let Some(progress) = options.progress {
+            progress(BatchProgress {
+                completed: completed.fetch_add(1, Ordering::Relaxed) + 1,
+                total: jobs.len(),
+                job,
+                succeeded: result.is_ok(),
+            });
+        }
+
+        BatchFileResult {
+            job: job.clone(),
+            result,
+        }
+    };
+
+    #[cfg(feature = "rayon")]
+    if options.parallel {
+        use rayon::prelude::*;
+        return jobs
+            .par_iter()
+            // 1 item at once per thread. Files are generally big and take time to process,
+            // so 'max work stealing' is preferred.
+            .with_max_len(1)
+            .map(run_job)
+            .collect();
+    }
+
+    jobs.iter().map(run_job).collect()
+}
+
+fn process_job<F>(job: &BatchJob, process: &F) -> FileOperationResult<u64>
+where
+    F: Fn(&BatchJob) -> FileOperationResult<()>,
+{
+    if let Some(parent) = job.output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let bytes = fs::metadata(&job.input)?.len();
+    process(job)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TransformError;
+    use crate::test_prelude::*;
+    use dxt_lossless_transform_api_common::estimate::NoEstimation;
+    use std::sync::Mutex;
+    use std::vec;
+
+    fn registry() -> HandlerRegistry<NoEstimation> {
+        HandlerRegistry::new().with_handler(MockHandler::new_accepting("dds"), &["dds"])
+    }
+
+    fn create_input_dir(files: &[&str]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for file in files {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, create_test_data(64)).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn from_directory_maps_relative_paths() {
+        let input = create_input_dir(&["a.dds", "nested/b.dds"]);
+        let output = Path::new("out");
+
+        let mut jobs = BatchJob::from_directory(input.path(), output).unwrap();
+        jobs.sort_by(|a, b| a.input.cmp(&b.input));
+        assert_eq!(
+            jobs,
+            vec![
+                BatchJob::new(input.path().join("a.dds"), output.join("a.dds")),
+                BatchJob::new(
+                    input.path().join("nested/b.dds"),
+                    output.join("nested/b.dds")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_directory_fails_for_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let result = BatchJob::from_directory(&dir.path().join("missing"), Path::new("out"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn transform_batch_reports_per_file_results_in_order() {
+        let input = create_input_dir(&["a.dds", "nested/b.dds", "c.png"]);
+        let output = tempfile::tempdir().unwrap();
+        let jobs = vec![
+            BatchJob::new(input.path().join("a.dds"), output.path().join("a.dds")),
+            BatchJob::new(input.path().join("c.png"), output.path().join("c.png")),
+            BatchJob::new(
+                input.path().join("nested/b.dds"),
+                output.path().join("nested/b.dds"),
+            ),
+        ];
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let results = transform_batch(&jobs, &registry(), &bundle, &BatchOptions::new());
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].job, jobs[0]);
+        assert_eq!(results[0].result.as_ref().unwrap(), &64);
+        assert!(matches!(
+            results[1].result,
+            Err(FileOperationError::Transform(
+                TransformError::NoSupportedHandler
+            ))
+        ));
+        assert!(results[2].result.is_ok());
+        assert!(output.path().join("nested/b.dds").exists());
+    }
+
+    #[test]
+    fn untransform_batch_reports_progress() {
+        let input = create_input_dir(&["a.dds", "b.dds"]);
+        let output = tempfile::tempdir().unwrap();
+        let jobs = BatchJob::from_directory(input.path(), output.path()).unwrap();
+
+        let reported = Mutex::new(Vec::new());
+        let progress = |p: BatchProgress<'_>| {
+            assert_eq!(p.total, 2);
+            assert!(p.succeeded);
+            reported.lock().unwrap().push(p.completed);
+        };
+        let options = BatchOptions::new().with_progress(&progress);
+
+        let results = untransform_batch(&jobs, &registry(), &options);
+        assert!(results.iter().all(|x| x.result.is_ok()));
+
+        let mut reported = reported.into_inner().unwrap();
+        reported.sort();
+        assert_eq!(reported, vec![1, 2]);
+    }
+
+    #[test]
+    fn transform_batch_cancellation_skips_remaining_jobs() {
+        let input = create_input_dir(&["a.dds", "b.dds", "c.dds"]);
+        let output = tempfile::tempdir().unwrap();
+        let jobs = BatchJob::from_directory(input.path(), output.path()).unwrap();
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let cancel = || true;
+        let options = BatchOptions::new().with_cancellation(&cancel);
+
+        let results = transform_batch(&jobs, &registry(), &bundle, &options);
+        assert!(results
+            .iter()
+            .all(|x| matches!(x.result, Err(FileOperationError::Cancelled))));
+        assert!(jobs.iter().all(|x| !x.output.exists()));
+    }
+}
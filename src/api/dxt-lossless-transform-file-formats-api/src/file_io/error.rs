@@ -19,6 +19,12 @@ pub enum FileOperationError {
     /// Transform operation failed
     #[error("Transform operation failed: {0}")]
     Transform(#[from] TransformError),
+
+    /// Operation was cancelled before it started (see [`BatchOptions::with_cancellation`])
+    ///
+    /// [`BatchOptions::with_cancellation`]: crate::file_io::BatchOptions::with_cancellation
+    #[error("Operation was cancelled")]
+    Cancelled,
 }
 
 /// Specific backend-related errors that can occur during file I/O operations.
@@ -50,6 +56,13 @@ pub enum FileIoError {
 }
 
 // Direct From implementations for specific error types used with ? operator in file operations
+#[cfg(feature = "std")]
+impl From<std::io::Error> for FileOperationError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(FileIoError::Std(e))
+    }
+}
+
 #[cfg(feature = "lightweight-mmap")]
 impl From<lightweight_mmap::handles::HandleOpenError> for FileOperationError {
     fn from(e: lightweight_mmap::handles::HandleOpenError) -> Self {
@@ -2,7 +2,11 @@
 
 use crate::bundle::TransformBundle;
 use crate::file_io::FileOperationResult;
-use crate::handlers::{FileFormatDetection, FileFormatHandler, FileFormatUntransformDetection};
+use crate::handlers::{
+    verify_roundtrip, DynFileFormatHandler, FileFormatDetection, FileFormatHandler,
+    FileFormatUntransformDetection, HandlerRegistry,
+};
+use crate::report::{Stopwatch, TransformReport};
 use crate::TransformError;
 use core::fmt::Debug;
 use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
@@ -24,7 +28,8 @@ use std::path::Path;
 ///
 /// # Returns
 ///
-/// Result indicating success or error
+/// Result indicating success or error.
+/// If the bundle requests verification and it fails, the output file is deleted.
 pub fn transform_file_with_handler<H: FileFormatHandler, T>(
     handler: &H,
     input_path: &Path,
@@ -35,22 +40,52 @@ where
     T: SizeEstimationOperations,
     T::Error: Debug,
 {
+    transform_file_with_report(handler, input_path, output_path, bundle)?;
+    Ok(())
+}
+
+/// Transform a file using a specific handler and transform bundle,
+/// returning a report describing the transform.
+///
+/// Same as [`transform_file_with_handler`], but returns a [`TransformReport`];
+/// see [`crate::api::transform_slice_with_report`]. The reported time includes file I/O.
+///
+/// # Arguments
+///
+/// * `handler` - The file format handler to use
+/// * `input_path` - Path to the input file
+/// * `output_path` - Path to the output file (will be created). The output directory must exist.
+/// * `bundle` - The transform bundle containing BCx builders
+///
+/// # Returns
+///
+/// The [`TransformReport`] on success.
+/// If the bundle requests verification and it fails, the output file is deleted.
+pub fn transform_file_with_report<H: FileFormatHandler, T>(
+    handler: &H,
+    input_path: &Path,
+    output_path: &Path,
+    bundle: &TransformBundle<T>,
+) -> FileOperationResult<TransformReport>
+where
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    let stopwatch = Stopwatch::start();
+
     // Open input file
     let input_handle = ReadOnlyFileHandle::open(input_path)?;
     let input_size = input_handle.size()? as usize;
     let input_mapping = ReadOnlyMmap::new(&input_handle, 0, input_size)?;
-    let output_handle = ReadWriteFileHandle::create_preallocated(output_path, input_size as i64)?;
-    let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, input_size)?;
+    let output_size = handler.transformed_size(input_mapping.as_slice());
 
     // Transform directly into the memory-mapped output
-    crate::api::transform_slice_with_bundle(
-        handler,
-        input_mapping.as_slice(),
-        output_mapping.as_mut_slice(),
-        bundle,
-    )?;
+    let mut report = super::transform_to_new_file(output_path, output_size, |output| {
+        crate::api::transform_slice_with_report(handler, input_mapping.as_slice(), output, bundle)
+    })?;
 
-    Ok(())
+    report.elapsed = stopwatch.elapsed();
+    Ok(report)
 }
 
 /// Untransform a file using a specific handler.
@@ -77,8 +112,9 @@ pub fn untransform_file_with_handler<H: FileFormatHandler>(
     let input_size = input_handle.size()? as usize;
     let input_mapping = ReadOnlyMmap::new(&input_handle, 0, input_size)?;
 
-    let output_handle = ReadWriteFileHandle::create_preallocated(output_path, input_size as i64)?;
-    let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, input_size)?;
+    let output_size = handler.untransformed_size(input_mapping.as_slice());
+    let output_handle = ReadWriteFileHandle::create_preallocated(output_path, output_size as i64)?;
+    let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, output_size)?;
 
     // Untransform directly into the memory-mapped output
     crate::api::untransform_slice(
@@ -106,6 +142,7 @@ pub fn untransform_file_with_handler<H: FileFormatHandler>(
 ///
 /// Result containing the handler that was used, or [`TransformError::NoSupportedHandler`]
 /// if no handler can process the file.
+/// If the bundle requests verification and it fails, the output file is deleted.
 ///
 /// # Example
 ///
@@ -153,18 +190,11 @@ where
     // Try each handler until one accepts the file
     for handler in handlers {
         if handler.can_handle(input_data, file_extension_ref) {
-            // Create output file with same size as input
-            let output_handle =
-                ReadWriteFileHandle::create_preallocated(output_path, input_size as i64)?;
-            let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, input_size)?;
-
-            // Transform using the accepting handler
-            crate::api::transform_slice_with_bundle(
-                &handler,
-                input_data,
-                output_mapping.as_mut_slice(),
-                bundle,
-            )?;
+            // Transform into an output file sized for the handler's output
+            let output_size = handler.transformed_size(input_data);
+            super::transform_to_new_file(output_path, output_size, |output| {
+                crate::api::transform_slice_with_bundle(&handler, input_data, output, bundle)
+            })?;
 
             return Ok(handler);
         }
@@ -231,10 +261,11 @@ where
     // Try each handler until one accepts the file
     for handler in handlers {
         if handler.can_handle_untransform(input_data, file_extension_ref) {
-            // Create output file with same size as input
+            // Create output file sized for the handler's output
+            let output_size = handler.untransformed_size(input_data);
             let output_handle =
-                ReadWriteFileHandle::create_preallocated(output_path, input_size as i64)?;
-            let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, input_size)?;
+                ReadWriteFileHandle::create_preallocated(output_path, output_size as i64)?;
+            let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, output_size)?;
 
             // Untransform using the accepting handler
             crate::api::untransform_slice(&handler, input_data, output_mapping.as_mut_slice())?;
@@ -247,6 +278,135 @@ where
     Err(TransformError::NoSupportedHandler.into())
 }
 
+/// Transform a file using the first handler in a [`HandlerRegistry`] that accepts it.
+///
+/// Handlers registered for the input file's extension are tried first;
+/// see [`HandlerRegistry`] for details.
+///
+/// # Arguments
+///
+/// * `registry` - The registry of file format handlers to try
+/// * `input_path` - Path to the input file
+/// * `output_path` - Path to the output file (will be created). The output directory must exist.
+/// * `bundle` - The transform bundle containing BCx builders
+///
+/// # Returns
+///
+/// Result containing the handler that was used, or [`TransformError::NoSupportedHandler`]
+/// if no handler can process the file. If the bundle requests verification and it fails, the
+/// output file is deleted and [`TransformError::RoundtripMismatch`] is returned.
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::{
+///     file_io::{transform_file_with_registry, FileOperationResult},
+///     HandlerRegistry, TransformBundle
+/// };
+/// use dxt_lossless_transform_dds::DdsHandler;
+/// use dxt_lossless_transform_api_common::estimate::NoEstimation;
+/// use std::path::Path;
+///
+/// fn example_transform_file_with_registry(
+///     input_path: &Path,
+///     output_path: &Path
+/// ) -> FileOperationResult<()> {
+///     let registry = HandlerRegistry::<NoEstimation>::new().with_handler(DdsHandler, &["dds"]);
+///     let bundle = TransformBundle::<NoEstimation>::default_all();
+///     transform_file_with_registry(&registry, input_path, output_path, &bundle)?;
+///     Ok(())
+/// }
+/// ```
+pub fn transform_file_with_registry<'a, SizeEstimator>(
+    registry: &'a HandlerRegistry<SizeEstimator>,
+    input_path: &Path,
+    output_path: &Path,
+    bundle: &TransformBundle<SizeEstimator>,
+) -> FileOperationResult<&'a dyn DynFileFormatHandler<SizeEstimator>>
+where
+    SizeEstimator: SizeEstimationOperations,
+    SizeEstimator::Error: Debug,
+{
+    // Open input file and read data for format detection
+    let input_handle = ReadOnlyFileHandle::open(input_path)?;
+    let input_size = input_handle.size()? as usize;
+    let input_mapping = ReadOnlyMmap::new(&input_handle, 0, input_size)?;
+    let input_data = input_mapping.as_slice();
+
+    // Extract file extension from input path for handler priority and faster detection
+    let file_extension = super::extract_lowercase_extension(input_path);
+    let file_extension_ref = file_extension.as_deref();
+
+    let handler = registry
+        .find_transform_handler(input_data, file_extension_ref)
+        .ok_or(TransformError::NoSupportedHandler)?;
+
+    // Transform into an output file sized for the handler's output
+    let output_size = handler.dyn_transformed_size(input_data);
+    super::transform_to_new_file(output_path, output_size, |output| {
+        handler.dyn_transform_bundle(input_data, output, bundle)?;
+        if bundle.verify() {
+            verify_roundtrip(
+                input_data,
+                output,
+                handler.dyn_untransformed_size(output),
+                |input, output| handler.dyn_untransform(input, output),
+            )?;
+        }
+        Ok(())
+    })?;
+
+    Ok(handler)
+}
+
+/// Untransform a file using the first handler in a [`HandlerRegistry`] that accepts it.
+///
+/// Handlers registered for the input file's extension are tried first;
+/// see [`HandlerRegistry`] for details.
+///
+/// # Arguments
+///
+/// * `registry` - The registry of file format handlers to try
+/// * `input_path` - Path to the input file (containing transformed data)
+/// * `output_path` - Path to the output file (will be created). The output directory must exist.
+///
+/// # Returns
+///
+/// Result containing the handler that was used, or [`TransformError::NoSupportedHandler`]
+/// if no handler can process the file.
+pub fn untransform_file_with_registry<'a, SizeEstimator>(
+    registry: &'a HandlerRegistry<SizeEstimator>,
+    input_path: &Path,
+    output_path: &Path,
+) -> FileOperationResult<&'a dyn DynFileFormatHandler<SizeEstimator>>
+where
+    SizeEstimator: SizeEstimationOperations,
+    SizeEstimator::Error: Debug,
+{
+    // Open input file and read data for format detection
+    let input_handle = ReadOnlyFileHandle::open(input_path)?;
+    let input_size = input_handle.size()? as usize;
+    let input_mapping = ReadOnlyMmap::new(&input_handle, 0, input_size)?;
+    let input_data = input_mapping.as_slice();
+
+    // Extract file extension from input path for handler priority and faster detection
+    let file_extension = super::extract_lowercase_extension(input_path);
+    let file_extension_ref = file_extension.as_deref();
+
+    let handler = registry
+        .find_untransform_handler(input_data, file_extension_ref)
+        .ok_or(TransformError::NoSupportedHandler)?;
+
+    // Create output file sized for the handler's output
+    let output_size = handler.dyn_untransformed_size(input_data);
+    let output_handle = ReadWriteFileHandle::create_preallocated(output_path, output_size as i64)?;
+    let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, output_size)?;
+
+    // Untransform using the accepting handler
+    handler.dyn_untransform(input_data, output_mapping.as_mut_slice())?;
+    Ok(handler)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_prelude::*;
@@ -429,4 +589,109 @@ mod tests {
             false, // is_transform
         );
     }
+
+    #[test]
+    fn transform_with_registry_succeeds_on_extension_match() {
+        let handler = MockHandler::new_accepting("dds");
+        let registry =
+            HandlerRegistry::<NoEstimation>::new().with_handler(handler.clone(), &["dds"]);
+        let input_data = create_test_data(64);
+        let input_file = create_input_file_with_data_and_extension(&input_data, Some("dds"));
+        let output_file = create_output_file();
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        run_extension_test(
+            &handler,
+            || {
+                transform_file_with_registry(
+                    &registry,
+                    input_file.path(),
+                    output_file.path(),
+                    &bundle,
+                )
+            },
+            "dds",
+            ExtensionTestResult::Success,
+            true, // is_transform
+        );
+        verify_file_operation_success(output_file.path(), input_data.len());
+    }
+
+    #[test]
+    fn untransform_with_registry_fails_on_extension_mismatch() {
+        let handler = MockHandler::new_accepting("dds");
+        let registry =
+            HandlerRegistry::<NoEstimation>::new().with_handler(handler.clone(), &["dds"]);
+        let input_data = create_test_data(64);
+        let input_file = create_input_file_with_data_and_extension(&input_data, Some("png"));
+        let output_file = create_output_file();
+
+        run_extension_test(
+            &handler,
+            || untransform_file_with_registry(&registry, input_file.path(), output_file.path()),
+            "png",
+            ExtensionTestResult::NoSupportedHandler,
+            false, // is_transform
+        );
+    }
+
+    #[test]
+    fn transform_with_registry_verify_detects_mismatch() {
+        let registry = HandlerRegistry::<NoEstimation>::new()
+            .with_handler(LossyHandler { corrupt_offset: 40 }, &["dds"]);
+        let input_file =
+            create_input_file_with_data_and_extension(&create_test_data(64), Some("dds"));
+        let output_file = create_output_file();
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_verify(true);
+
+        let result =
+            transform_file_with_registry(&registry, input_file.path(), output_file.path(), &bundle);
+        verify_roundtrip_mismatch_removes_output(result, output_file.path(), 40);
+    }
+
+    #[test]
+    fn transform_file_verify_mismatch_removes_output() {
+        let handler = LossyHandler { corrupt_offset: 12 };
+        let input_file = create_input_file_with_data_and_extension(&create_test_data(64), None);
+        let output_file = create_output_file();
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_verify(true);
+
+        let result =
+            transform_file_with_handler(&handler, input_file.path(), output_file.path(), &bundle);
+        verify_roundtrip_mismatch_removes_output(result, output_file.path(), 12);
+    }
+
+    #[test]
+    fn transform_with_multiple_handlers_verify_mismatch_removes_output() {
+        let handler = LossyHandler { corrupt_offset: 20 };
+        let input_file =
+            create_input_file_with_data_and_extension(&create_test_data(64), Some("dds"));
+        let output_file = create_output_file();
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_verify(true);
+
+        let result = transform_file_with_multiple_handlers(
+            [handler],
+            input_file.path(),
+            output_file.path(),
+            &bundle,
+        );
+        verify_roundtrip_mismatch_removes_output(result, output_file.path(), 20);
+    }
+
+    #[test]
+    fn transform_file_with_report_describes_transform() {
+        let handler = crate::raw::RawBlocksHandler::new(crate::embed::TransformFormat::Bc1);
+        let input_data = create_test_bc1_data(16);
+        let input_file = create_input_file_with_data_and_extension(&input_data, Some("bc1"));
+        let output_file = create_output_file();
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let report =
+            transform_file_with_report(&handler, input_file.path(), output_file.path(), &bundle)
+                .unwrap();
+        assert_eq!(report.format, Some(crate::embed::TransformFormat::Bc1));
+        assert_eq!(report.bytes_processed, input_data.len());
+        assert!(report.elapsed.is_some());
+        verify_file_operation_success(output_file.path(), handler.transformed_size(&input_data));
+    }
 }
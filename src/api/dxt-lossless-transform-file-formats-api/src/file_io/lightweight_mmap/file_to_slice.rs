@@ -47,10 +47,11 @@ where
     let input_mapping = ReadOnlyMmap::new(&input_handle, 0, input_size)?;
 
     // Check if output buffer is large enough
-    if input_size > output_data.len() {
+    let output_size = handler.transformed_size(input_mapping.as_slice());
+    if output_size > output_data.len() {
         return Err(
             TransformError::FormatHandler(FormatHandlerError::OutputBufferTooSmall {
-                required: input_size,
+                required: output_size,
                 actual: output_data.len(),
             })
             .into(),
@@ -61,7 +62,7 @@ where
     crate::api::transform_slice_with_bundle(
         handler,
         input_mapping.as_slice(),
-        &mut output_data[..input_size],
+        &mut output_data[..output_size],
         bundle,
     )?;
 
@@ -98,10 +99,11 @@ pub fn untransform_file_to_slice_with_handler<H: FileFormatHandler>(
     let input_mapping = ReadOnlyMmap::new(&input_handle, 0, input_size)?;
 
     // Check if output buffer is large enough
-    if input_size > output_data.len() {
+    let output_size = handler.untransformed_size(input_mapping.as_slice());
+    if output_size > output_data.len() {
         return Err(
             TransformError::FormatHandler(FormatHandlerError::OutputBufferTooSmall {
-                required: input_size,
+                required: output_size,
                 actual: output_data.len(),
             })
             .into(),
@@ -112,7 +114,7 @@ pub fn untransform_file_to_slice_with_handler<H: FileFormatHandler>(
     crate::api::untransform_slice(
         handler,
         input_mapping.as_slice(),
-        &mut output_data[..input_size],
+        &mut output_data[..output_size],
     )?;
 
     Ok(())
@@ -179,17 +181,6 @@ where
     let input_mapping = ReadOnlyMmap::new(&input_handle, 0, input_size)?;
     let input_data = input_mapping.as_slice();
 
-    // Check if output buffer is large enough
-    if input_size > output_data.len() {
-        return Err(
-            TransformError::FormatHandler(FormatHandlerError::OutputBufferTooSmall {
-                required: input_size,
-                actual: output_data.len(),
-            })
-            .into(),
-        );
-    }
-
     // Extract file extension from input path for faster format detection
     let file_extension = super::extract_lowercase_extension(input_path);
     let file_extension_ref = file_extension.as_deref();
@@ -197,11 +188,23 @@ where
     // Try each handler until one accepts the file
     for handler in handlers {
         if handler.can_handle(input_data, file_extension_ref) {
+            // Check if output buffer is large enough
+            let output_size = handler.transformed_size(input_data);
+            if output_size > output_data.len() {
+                return Err(TransformError::FormatHandler(
+                    FormatHandlerError::OutputBufferTooSmall {
+                        required: output_size,
+                        actual: output_data.len(),
+                    },
+                )
+                .into());
+            }
+
             // Transform using the accepting handler
             crate::api::transform_slice_with_bundle(
                 &handler,
                 input_data,
-                &mut output_data[..input_size],
+                &mut output_data[..output_size],
                 bundle,
             )?;
 
@@ -268,17 +271,6 @@ where
     let input_mapping = ReadOnlyMmap::new(&input_handle, 0, input_size)?;
     let input_data = input_mapping.as_slice();
 
-    // Check if output buffer is large enough
-    if input_size > output_data.len() {
-        return Err(
-            TransformError::FormatHandler(FormatHandlerError::OutputBufferTooSmall {
-                required: input_size,
-                actual: output_data.len(),
-            })
-            .into(),
-        );
-    }
-
     // Extract file extension from input path for faster format detection
     let file_extension = super::extract_lowercase_extension(input_path);
     let file_extension_ref = file_extension.as_deref();
@@ -286,8 +278,20 @@ where
     // Try each handler until one accepts the file
     for handler in handlers {
         if handler.can_handle_untransform(input_data, file_extension_ref) {
+            // Check if output buffer is large enough
+            let output_size = handler.untransformed_size(input_data);
+            if output_size > output_data.len() {
+                return Err(TransformError::FormatHandler(
+                    FormatHandlerError::OutputBufferTooSmall {
+                        required: output_size,
+                        actual: output_data.len(),
+                    },
+                )
+                .into());
+            }
+
             // Untransform using the accepting handler
-            crate::api::untransform_slice(&handler, input_data, &mut output_data[..input_size])?;
+            crate::api::untransform_slice(&handler, input_data, &mut output_data[..output_size])?;
 
             return Ok(handler);
         }
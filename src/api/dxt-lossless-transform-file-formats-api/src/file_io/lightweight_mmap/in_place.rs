@@ -0,0 +1,290 @@
+//! In-place file transformation operations using memory mapping.
+//!
+//! These map the file once as read-write and transform it in place, avoiding a second
+//! mapping (and file) for the output. Only handlers that do not change the size of the
+//! data (e.g. DDS) support in-place operations.
+
+use crate::bundle::TransformBundle;
+use crate::file_io::FileOperationResult;
+use crate::handlers::{FileFormatDetection, FileFormatHandler, FileFormatUntransformDetection};
+use crate::TransformError;
+use core::fmt::Debug;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use lightweight_mmap::handles::*;
+use lightweight_mmap::mmap::*;
+use std::path::Path;
+
+/// Transform a file in place using a specific handler and transform bundle.
+///
+/// This function memory-maps the file as read-write and transforms it in place,
+/// see [`crate::api::transform_slice_in_place`].
+///
+/// # Arguments
+///
+/// * `handler` - The file format handler to use
+/// * `path` - Path to the file to transform
+/// * `bundle` - The transform bundle containing BCx builders
+///
+/// # Returns
+///
+/// Result indicating success or error.
+/// If an error occurs, the file is left unchanged.
+pub fn transform_file_in_place_with_handler<H: FileFormatHandler, T>(
+    handler: &H,
+    path: &Path,
+    bundle: &TransformBundle<T>,
+) -> FileOperationResult<()>
+where
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    let handle = ReadWriteFileHandle::open(path)?;
+    let size = handle.size()? as usize;
+    let mut mapping = ReadWriteMmap::new(&handle, 0, size)?;
+
+    crate::api::transform_slice_in_place(handler, mapping.as_mut_slice(), bundle)?;
+    Ok(())
+}
+
+/// Untransform a file in place using a specific handler.
+///
+/// This function memory-maps the file as read-write and untransforms it in place,
+/// see [`crate::api::untransform_slice_in_place`].
+///
+/// # Arguments
+///
+/// * `handler` - The file format handler to use
+/// * `path` - Path to the file to untransform
+///
+/// # Returns
+///
+/// Result indicating success or error.
+/// If an error occurs, the file is left unchanged.
+pub fn untransform_file_in_place_with_handler<H: FileFormatHandler>(
+    handler: &H,
+    path: &Path,
+) -> FileOperationResult<()> {
+    let handle = ReadWriteFileHandle::open(path)?;
+    let size = handle.size()? as usize;
+    let mut mapping = ReadWriteMmap::new(&handle, 0, size)?;
+
+    crate::api::untransform_slice_in_place(handler, mapping.as_mut_slice())?;
+    Ok(())
+}
+
+/// Transform a file in place using multiple handlers with automatic format detection.
+///
+/// In-place counterpart of [`super::transform_file_with_multiple_handlers`].
+///
+/// # Arguments
+///
+/// * `handlers` - Iterator of file format handlers that implement [`FileFormatDetection`]
+/// * `path` - Path to the file to transform
+/// * `bundle` - The transform bundle containing BCx builders
+///
+/// # Returns
+///
+/// Result containing the handler that was used, or [`TransformError::NoSupportedHandler`]
+/// if no handler can process the file.
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::{
+///     file_io::{transform_file_in_place_with_multiple_handlers, FileOperationResult},
+///     TransformBundle
+/// };
+/// use dxt_lossless_transform_dds::DdsHandler;
+/// use dxt_lossless_transform_api_common::estimate::NoEstimation;
+/// use std::path::Path;
+///
+/// fn example_transform_file_in_place(path: &Path) -> FileOperationResult<DdsHandler> {
+///     let bundle = TransformBundle::<NoEstimation>::default_all();
+///     transform_file_in_place_with_multiple_handlers([DdsHandler], path, &bundle)
+/// }
+/// ```
+pub fn transform_file_in_place_with_multiple_handlers<HandlerIterator, Handler, SizeEstimator>(
+    handlers: HandlerIterator,
+    path: &Path,
+    bundle: &TransformBundle<SizeEstimator>,
+) -> FileOperationResult<Handler>
+where
+    HandlerIterator: IntoIterator<Item = Handler>,
+    Handler: FileFormatDetection,
+    SizeEstimator: SizeEstimationOperations,
+    SizeEstimator::Error: Debug,
+{
+    let handle = ReadWriteFileHandle::open(path)?;
+    let size = handle.size()? as usize;
+    let mut mapping = ReadWriteMmap::new(&handle, 0, size)?;
+    let data = mapping.as_mut_slice();
+
+    // Extract file extension from path for faster format detection
+    let file_extension = super::extract_lowercase_extension(path);
+    let file_extension_ref = file_extension.as_deref();
+
+    // Try each handler until one accepts the file
+    for handler in handlers {
+        if handler.can_handle(data, file_extension_ref) {
+            crate::api::transform_slice_in_place(&handler, data, bundle)?;
+            return Ok(handler);
+        }
+    }
+
+    // No handler could process the file
+    Err(TransformError::NoSupportedHandler.into())
+}
+
+/// Untransform a file in place using multiple handlers with automatic format detection.
+///
+/// In-place counterpart of [`super::untransform_file_with_multiple_handlers`].
+///
+/// # Arguments
+///
+/// * `handlers` - Iterator of file format handlers that implement [`FileFormatUntransformDetection`]
+/// * `path` - Path to the file to untransform
+///
+/// # Returns
+///
+/// Result containing the handler that was used, or [`TransformError::NoSupportedHandler`]
+/// if no handler can process the file.
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::file_io::{
+///     untransform_file_in_place_with_multiple_handlers,
+///     FileOperationResult
+/// };
+/// use dxt_lossless_transform_dds::DdsHandler;
+/// use std::path::Path;
+///
+/// fn example_untransform_file_in_place(path: &Path) -> FileOperationResult<DdsHandler> {
+///     untransform_file_in_place_with_multiple_handlers([DdsHandler], path)
+/// }
+/// ```
+pub fn untransform_file_in_place_with_multiple_handlers<HandlerIterator, Handler>(
+    handlers: HandlerIterator,
+    path: &Path,
+) -> FileOperationResult<Handler>
+where
+    HandlerIterator: IntoIterator<Item = Handler>,
+    Handler: FileFormatUntransformDetection,
+{
+    let handle = ReadWriteFileHandle::open(path)?;
+    let size = handle.size()? as usize;
+    let mut mapping = ReadWriteMmap::new(&handle, 0, size)?;
+    let data = mapping.as_mut_slice();
+
+    // Extract file extension from path for faster format detection
+    let file_extension = super::extract_lowercase_extension(path);
+    let file_extension_ref = file_extension.as_deref();
+
+    // Try each handler until one accepts the file
+    for handler in handlers {
+        if handler.can_handle_untransform(data, file_extension_ref) {
+            crate::api::untransform_slice_in_place(&handler, data)?;
+            return Ok(handler);
+        }
+    }
+
+    // No handler could process the file
+    Err(TransformError::NoSupportedHandler.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_prelude::*;
+    use super::*;
+    use crate::embed::TransformFormat;
+    use crate::error::FormatHandlerError;
+    use crate::file_io::FileOperationError;
+    use crate::raw::RawBlocksHandler;
+
+    #[test]
+    fn transform_file_in_place_succeeds_with_single_handler() {
+        let handler = MockHandler::new_extensionless_accepting();
+        let input_data = create_test_data(64);
+        let file = create_input_file_with_data_and_extension(&input_data, None);
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        run_single_handler_test(
+            &handler,
+            || transform_file_in_place_with_handler(&handler, file.path(), &bundle),
+            true,  // verify_transform_called
+            false, // verify_untransform_called
+        );
+
+        assert_eq!(read_file_contents(file.path()).unwrap(), input_data);
+    }
+
+    #[test]
+    fn untransform_file_in_place_succeeds_with_single_handler() {
+        let handler = MockHandler::new_extensionless_accepting();
+        let input_data = create_test_data(64);
+        let file = create_input_file_with_data_and_extension(&input_data, None);
+
+        run_single_handler_test(
+            &handler,
+            || untransform_file_in_place_with_handler(&handler, file.path()),
+            false, // verify_transform_called
+            true,  // verify_untransform_called
+        );
+
+        assert_eq!(read_file_contents(file.path()).unwrap(), input_data);
+    }
+
+    #[test]
+    fn transform_file_in_place_rejects_size_change_without_modifying_file() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let input_data = create_test_data(64);
+        let file = create_input_file_with_data_and_extension(&input_data, Some("bc1"));
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let result = transform_file_in_place_with_handler(&handler, file.path(), &bundle);
+        assert!(matches!(
+            result,
+            Err(FileOperationError::Transform(
+                TransformError::FormatHandler(FormatHandlerError::InPlaceSizeMismatch { .. })
+            ))
+        ));
+        assert_eq!(read_file_contents(file.path()).unwrap(), input_data);
+    }
+
+    #[test]
+    fn transform_in_place_with_multiple_handlers_succeeds_on_extension_match() {
+        let handler = MockHandler::new_accepting("dds");
+        let input_data = create_test_data(64);
+        let file = create_input_file_with_data_and_extension(&input_data, Some("dds"));
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        run_extension_test(
+            &handler,
+            || {
+                transform_file_in_place_with_multiple_handlers(
+                    [handler.clone()],
+                    file.path(),
+                    &bundle,
+                )
+            },
+            "dds",
+            ExtensionTestResult::Success,
+            true, // is_transform
+        );
+    }
+
+    #[test]
+    fn untransform_in_place_with_multiple_handlers_fails_on_extension_mismatch() {
+        let handler = MockHandler::new_accepting("dds");
+        let input_data = create_test_data(64);
+        let file = create_input_file_with_data_and_extension(&input_data, Some("png"));
+
+        run_extension_test(
+            &handler,
+            || untransform_file_in_place_with_multiple_handlers([handler.clone()], file.path()),
+            "png",
+            ExtensionTestResult::NoSupportedHandler,
+            false, // is_transform
+        );
+    }
+}
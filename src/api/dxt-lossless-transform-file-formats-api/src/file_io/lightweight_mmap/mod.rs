@@ -5,15 +5,21 @@
 //! - File to file transformations
 //! - File to slice transformations  
 //! - Slice to file transformations
+//! - In-place file transformations
 //!
 //! All operations use memory mapping for optimal performance and support both single
 //! handler and multiple handler (auto-detection) variants.
 
+use crate::file_io::FileOperationResult;
+use crate::{TransformError, TransformResult};
+use lightweight_mmap::handles::ReadWriteFileHandle;
+use lightweight_mmap::mmap::ReadWriteMmap;
 use std::path::Path;
 use std::string::String;
 
 pub mod file;
 pub mod file_to_slice;
+pub mod in_place;
 pub mod slice_to_file;
 
 #[cfg(test)]
@@ -22,8 +28,34 @@ pub mod test_prelude;
 // Re-export all public functions
 pub use file::*;
 pub use file_to_slice::*;
+pub use in_place::*;
 pub use slice_to_file::*;
 
+/// Creates the output file of a transform, sized `output_size`, and fills it using `transform`.
+///
+/// If `transform` fails verification ([`TransformError::RoundtripMismatch`]), the output file is
+/// deleted, as its contents can't be restored to the original data.
+pub(crate) fn transform_to_new_file<R>(
+    output_path: &Path,
+    output_size: usize,
+    transform: impl FnOnce(&mut [u8]) -> TransformResult<R>,
+) -> FileOperationResult<R> {
+    let output_handle = ReadWriteFileHandle::create_preallocated(output_path, output_size as i64)?;
+    let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, output_size)?;
+
+    match transform(output_mapping.as_mut_slice()) {
+        Ok(result) => Ok(result),
+        Err(e @ TransformError::RoundtripMismatch { .. }) => {
+            // Unmap and close the file first, so it can be deleted on all platforms
+            drop(output_mapping);
+            drop(output_handle);
+            std::fs::remove_file(output_path)?;
+            Err(e.into())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
 /// Extract file extension from a path and convert to lowercase.
 ///
 /// # Arguments
@@ -24,7 +24,8 @@ use std::path::Path;
 ///
 /// # Returns
 ///
-/// Result indicating success or error
+/// Result indicating success or error.
+/// If the bundle requests verification and it fails, the output file is deleted.
 pub fn transform_slice_to_file_with_handler<H: FileFormatHandler, T>(
     handler: &H,
     input_data: &[u8],
@@ -35,19 +36,12 @@ where
     T: SizeEstimationOperations,
     T::Error: Debug,
 {
-    let input_size = input_data.len();
-    let output_handle = ReadWriteFileHandle::create_preallocated(output_path, input_size as i64)?;
-    let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, input_size)?;
+    let output_size = handler.transformed_size(input_data);
 
     // Transform directly into the memory-mapped output
-    crate::api::transform_slice_with_bundle(
-        handler,
-        input_data,
-        output_mapping.as_mut_slice(),
-        bundle,
-    )?;
-
-    Ok(())
+    super::transform_to_new_file(output_path, output_size, |output| {
+        crate::api::transform_slice_with_bundle(handler, input_data, output, bundle)
+    })
 }
 
 /// Untransform a slice using a specific handler and write to a file.
@@ -69,9 +63,9 @@ pub fn untransform_slice_to_file_with_handler<H: FileFormatHandler>(
     input_data: &[u8],
     output_path: &Path,
 ) -> FileOperationResult<()> {
-    let input_size = input_data.len();
-    let output_handle = ReadWriteFileHandle::create_preallocated(output_path, input_size as i64)?;
-    let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, input_size)?;
+    let output_size = handler.untransformed_size(input_data);
+    let output_handle = ReadWriteFileHandle::create_preallocated(output_path, output_size as i64)?;
+    let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, output_size)?;
 
     // Untransform directly into the memory-mapped output
     crate::api::untransform_slice(handler, input_data, output_mapping.as_mut_slice())?;
@@ -96,6 +90,7 @@ pub fn untransform_slice_to_file_with_handler<H: FileFormatHandler>(
 ///
 /// Result containing the handler that was used, or [`TransformError::NoSupportedHandler`]
 /// if no handler can process the slice.
+/// If the bundle requests verification and it fails, the output file is deleted.
 ///
 /// # Example
 ///
@@ -140,18 +135,11 @@ where
     // Try each handler until one accepts the slice
     for handler in handlers {
         if handler.can_handle(input_data, file_extension) {
-            let input_size = input_data.len();
-            let output_handle =
-                ReadWriteFileHandle::create_preallocated(output_path, input_size as i64)?;
-            let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, input_size)?;
-
             // Transform using the accepting handler
-            crate::api::transform_slice_with_bundle(
-                &handler,
-                input_data,
-                output_mapping.as_mut_slice(),
-                bundle,
-            )?;
+            let output_size = handler.transformed_size(input_data);
+            super::transform_to_new_file(output_path, output_size, |output| {
+                crate::api::transform_slice_with_bundle(&handler, input_data, output, bundle)
+            })?;
 
             return Ok(handler);
         }
@@ -215,10 +203,10 @@ where
     // Try each handler until one accepts the slice
     for handler in handlers {
         if handler.can_handle_untransform(input_data, file_extension) {
-            let input_size = input_data.len();
+            let output_size = handler.untransformed_size(input_data);
             let output_handle =
-                ReadWriteFileHandle::create_preallocated(output_path, input_size as i64)?;
-            let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, input_size)?;
+                ReadWriteFileHandle::create_preallocated(output_path, output_size as i64)?;
+            let mut output_mapping = ReadWriteMmap::new(&output_handle, 0, output_size)?;
 
             // Untransform using the accepting handler
             crate::api::untransform_slice(&handler, input_data, output_mapping.as_mut_slice())?;
@@ -260,6 +248,37 @@ mod tests {
         verify_file_operation_success(output_file.path(), input_data.len());
     }
 
+    #[test]
+    fn transform_slice_to_file_verify_mismatch_removes_output() {
+        let handler = LossyHandler { corrupt_offset: 12 };
+        let output_file = create_output_file();
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_verify(true);
+
+        let result = transform_slice_to_file_with_handler(
+            &handler,
+            &create_test_data(64),
+            output_file.path(),
+            &bundle,
+        );
+        verify_roundtrip_mismatch_removes_output(result, output_file.path(), 12);
+    }
+
+    #[test]
+    fn transform_slice_to_file_with_multiple_handlers_verify_mismatch_removes_output() {
+        let handler = LossyHandler { corrupt_offset: 20 };
+        let output_file = create_output_file();
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_verify(true);
+
+        let result = transform_slice_to_file_with_multiple_handlers(
+            [handler],
+            &create_test_data(64),
+            output_file.path(),
+            &bundle,
+            Some("dds"),
+        );
+        verify_roundtrip_mismatch_removes_output(result, output_file.path(), 20);
+    }
+
     #[test]
     fn untransform_slice_to_file_succeeds_with_single_handler() {
         let handler = MockHandler::new_extensionless_accepting();
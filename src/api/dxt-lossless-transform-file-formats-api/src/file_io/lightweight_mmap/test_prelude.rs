@@ -48,6 +48,22 @@ pub fn verify_file_operation_success(output_path: &std::path::Path, expected_siz
     assert_eq!(output_data.len(), expected_size);
 }
 
+/// Helper to verify that a transform failed verification at `offset`, and that its output file
+/// was deleted.
+pub fn verify_roundtrip_mismatch_removes_output<R>(
+    result: FileOperationResult<R>,
+    output_path: &std::path::Path,
+    offset: usize,
+) {
+    assert!(matches!(
+        result,
+        Err(crate::file_io::FileOperationError::Transform(
+            TransformError::RoundtripMismatch { offset: actual }
+        )) if actual == offset
+    ));
+    assert!(!output_path.exists());
+}
+
 /// Helper to verify transform handler calls.
 pub fn verify_transform_handler_calls(
     handler: &MockHandler,
@@ -1,11 +1,19 @@
 //! File I/O operations for transform-aware file handling.
 //!
 //! This module provides memory-mapped file operations for transform and untransform operations
-//! using `lightweight-mmap` for better performance.
+//! using `lightweight-mmap` for better performance, as well as streaming operations for
+//! arbitrary [`std::io::Read`] and [`std::io::Write`] implementations, and batch operations over
+//! many files (optionally in parallel, with the `rayon` feature).
 
 mod error;
 pub use error::*;
 
+mod stream;
+pub use stream::*;
+
+mod batch;
+pub use batch::*;
+
 #[cfg(feature = "lightweight-mmap")]
 mod lightweight_mmap;
 
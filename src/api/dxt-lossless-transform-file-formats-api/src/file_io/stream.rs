@@ -0,0 +1,552 @@
+//! Streaming transform operations for [`Read`] and [`Write`] implementations.
+//!
+//! These allow transforming data which is not available as a file or slice, such as data coming
+//! from an archive reader, a pipe or the network, while only keeping the file headers and a bounded
+//! amount of texture data in memory.
+//!
+//! # Seeking
+//!
+//! Transformed texture data is stored as separate arrays (e.g. all colours, then all indices), so
+//! the transformed side of the operation must be seekable:
+//!
+//! - [`transform_reader_to_writer`] requires a [`Write`] + [`Seek`] output
+//! - [`untransform_reader_to_writer`] requires a [`Read`] + [`Seek`] input
+//!
+//! The original (untransformed) side may be any [`Read`] or [`Write`].
+
+use crate::bundle::TransformBundle;
+use crate::embed::{TransformDetails, TransformFormat, TransformHeader};
+use crate::error::{FormatHandlerError, TransformError};
+use crate::file_io::FileOperationResult;
+use crate::handlers::checksum::verify_checksum;
+use crate::handlers::dispatch::dispatch_transform_with_header;
+use crate::handlers::{dispatch_transform, dispatch_untransform, FileFormatStreaming};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use tempfile::SpooledTempFile;
+use xxhash_rust::xxh3::Xxh3Default;
+
+/// Default amount of texture data processed at once by the streaming operations, in bytes.
+///
+/// Streaming operations allocate two buffers of this size.
+pub const DEFAULT_STREAM_SEGMENT_SIZE: usize = 1024 * 1024;
+
+/// Segment sizes are rounded down to a multiple of this, so segments only contain whole blocks
+/// of every supported format.
+const SEGMENT_ALIGNMENT: usize = 16;
+
+/// Transform a file read from a [`Read`] and write the result to a [`Write`].
+///
+/// Same as [`transform_reader_to_writer_with_segment_size`] with [`DEFAULT_STREAM_SEGMENT_SIZE`].
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::{
+///     file_io::{transform_reader_to_writer, FileOperationResult},
+///     TransformBundle
+/// };
+/// use dxt_lossless_transform_dds::DdsHandler;
+/// use dxt_lossless_transform_api_common::estimate::NoEstimation;
+/// use std::io::{Cursor, Read};
+///
+/// fn example_transform_reader(reader: impl Read) -> FileOperationResult<Vec<u8>> {
+///     let bundle = TransformBundle::<NoEstimation>::default_all();
+///     let mut output = Cursor::new(Vec::new());
+///     transform_reader_to_writer(&DdsHandler, reader, &mut output, &bundle)?;
+///     Ok(output.into_inner())
+/// }
+/// ```
+pub fn transform_reader_to_writer<H, T, R, W>(
+    handler: &H,
+    reader: R,
+    writer: W,
+    bundle: &TransformBundle<T>,
+) -> FileOperationResult<()>
+where
+    H: FileFormatStreaming,
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+    R: Read,
+    W: Write + Seek,
+{
+    transform_reader_to_writer_with_segment_size(
+        handler,
+        reader,
+        writer,
+        bundle,
+        DEFAULT_STREAM_SEGMENT_SIZE,
+    )
+}
+
+/// Transform a file read from a [`Read`] and write the result to a [`Write`], processing at most
+/// `segment_size` bytes of texture data at once.
+///
+/// The file headers are read and parsed first, then the texture data is transformed segment by
+/// segment and any data after the texture data is copied verbatim. The output is written starting
+/// at the writer's current position.
+///
+/// For bundles with manual builders (or passthrough), the output is identical to
+/// [`FileFormatHandler::transform_bundle`]. Automatic builders may pick different settings, see
+/// the remarks below; the output still untransforms to the original file.
+///
+/// # Arguments
+///
+/// * `handler` - The file format handler to use
+/// * `reader` - Reader positioned at the start of the file
+/// * `writer` - Seekable writer to write the transformed file to
+/// * `bundle` - The transform bundle containing BCx builders
+/// * `segment_size` - Maximum amount of texture data to process at once, rounded down to a
+///   multiple of 16 bytes (minimum 16)
+///
+/// # Remarks
+///
+/// Automatic transform builders in the `bundle` only see the first segment when determining
+/// the best settings; those settings are then used for the entire texture.
+///
+/// If the `bundle` allows truncated files ([`TransformBundle::with_allow_truncated`]), the texture
+/// data is spooled before transforming it, as the layout of the transformed data depends on how
+/// much of it is present, which a [`Read`] can't tell up front. The spool is kept in memory up to
+/// `segment_size` bytes, and moved to a temporary file beyond that.
+///
+/// # Returns
+///
+/// Result indicating success or error. On error, the writer may contain partially written data.
+///
+/// [`FileFormatHandler::transform_bundle`]: crate::handlers::FileFormatHandler::transform_bundle
+pub fn transform_reader_to_writer_with_segment_size<H, T, R, W>(
+    handler: &H,
+    mut reader: R,
+    mut writer: W,
+    bundle: &TransformBundle<T>,
+    segment_size: usize,
+) -> FileOperationResult<()>
+where
+    H: FileFormatStreaming,
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+    R: Read,
+    W: Write + Seek,
+{
+    // Read and parse the file headers
+    let mut head = Vec::new();
+    (&mut reader)
+        .take(handler.max_header_size() as u64)
+        .read_to_end(&mut head)?;
+    let info = handler.inspect(&head)?;
+    match info.transform_details {
+        None => {}
+        Some(TransformDetails::Unknown(_)) => {
+            return Err(TransformError::from(FormatHandlerError::InvalidInputFileHeader).into())
+        }
+        Some(_) => return Err(TransformError::from(FormatHandlerError::AlreadyTransformed).into()),
+    }
+
+    let data_offset = info.data_offset;
+    if head.len() < data_offset {
+        return Err(TransformError::from(FormatHandlerError::InputTooShort {
+            required: data_offset,
+            actual: head.len(),
+        })
+        .into());
+    }
+
+    let format = match info.format {
+        Some(format) => format,
+        // Store formats we can't transform unchanged, if requested
+        None if bundle.passthrough_unsupported() => TransformFormat::None,
+        None => return Err(TransformError::from(FormatHandlerError::UnknownFileFormat).into()),
+    };
+
+    // Any texture data read along with the headers is consumed before the reader
+    let (headers, head_data) = head.split_at_mut(data_offset);
+    let mut payload = (&*head_data).chain(&mut reader);
+    let segment_size = align_segment_size(segment_size, SEGMENT_ALIGNMENT);
+
+    // Truncated files only transform the whole blocks present in the file, the rest is copied
+    // verbatim as leftover data. Finding out how much data is present requires reading all of it,
+    // so it is spooled; only up to a segment is kept in memory, the rest goes to a temporary file.
+    let mut spool = SpooledTempFile::new(segment_size);
+    let (format, data_length, truncated) = if bundle.allow_truncated() {
+        let available = io::copy(
+            &mut (&mut payload).take(info.data_length as u64),
+            &mut spool,
+        )?;
+        spool.rewind()?;
+        truncated_texture_data_length(format, info.data_length, available as usize)
+    } else {
+        (format, info.data_length, false)
+    };
+    let mut payload = (&mut spool).chain(payload);
+
+    let start = writer.stream_position()?;
+    let data_start = start + data_offset as u64;
+    let buffer_size = segment_size.min(data_length);
+    let mut input = vec![0u8; buffer_size];
+    let mut output = vec![0u8; buffer_size];
+
+    // The checksum is only known at the end; reserve room for it up front, as the transform
+    // header records whether it is present.
+    let store_checksum = bundle.checksum();
+    if store_checksum && !handler.embed_checksum(headers, 0) {
+        return Err(TransformError::from(FormatHandlerError::ChecksumUnsupported).into());
+    }
+    let mut hasher = Xxh3Default::new();
+
+    let mut header: Option<TransformHeader> = None;
+    let mut offset = 0;
+    loop {
+        let len = segment_size.min(data_length - offset);
+        let input = &mut input[..len];
+        let output = &mut output[..len];
+        read_texture_data(
+            &mut payload,
+            input,
+            data_offset + data_length,
+            data_offset + offset,
+        )?;
+        if store_checksum {
+            hasher.update(input);
+        }
+
+        let segment_header = match header {
+            Some(header) => {
+                dispatch_transform_with_header(header, input, output)?;
+                header
+            }
+            None => {
+                // Settings are determined from the first segment, then the headers can be written
+                let header = dispatch_transform(format, input, output, bundle)?
+                    .with_checksum(store_checksum)
+                    .with_truncated(truncated);
+                handler.embed_transform_header(headers, header)?;
+                writer.seek(SeekFrom::Start(start))?;
+                writer.write_all(headers)?;
+                header
+            }
+        };
+
+        write_segment(
+            &mut writer,
+            stream_widths(segment_header)?,
+            data_start,
+            data_length,
+            offset,
+            output,
+        )?;
+        header = Some(segment_header);
+
+        offset += len;
+        if offset >= data_length {
+            break;
+        }
+    }
+
+    // Now that all texture data was seen, store the actual checksum
+    if store_checksum {
+        if !handler.embed_checksum(headers, hasher.digest()) {
+            return Err(TransformError::from(FormatHandlerError::InvalidInputFileHeader).into());
+        }
+        writer.seek(SeekFrom::Start(start))?;
+        writer.write_all(headers)?;
+    }
+
+    // Copy leftover data after texture data verbatim
+    writer.seek(SeekFrom::Start(data_start + data_length as u64))?;
+    io::copy(&mut payload, &mut writer)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Untransform a file read from a [`Read`] and write the result to a [`Write`].
+///
+/// Same as [`untransform_reader_to_writer_with_segment_size`] with [`DEFAULT_STREAM_SEGMENT_SIZE`].
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::file_io::{
+///     untransform_reader_to_writer, FileOperationResult
+/// };
+/// use dxt_lossless_transform_dds::DdsHandler;
+/// use std::io::{Read, Seek, Write};
+///
+/// fn example_untransform_reader(
+///     reader: impl Read + Seek,
+///     writer: impl Write,
+/// ) -> FileOperationResult<()> {
+///     untransform_reader_to_writer(&DdsHandler, reader, writer)
+/// }
+/// ```
+pub fn untransform_reader_to_writer<H, R, W>(
+    handler: &H,
+    reader: R,
+    writer: W,
+) -> FileOperationResult<()>
+where
+    H: FileFormatStreaming,
+    R: Read + Seek,
+    W: Write,
+{
+    untransform_reader_to_writer_with_segment_size(
+        handler,
+        reader,
+        writer,
+        DEFAULT_STREAM_SEGMENT_SIZE,
+    )
+}
+
+/// Untransform a file read from a [`Read`] and write the result to a [`Write`], processing at most
+/// `segment_size` bytes of texture data at once.
+///
+/// The file headers are read and restored first, then the texture data is untransformed segment
+/// by segment and any data after the texture data is copied verbatim. The output is identical to
+/// [`FileFormatHandler::untransform`].
+///
+/// # Arguments
+///
+/// * `handler` - The file format handler to use
+/// * `reader` - Seekable reader positioned at the start of the transformed file
+/// * `writer` - Writer to write the original file to
+/// * `segment_size` - Maximum amount of texture data to process at once, rounded down to a
+///   multiple of the block size (minimum one block)
+///
+/// # Returns
+///
+/// Result indicating success or error. On error, the writer may contain partially written data.
+/// If a checksum was stored during transform, it is verified once all data has been written;
+/// [`TransformError::ChecksumMismatch`] then means the written data is corrupted.
+///
+/// [`FileFormatHandler::untransform`]: crate::handlers::FileFormatHandler::untransform
+pub fn untransform_reader_to_writer_with_segment_size<H, R, W>(
+    handler: &H,
+    mut reader: R,
+    mut writer: W,
+    segment_size: usize,
+) -> FileOperationResult<()>
+where
+    H: FileFormatStreaming,
+    R: Read + Seek,
+    W: Write,
+{
+    let start = reader.stream_position()?;
+    let total_length = reader.seek(SeekFrom::End(0))? - start;
+    reader.seek(SeekFrom::Start(start))?;
+
+    // Read and parse the file headers
+    let mut head = Vec::new();
+    (&mut reader)
+        .take(handler.max_header_size() as u64)
+        .read_to_end(&mut head)?;
+    let info = handler.inspect(&head)?;
+    if !info.is_transformed() {
+        return Err(TransformError::from(FormatHandlerError::NotTransformed).into());
+    }
+
+    let data_offset = info.data_offset;
+    if head.len() < data_offset {
+        return Err(TransformError::from(FormatHandlerError::InputTooShort {
+            required: data_offset,
+            actual: head.len(),
+        })
+        .into());
+    }
+
+    // Restore the original headers
+    head.truncate(data_offset);
+    let header = handler.restore_headers(&mut head)?;
+    let expected_checksum = if header.has_checksum() {
+        Some(handler.restore_checksum(&mut head)?)
+    } else {
+        None
+    };
+    let widths = stream_widths(header)?;
+    let block_size: usize = widths.iter().sum();
+
+    // Truncated files only hold the whole blocks present in the file, the rest is leftover data
+    let total_required = data_offset + info.data_length;
+    let data_length = match total_length as usize {
+        total if total >= total_required => info.data_length,
+        total if header.is_truncated() => {
+            let available = total - data_offset;
+            available - available % block_size
+        }
+        total => {
+            return Err(TransformError::from(
+                FormatHandlerError::InputTooShortForStatedTextureSize {
+                    required: total_required,
+                    actual: total,
+                },
+            )
+            .into())
+        }
+    };
+    if !data_length.is_multiple_of(block_size) {
+        return Err(TransformError::InvalidDataAlignment {
+            size: data_length,
+            required_divisor: block_size,
+        }
+        .into());
+    }
+    writer.write_all(&head)?;
+
+    let data_start = start + data_offset as u64;
+    let segment_size = align_segment_size(segment_size, block_size);
+    let buffer_size = segment_size.min(data_length);
+    let mut input = vec![0u8; buffer_size];
+    let mut output = vec![0u8; buffer_size];
+
+    let mut hasher = Xxh3Default::new();
+    let mut offset = 0;
+    while offset < data_length {
+        let len = segment_size.min(data_length - offset);
+        let input = &mut input[..len];
+        let output = &mut output[..len];
+
+        read_segment(&mut reader, widths, data_start, data_length, offset, input)?;
+        dispatch_untransform(header, input, output)?;
+        if expected_checksum.is_some() {
+            hasher.update(output);
+        }
+        writer.write_all(output)?;
+        offset += len;
+    }
+
+    // Copy leftover data after texture data verbatim
+    reader.seek(SeekFrom::Start(data_start + data_length as u64))?;
+    io::copy(&mut reader, &mut writer)?;
+    writer.flush()?;
+
+    if let Some(expected) = expected_checksum {
+        verify_checksum(expected, hasher.digest())?;
+    }
+    Ok(())
+}
+
+/// Returns the per-block size of each array in the transformed data, in order.
+///
+/// The transformed texture data consists of one array per entry, each holding
+/// `width` bytes for every block in the texture.
+fn stream_widths(header: TransformHeader) -> Result<&'static [usize], TransformError> {
+    match TransformDetails::from_header(header) {
+        TransformDetails::Bc1(settings) if settings.split_colour_endpoints => Ok(&[2, 2, 4]),
+        TransformDetails::Bc1(_) => Ok(&[4, 4]),
+        TransformDetails::Bc2(settings) if settings.split_colour_endpoints => Ok(&[8, 2, 2, 4]),
+        TransformDetails::Bc2(_) => Ok(&[8, 4, 4]),
+        TransformDetails::None => Ok(&[1]),
+        _ => Err(TransformError::UnknownTransformFormat),
+    }
+}
+
+/// Returns the format and length of the texture data to transform, and whether it is truncated,
+/// given the `available` amount of texture data.
+///
+/// If not even a single block of `format` is present, the data is transformed as
+/// [`TransformFormat::None`].
+fn truncated_texture_data_length(
+    format: TransformFormat,
+    data_length: usize,
+    available: usize,
+) -> (TransformFormat, usize, bool) {
+    if available >= data_length {
+        return (format, data_length, false);
+    }
+
+    let format = if available < format.block_size() {
+        TransformFormat::None
+    } else {
+        format
+    };
+    (format, available - available % format.block_size(), true)
+}
+
+/// Rounds `segment_size` down to a multiple of `alignment`, with a minimum of `alignment`.
+fn align_segment_size(segment_size: usize, alignment: usize) -> usize {
+    (segment_size / alignment).max(1) * alignment
+}
+
+/// Fills `buffer` with texture data, reporting a truncated stream as a format error.
+fn read_texture_data(
+    reader: &mut impl Read,
+    buffer: &mut [u8],
+    total_required: usize,
+    position: usize,
+) -> FileOperationResult<()> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => {
+                return Err(TransformError::from(
+                    FormatHandlerError::InputTooShortForStatedTextureSize {
+                        required: total_required,
+                        actual: position + filled,
+                    },
+                )
+                .into());
+            }
+            Ok(read) => filled += read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
+
+/// Writes a transformed segment, scattering each of its arrays to its place in the full texture data.
+fn write_segment(
+    writer: &mut (impl Write + Seek),
+    widths: &[usize],
+    data_start: u64,
+    data_length: usize,
+    offset: usize,
+    segment: &[u8],
+) -> io::Result<()> {
+    let block_size: usize = widths.iter().sum();
+    let first_block = offset / block_size;
+    let num_blocks = segment.len() / block_size;
+    let total_blocks = data_length / block_size;
+
+    let mut array_start = 0;
+    let mut segment_pos = 0;
+    for &width in widths {
+        let array_len = num_blocks * width;
+        writer.seek(SeekFrom::Start(
+            data_start + (array_start + first_block * width) as u64,
+        ))?;
+        writer.write_all(&segment[segment_pos..segment_pos + array_len])?;
+        array_start += total_blocks * width;
+        segment_pos += array_len;
+    }
+    Ok(())
+}
+
+/// Reads a transformed segment, gathering each of its arrays from the full texture data.
+fn read_segment(
+    reader: &mut (impl Read + Seek),
+    widths: &[usize],
+    data_start: u64,
+    data_length: usize,
+    offset: usize,
+    segment: &mut [u8],
+) -> io::Result<()> {
+    let block_size: usize = widths.iter().sum();
+    let first_block = offset / block_size;
+    let num_blocks = segment.len() / block_size;
+    let total_blocks = data_length / block_size;
+
+    let mut array_start = 0;
+    let mut segment_pos = 0;
+    for &width in widths {
+        let array_len = num_blocks * width;
+        reader.seek(SeekFrom::Start(
+            data_start + (array_start + first_block * width) as u64,
+        ))?;
+        reader.read_exact(&mut segment[segment_pos..segment_pos + array_len])?;
+        array_start += total_blocks * width;
+        segment_pos += array_len;
+    }
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Checksums of the original texture data.
+//!
+//! Handlers store these when [`TransformBundle::with_checksum`] is enabled, and verify them on
+//! untransform. All handlers use the same algorithm (xxh3), so checksums can be compared across
+//! formats and streaming/non-streaming operations.
+//!
+//! [`TransformBundle::with_checksum`]: crate::bundle::TransformBundle::with_checksum
+
+use crate::error::{TransformError, TransformResult};
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Computes the checksum of original texture data.
+#[inline]
+pub fn texture_checksum(data: &[u8]) -> u64 {
+    xxh3_64(data)
+}
+
+/// Verifies restored texture data against a checksum computed with [`texture_checksum`].
+///
+/// # Returns
+///
+/// [`TransformError::ChecksumMismatch`] if the checksum of `data` is not `expected`.
+pub fn verify_texture_checksum(expected: u64, data: &[u8]) -> TransformResult<()> {
+    verify_checksum(expected, texture_checksum(data))
+}
+
+/// Compares a checksum of restored data (e.g. computed incrementally) with the stored one.
+pub(crate) fn verify_checksum(expected: u64, actual: u64) -> TransformResult<()> {
+    if actual != expected {
+        return Err(TransformError::ChecksumMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_texture_checksum_detects_modified_data() {
+        let mut data = [0x55u8; 64];
+        let checksum = texture_checksum(&data);
+        assert!(verify_texture_checksum(checksum, &data).is_ok());
+
+        data[63] ^= 1;
+        assert!(matches!(
+            verify_texture_checksum(checksum, &data),
+            Err(TransformError::ChecksumMismatch { expected, .. }) if expected == checksum
+        ));
+    }
+}
@@ -6,10 +6,11 @@
 
 use crate::bundle::TransformBundle;
 use crate::embed::formats::{
-    EmbeddableBc1Details, EmbeddableBc2Details, EmbeddableTransformDetails,
+    EmbeddableBc1Details, EmbeddableBc2Details, EmbeddableNoneDetails, EmbeddableTransformDetails,
 };
-use crate::embed::{TransformFormat, TransformHeader};
+use crate::embed::{TransformDetails, TransformFormat, TransformHeader};
 use crate::error::{FormatHandlerError, TransformError, TransformResult};
+use crate::report::TransformReport;
 use core::fmt::Debug;
 use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 
@@ -91,6 +92,12 @@ pub fn dispatch_untransform(
                 );
             }
         }
+        Some(TransformFormat::None) => {
+            EmbeddableNoneDetails::from_header(header)?;
+
+            // Data was stored unchanged
+            output_texture_data[..input_texture_data.len()].copy_from_slice(input_texture_data);
+        }
         _ => {
             return Err(TransformError::UnknownTransformFormat);
         }
@@ -124,6 +131,10 @@ pub fn dispatch_untransform(
 ///
 /// Output buffer must be at least the same size as the input buffer.
 ///
+/// Formats without a builder in the `bundle` are rejected, unless
+/// [`TransformBundle::with_passthrough_unsupported`] is enabled, in which case the data is
+/// copied unchanged and a [`TransformFormat::None`] header is returned.
+///
 /// # Example
 ///
 /// See: `dxt-lossless-transform-dds` crate.
@@ -137,7 +148,100 @@ where
     T: SizeEstimationOperations,
     T::Error: Debug,
 {
-    bundle.dispatch_transform(format, input_texture_data, output_texture_data)
+    dispatch_transform_with_report(
+        format,
+        input_texture_data,
+        output_texture_data,
+        bundle,
+        &mut TransformReport::default(),
+    )
+}
+
+/// Dispatch transform operation based on the detected format, describing it in a report.
+///
+/// This is [`dispatch_transform`], but also fills in [`TransformReport::format`],
+/// [`TransformReport::details`] and [`TransformReport::candidates`]. Use this when implementing
+/// [`FileFormatHandler::transform_bundle_with_report`].
+///
+/// [`FileFormatHandler::transform_bundle_with_report`]: crate::handlers::FileFormatHandler::transform_bundle_with_report
+pub fn dispatch_transform_with_report<T>(
+    format: TransformFormat,
+    input_texture_data: &[u8],
+    output_texture_data: &mut [u8],
+    bundle: &TransformBundle<T>,
+    report: &mut TransformReport,
+) -> TransformResult<TransformHeader>
+where
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    bundle.dispatch_transform(format, input_texture_data, output_texture_data, report)
+}
+
+/// Transform texture data using the settings stored in an existing transform header.
+///
+/// This is the transform counterpart of [`dispatch_untransform`]; it is used when the settings were
+/// already determined (e.g. by [`dispatch_transform`] on an earlier part of the same texture),
+/// so the data can be transformed in multiple parts with identical settings.
+#[cfg_attr(not(feature = "file-io"), allow(dead_code))]
+pub(crate) fn dispatch_transform_with_header(
+    header: TransformHeader,
+    input_texture_data: &[u8],
+    output_texture_data: &mut [u8],
+) -> TransformResult<()> {
+    if output_texture_data.len() < input_texture_data.len() {
+        return Err(TransformError::FormatHandler(
+            FormatHandlerError::OutputBufferTooSmall {
+                required: input_texture_data.len(),
+                actual: output_texture_data.len(),
+            },
+        ));
+    }
+
+    match TransformDetails::from_header(header) {
+        TransformDetails::Bc1(settings) => {
+            if !input_texture_data.len().is_multiple_of(8) {
+                return Err(TransformError::InvalidDataAlignment {
+                    size: input_texture_data.len(),
+                    required_divisor: 8,
+                });
+            }
+
+            unsafe {
+                dxt_lossless_transform_bc1::transform_bc1_with_settings(
+                    input_texture_data.as_ptr(),
+                    output_texture_data.as_mut_ptr(),
+                    input_texture_data.len(),
+                    settings,
+                );
+            }
+        }
+        TransformDetails::Bc2(settings) => {
+            if !input_texture_data.len().is_multiple_of(16) {
+                return Err(TransformError::InvalidDataAlignment {
+                    size: input_texture_data.len(),
+                    required_divisor: 16,
+                });
+            }
+
+            unsafe {
+                dxt_lossless_transform_bc2::transform_bc2_with_settings(
+                    input_texture_data.as_ptr(),
+                    output_texture_data.as_mut_ptr(),
+                    input_texture_data.len(),
+                    settings,
+                );
+            }
+        }
+        TransformDetails::None => {
+            output_texture_data[..input_texture_data.len()].copy_from_slice(input_texture_data);
+        }
+        _ => {
+            return Err(TransformError::UnknownTransformFormat);
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -145,6 +249,9 @@ mod tests {
     use super::*;
     use crate::test_prelude::*;
     use alloc::vec;
+    use alloc::vec::Vec;
+    use dxt_lossless_transform_api_common::estimate::NoEstimation;
+    use rstest::rstest;
 
     #[test]
     fn test_dispatch_untransform_invalid_alignment() {
@@ -158,4 +265,82 @@ mod tests {
             Err(TransformError::InvalidDataAlignment { .. })
         ));
     }
+
+    #[test]
+    fn test_dispatch_untransform_rejects_extended_header() {
+        let mut buffer = [0u8; crate::embed::EXTENDED_TRANSFORM_HEADER_SIZE];
+        buffer[0] = crate::embed::EXTENDED_FORMAT_ESCAPE;
+        let header = unsafe { TransformHeader::read_from_ptr(buffer.as_ptr()) };
+        let input = vec![0u8; 16];
+        let mut output = vec![0u8; 16];
+
+        let result = dispatch_untransform(header, &input, &mut output);
+        assert!(matches!(
+            result,
+            Err(TransformError::UnknownTransformFormat)
+        ));
+    }
+
+    #[test]
+    fn test_dispatch_transform_rejects_unsupported_format_by_default() {
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+        let input = vec![0u8; 16];
+        let mut output = vec![0u8; 16];
+
+        let result = dispatch_transform(TransformFormat::Bc7, &input, &mut output, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::UnknownTransformFormat)
+        ));
+
+        let result = dispatch_transform(
+            TransformFormat::Bc1,
+            &input,
+            &mut output,
+            &TransformBundle::<NoEstimation>::new(),
+        );
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::NoBuilderForFormat(TransformFormat::Bc1)
+            ))
+        ));
+    }
+
+    #[rstest]
+    #[case::unsupported_format(TransformFormat::Bc7)]
+    #[case::missing_builder(TransformFormat::Bc2)]
+    #[case::explicit_none(TransformFormat::None)]
+    fn test_dispatch_passthrough_roundtrip(#[case] format: TransformFormat) {
+        let bundle = TransformBundle::<NoEstimation>::new()
+            .with_bc1_manual(dxt_lossless_transform_bc1_api::Bc1ManualTransformBuilder::new())
+            .with_passthrough_unsupported(true);
+        let input: Vec<u8> = (0..64).collect();
+        let mut transformed = vec![0u8; input.len()];
+
+        let header = dispatch_transform(format, &input, &mut transformed, &bundle).unwrap();
+        assert_eq!(header.format(), Some(TransformFormat::None));
+        assert_eq!(transformed, input);
+
+        let mut restored = vec![0u8; input.len()];
+        dispatch_untransform(header, &transformed, &mut restored).unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[rstest]
+    #[case::bc1(TransformFormat::Bc1)]
+    #[case::bc2(TransformFormat::Bc2)]
+    #[case::none(TransformFormat::None)]
+    fn test_dispatch_transform_with_header_matches_dispatch_transform(
+        #[case] format: TransformFormat,
+    ) {
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+        let input: Vec<u8> = (0..64).collect();
+        let mut expected = vec![0u8; input.len()];
+        let header = dispatch_transform(format, &input, &mut expected, &bundle).unwrap();
+
+        let mut transformed = vec![0u8; input.len()];
+        dispatch_transform_with_header(header, &input, &mut transformed).unwrap();
+        assert_eq!(transformed, expected);
+    }
 }
@@ -0,0 +1,90 @@
+//! Object-safe counterpart of the file format handler traits.
+
+use super::{FileFormatDetection, FileFormatHandler, FileFormatUntransformDetection};
+use crate::bundle::TransformBundle;
+use crate::error::TransformResult;
+use core::fmt::Debug;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+
+/// Object-safe version of [`FileFormatHandler`], [`FileFormatDetection`] and
+/// [`FileFormatUntransformDetection`].
+///
+/// [`FileFormatHandler::transform_bundle`] is generic over the size estimator of the bundle,
+/// so handlers cannot be used as trait objects directly. This trait instead fixes the size
+/// estimator as the type parameter `T`, which allows handlers of different types to be stored
+/// together, e.g. in a [`HandlerRegistry`].
+///
+/// You do not need to implement this trait yourself; it is implemented for every handler that
+/// implements both [`FileFormatDetection`] and [`FileFormatUntransformDetection`].
+/// The methods carry a `dyn_` prefix to avoid ambiguity with the original traits.
+///
+/// [`HandlerRegistry`]: crate::handlers::HandlerRegistry
+pub trait DynFileFormatHandler<T>: Send + Sync
+where
+    T: SizeEstimationOperations,
+{
+    /// See [`FileFormatHandler::transform_bundle`].
+    fn dyn_transform_bundle(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        bundle: &TransformBundle<T>,
+    ) -> TransformResult<()>;
+
+    /// See [`FileFormatHandler::untransform`].
+    fn dyn_untransform(&self, input: &[u8], output: &mut [u8]) -> TransformResult<()>;
+
+    /// See [`FileFormatHandler::transformed_size`].
+    fn dyn_transformed_size(&self, input: &[u8]) -> usize;
+
+    /// See [`FileFormatHandler::untransformed_size`].
+    fn dyn_untransformed_size(&self, input: &[u8]) -> usize;
+
+    /// See [`FileFormatDetection::can_handle`].
+    fn dyn_can_handle(&self, input: &[u8], file_extension: Option<&str>) -> bool;
+
+    /// See [`FileFormatUntransformDetection::can_handle_untransform`].
+    fn dyn_can_handle_untransform(&self, input: &[u8], file_extension: Option<&str>) -> bool;
+}
+
+impl<H, T> DynFileFormatHandler<T> for H
+where
+    H: FileFormatDetection + FileFormatUntransformDetection + Send + Sync,
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    #[inline]
+    fn dyn_transform_bundle(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        bundle: &TransformBundle<T>,
+    ) -> TransformResult<()> {
+        FileFormatHandler::transform_bundle(self, input, output, bundle)
+    }
+
+    #[inline]
+    fn dyn_untransform(&self, input: &[u8], output: &mut [u8]) -> TransformResult<()> {
+        FileFormatHandler::untransform(self, input, output)
+    }
+
+    #[inline]
+    fn dyn_transformed_size(&self, input: &[u8]) -> usize {
+        FileFormatHandler::transformed_size(self, input)
+    }
+
+    #[inline]
+    fn dyn_untransformed_size(&self, input: &[u8]) -> usize {
+        FileFormatHandler::untransformed_size(self, input)
+    }
+
+    #[inline]
+    fn dyn_can_handle(&self, input: &[u8], file_extension: Option<&str>) -> bool {
+        FileFormatDetection::can_handle(self, input, file_extension)
+    }
+
+    #[inline]
+    fn dyn_can_handle_untransform(&self, input: &[u8], file_extension: Option<&str>) -> bool {
+        FileFormatUntransformDetection::can_handle_untransform(self, input, file_extension)
+    }
+}
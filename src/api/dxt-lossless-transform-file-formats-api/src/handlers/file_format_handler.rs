@@ -1,7 +1,10 @@
 //! Core trait for file format transformation.
 
 use crate::bundle::TransformBundle;
-use crate::error::TransformResult;
+use crate::error::{FormatHandlerError, TransformError, TransformResult};
+use crate::report::TransformReport;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::fmt::Debug;
 use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 
@@ -49,6 +52,8 @@ use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 /// ✅ **2. Parse File Header**
 /// - Parse the original file header to extract format information
 /// - Return `FormatHandlerError::InvalidInputFileHeader` if parsing fails
+/// - Return `FormatHandlerError::AlreadyTransformed` instead if the input is an already transformed
+///   file (e.g. the magic was replaced with a decodable transform header)
 /// - Extract `data_offset` (where texture data starts) and calculate `data_length`
 /// ```no_run
 /// use dxt_lossless_transform_file_formats_api::*;
@@ -150,11 +155,18 @@ use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 /// ✅ **7. Embed Transform Metadata**
 /// - Overwrite magic header/signature with transform metadata
 /// - Use safe pointer operations with proper bounds checking
+/// - If there is only room for a standard header, reject extended headers
+///   (see [extended headers](crate::embed#extended-headers))
 /// ```no_run
 /// use dxt_lossless_transform_file_formats_api::{*, embed::*};
 ///
-/// # fn example(header: TransformHeader, output: &mut [u8]) {
+/// # fn example(header: TransformHeader, output: &mut [u8]) -> TransformResult<()> {
 /// // Example from DDS implementation:
+/// // The DDS magic only has room for a standard header.
+/// if header.is_extended() {
+///     return Err(FormatHandlerError::ExtendedHeaderNotSupported.into());
+/// }
+///
 /// // SAFETY: output.as_mut_ptr() is valid for writes of at least TRANSFORM_HEADER_SIZE bytes because:
 /// // 1. We validated output.len() >= input.len() above
 /// // 2. parse_dds succeeded, guaranteeing input has valid DDS structure (minimum 128 bytes)
@@ -162,6 +174,7 @@ use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 /// unsafe {
 ///     header.write_to_ptr(output.as_mut_ptr());
 /// }
+/// # Ok(())
 /// # }
 /// ```
 ///
@@ -191,20 +204,29 @@ use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 /// ```
 ///
 /// ✅ **2. Read Transform Header**
+/// - Return `FormatHandlerError::NotTransformed` if the input still has the original magic
 /// - Extract transform metadata from the first 4 bytes
+/// - Check [`TransformHeader::size_from_first_byte`] before reading, as extended headers are 8 bytes
 /// - Use safe pointer operations with proper bounds checking
 /// ```no_run
 /// use dxt_lossless_transform_file_formats_api::{*, embed::*};
 ///
-/// # fn example(input: &[u8]) -> TransformHeader {
+/// # fn example(input: &[u8]) -> TransformResult<TransformHeader> {
 /// // Example from DDS implementation:
+/// // The DDS magic only has room for a standard header.
+/// if TransformHeader::size_from_first_byte(input[0]) != TRANSFORM_HEADER_SIZE {
+///     return Err(FormatHandlerError::ExtendedHeaderNotSupported.into());
+/// }
+///
 /// // SAFETY: input.as_ptr() is valid for reads of at least TRANSFORM_HEADER_SIZE bytes because we validated
-/// // input.len() >= TRANSFORM_HEADER_SIZE above. The input slice guarantees pointer validity.
+/// // input.len() >= TRANSFORM_HEADER_SIZE above, and the header is not extended.
 /// let header = unsafe { TransformHeader::read_from_ptr(input.as_ptr()) };
-/// header
+/// Ok(header)
 /// # }
 /// ```
 ///
+/// [`TransformHeader::size_from_first_byte`]: crate::embed::TransformHeader::size_from_first_byte
+///
 /// ✅ **3. Parse File Header (Ignoring Magic)**
 /// - Parse the file header while ignoring the overwritten magic bytes
 /// - Return `FormatHandlerError::InvalidRestoredFileHeader` if parsing fails
@@ -323,6 +345,15 @@ use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 ///   issues with clever developers writing custom data in unused file format areas
 ///
 /// The transform metadata is 4 bytes, matching what many file formats use for their magic header size.
+///
+/// ## Formats Without a Disposable Header
+///
+/// Some data has no header bytes that can be overwritten (e.g. raw block streams, GPU upload buffers).
+/// Handlers for such data may wrap the payload in a container of their own instead, making the
+/// output larger than the input. Such handlers must override [`FileFormatHandler::transformed_size`]
+/// and [`FileFormatHandler::untransformed_size`] so callers can size their buffers.
+///
+/// See [`crate::raw::RawBlocksHandler`] for an example.
 pub trait FileFormatHandler: Send + Sync {
     /// Transform the input buffer to output buffer using the provided transform bundle.
     ///
@@ -340,13 +371,13 @@ pub trait FileFormatHandler: Send + Sync {
     /// # Parameters
     ///
     /// - `input`: Input buffer containing the file data
-    /// - `output`: Output buffer (must be same size as input)
+    /// - `output`: Output buffer (must be at least [`FileFormatHandler::transformed_size`] bytes)
     /// - `bundle`: Bundle containing transform builders for different BCx formats
     ///
     /// # Returns
     ///
     /// Ok(()) on success, or an error if:
-    /// - Output buffer is smaller than input buffer
+    /// - Output buffer is smaller than [`FileFormatHandler::transformed_size`]
     /// - Invalid or corrupted file header
     /// - Input buffer is too short for the texture dimensions declared in the header
     /// - No appropriate builder is provided in the bundle for the detected format
@@ -378,15 +409,172 @@ pub trait FileFormatHandler: Send + Sync {
     /// # Parameters
     ///
     /// - `input`: Input buffer containing transformed data
-    /// - `output`: Output buffer (must be same size as input)
+    /// - `output`: Output buffer (must be at least [`FileFormatHandler::untransformed_size`] bytes)
     ///
     /// # Returns
     ///
     /// Ok(()) on success, or an error if:
     /// - Input buffer is too short to contain transform header
-    /// - Output buffer is smaller than input buffer
+    /// - Output buffer is smaller than [`FileFormatHandler::untransformed_size`]
     /// - The restored file header is invalid or corrupted
     /// - Input buffer is too short for the texture dimensions declared in the header
     /// - Untransform operation fails
     fn untransform(&self, input: &[u8], output: &mut [u8]) -> TransformResult<()>;
+
+    /// Transform the input buffer to output buffer, describing the transform in a report.
+    ///
+    /// This is [`FileFormatHandler::transform_bundle`], but the detected format, the chosen
+    /// settings and the candidates tested by automatic builders are written to `report`.
+    ///
+    /// The default implementation calls [`FileFormatHandler::transform_bundle`] and leaves
+    /// `report` unchanged. Handlers should override it to call [`dispatch_transform_with_report`]
+    /// in place of [`dispatch_transform`], and implement [`FileFormatHandler::transform_bundle`]
+    /// by passing a throwaway report.
+    ///
+    /// [`dispatch_transform`]: crate::handlers::dispatch_transform
+    /// [`dispatch_transform_with_report`]: crate::handlers::dispatch_transform_with_report
+    fn transform_bundle_with_report<T>(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        bundle: &TransformBundle<T>,
+        _report: &mut TransformReport,
+    ) -> TransformResult<()>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        self.transform_bundle(input, output, bundle)
+    }
+
+    /// Returns the size of the output produced by [`FileFormatHandler::transform_bundle`] for the given input.
+    ///
+    /// The default implementation returns `input.len()`, which is correct for handlers that embed
+    /// the transform details inside the existing file header (e.g. DDS).
+    fn transformed_size(&self, input: &[u8]) -> usize {
+        input.len()
+    }
+
+    /// Returns the size of the output produced by [`FileFormatHandler::untransform`] for the given input.
+    ///
+    /// The default implementation returns `input.len()`, which is correct for handlers that embed
+    /// the transform details inside the existing file header (e.g. DDS).
+    ///
+    /// If the input is malformed, implementations should return `input.len()` and leave
+    /// error reporting to [`FileFormatHandler::untransform`].
+    fn untransformed_size(&self, input: &[u8]) -> usize {
+        input.len()
+    }
+
+    /// Transform the buffer in place using the provided transform bundle.
+    ///
+    /// Only supported when [`FileFormatHandler::transformed_size`] equals the buffer length;
+    /// otherwise [`FormatHandlerError::InPlaceSizeMismatch`] is returned.
+    ///
+    /// The default implementation copies the whole buffer into a scratch buffer and calls
+    /// [`FileFormatHandler::transform_bundle`]. Handlers that know where the texture data lives
+    /// should override this to only copy the texture data, keeping the headers where they are.
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: Buffer containing the file data, replaced with the transformed data on success
+    /// - `bundle`: Bundle containing transform builders for different BCx formats
+    ///
+    /// # Remarks
+    ///
+    /// If an error is returned (including cancellation and estimator errors of auto builders),
+    /// `data` is left unchanged. Overrides must uphold this, e.g. by restoring the modified
+    /// parts of `data` from their scratch copy.
+    fn transform_bundle_in_place<T>(
+        &self,
+        data: &mut [u8],
+        bundle: &TransformBundle<T>,
+    ) -> TransformResult<()>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        validate_in_place_size(self.transformed_size(data), data.len())?;
+        let scratch: Vec<u8> = data.to_vec();
+        let result = self.transform_bundle(&scratch, data, bundle);
+        if result.is_err() {
+            data.copy_from_slice(&scratch);
+        }
+        result
+    }
+
+    /// Untransform the buffer in place.
+    ///
+    /// Only supported when [`FileFormatHandler::untransformed_size`] equals the buffer length;
+    /// otherwise [`FormatHandlerError::InPlaceSizeMismatch`] is returned.
+    ///
+    /// The default implementation copies the whole buffer into a scratch buffer and calls
+    /// [`FileFormatHandler::untransform`]. Handlers that know where the texture data lives
+    /// should override this to only copy the texture data, keeping the headers where they are.
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: Buffer containing transformed data, replaced with the original data on success
+    ///
+    /// # Remarks
+    ///
+    /// If an error is returned (including [`TransformError::ChecksumMismatch`]), `data` is left
+    /// unchanged. Overrides must uphold this, e.g. by restoring the modified parts of `data` from
+    /// their scratch copy.
+    fn untransform_in_place(&self, data: &mut [u8]) -> TransformResult<()> {
+        validate_in_place_size(self.untransformed_size(data), data.len())?;
+        let scratch: Vec<u8> = data.to_vec();
+        let result = self.untransform(&scratch, data);
+        if result.is_err() {
+            data.copy_from_slice(&scratch);
+        }
+        result
+    }
+}
+
+/// Validates that an operation producing `output` bytes can be done in a buffer of `actual` bytes.
+///
+/// For use in [`FileFormatHandler::transform_bundle_in_place`] and
+/// [`FileFormatHandler::untransform_in_place`] implementations.
+#[inline]
+pub fn validate_in_place_size(output: usize, actual: usize) -> TransformResult<()> {
+    if output != actual {
+        return Err(FormatHandlerError::InPlaceSizeMismatch { output, actual }.into());
+    }
+    Ok(())
+}
+
+/// Untransforms `transformed` into a scratch buffer and checks that it matches `original`.
+///
+/// Used to implement [`TransformBundle::with_verify`].
+///
+/// # Parameters
+///
+/// - `original`: The data that was transformed
+/// - `transformed`: The transformed data
+/// - `restored_size`: Size of the untransformed data, as reported by the handler
+/// - `untransform`: Untransforms the first buffer into the second
+///
+/// # Returns
+///
+/// [`TransformError::RoundtripMismatch`] with the offset of the first difference if the restored
+/// data does not match `original`.
+pub(crate) fn verify_roundtrip(
+    original: &[u8],
+    transformed: &[u8],
+    restored_size: usize,
+    untransform: impl FnOnce(&[u8], &mut [u8]) -> TransformResult<()>,
+) -> TransformResult<()> {
+    if restored_size != original.len() {
+        return Err(TransformError::RoundtripMismatch {
+            offset: restored_size.min(original.len()),
+        });
+    }
+
+    let mut restored = vec![0u8; restored_size];
+    untransform(transformed, &mut restored)?;
+    match original.iter().zip(&restored).position(|(a, b)| a != b) {
+        Some(offset) => Err(TransformError::RoundtripMismatch { offset }),
+        None => Ok(()),
+    }
 }
@@ -0,0 +1,67 @@
+//! Trait for inspecting files without transforming them.
+
+use super::FileFormatHandler;
+use crate::embed::{TransformDetails, TransformFormat};
+use crate::error::TransformResult;
+
+/// Texture metadata of a file, as returned by [`FileFormatInspection::inspect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureInfo {
+    /// Format of the texture data, or [`None`] if the format cannot be transformed
+    /// by this library (e.g. an uncommon pixel format).
+    pub format: Option<TransformFormat>,
+    /// Width of the top level mipmap in pixels.
+    pub width: u32,
+    /// Height of the top level mipmap in pixels.
+    pub height: u32,
+    /// Depth of the top level mipmap in pixels (1 unless this is a volume texture).
+    pub depth: u32,
+    /// Number of mipmap levels, including the top level.
+    pub mip_count: u32,
+    /// Number of array elements (1 unless this is a texture array).
+    pub array_size: u32,
+    /// Number of faces per array element (6 for complete cube maps, 1 otherwise).
+    pub face_count: u32,
+    /// Offset of the texture data from the start of the file.
+    pub data_offset: usize,
+    /// Length of the texture data that is subject to transformation.
+    pub data_length: usize,
+    /// Transform details embedded in the file, or [`None`] if the file is not transformed.
+    pub transform_details: Option<TransformDetails>,
+}
+
+impl TextureInfo {
+    /// Returns `true` if the file has already been transformed.
+    pub fn is_transformed(&self) -> bool {
+        self.transform_details.is_some()
+    }
+}
+
+/// Trait for extracting texture metadata from files without transforming them.
+///
+/// This trait extends [`FileFormatHandler`] with the ability to parse a file's headers into
+/// a structured [`TextureInfo`], for use cases such as indexing textures in an asset database.
+///
+/// Inspection accepts both original and transformed files; use [`TextureInfo::is_transformed`]
+/// to tell them apart. The decoded transform settings are available via
+/// [`TextureInfo::transform_details`].
+pub trait FileFormatInspection: FileFormatHandler {
+    /// Parse the headers of a file into a [`TextureInfo`].
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: The file data to inspect. Only the file headers need to be present;
+    ///   the texture data itself is not read.
+    ///
+    /// # Returns
+    ///
+    /// The [`TextureInfo`] describing the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatHandlerError::InvalidInputFileHeader`] if the input is not a
+    /// (transformed or original) file of this handler's format.
+    ///
+    /// [`FormatHandlerError::InvalidInputFileHeader`]: crate::error::FormatHandlerError::InvalidInputFileHeader
+    fn inspect(&self, input: &[u8]) -> TransformResult<TextureInfo>;
+}
@@ -0,0 +1,102 @@
+//! Trait for transforming files from streams.
+
+use super::FileFormatInspection;
+use crate::embed::TransformHeader;
+use crate::error::{FormatHandlerError, TransformResult};
+
+/// Trait for handlers that can transform files incrementally, e.g. from a `std::io::Read`.
+///
+/// Streaming operations only keep the file headers and a bounded part of the texture data in
+/// memory. They locate the texture data via [`FileFormatInspection::inspect`], transform it in
+/// segments, and then use this trait to embed or restore the transform header in the file headers
+/// (`input[..data_offset]`).
+///
+/// The texture data is laid out as with [`FileFormatHandler::transform_bundle`], so either can
+/// untransform the output of the other. The transformed bytes are identical only for manual
+/// builders (or passthrough); automatic builders only see the first segment of a stream, so they
+/// may choose different settings.
+///
+/// [`FileFormatHandler::transform_bundle`]: super::FileFormatHandler::transform_bundle
+pub trait FileFormatStreaming: FileFormatInspection {
+    /// Returns the number of bytes from the start of the file that are sufficient for
+    /// [`FileFormatInspection::inspect`] to succeed.
+    ///
+    /// Streaming operations read this many bytes up front (or fewer, if the stream ends early).
+    fn max_header_size(&self) -> usize;
+
+    /// Embed the transform header into the file headers of an original file.
+    ///
+    /// This is the header part of [`FileFormatHandler::transform_bundle`].
+    ///
+    /// # Parameters
+    ///
+    /// - `headers`: The file headers, i.e. all bytes before the texture data.
+    /// - `header`: The transform header to embed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatHandlerError::ExtendedHeaderNotSupported`] if the format only has room for
+    /// a standard header, or another error if the headers are too short.
+    ///
+    /// [`FileFormatHandler::transform_bundle`]: super::FileFormatHandler::transform_bundle
+    /// [`FormatHandlerError::ExtendedHeaderNotSupported`]: crate::error::FormatHandlerError::ExtendedHeaderNotSupported
+    fn embed_transform_header(
+        &self,
+        headers: &mut [u8],
+        header: TransformHeader,
+    ) -> TransformResult<()>;
+
+    /// Read the transform header from the file headers of a transformed file, and restore
+    /// the original file headers.
+    ///
+    /// This is the header part of [`FileFormatHandler::untransform`].
+    ///
+    /// # Parameters
+    ///
+    /// - `headers`: The file headers, i.e. all bytes before the texture data.
+    ///
+    /// # Returns
+    ///
+    /// The transform header that was embedded in the file headers.
+    ///
+    /// [`FileFormatHandler::untransform`]: super::FileFormatHandler::untransform
+    fn restore_headers(&self, headers: &mut [u8]) -> TransformResult<TransformHeader>;
+
+    /// Store a checksum of the original texture data in the file headers of a transformed file.
+    ///
+    /// Streaming transforms call this once before the texture data is processed (with a
+    /// placeholder `checksum` of 0) to find out whether there is room, and once more with the
+    /// actual checksum at the end. Only the last checksum is kept.
+    ///
+    /// The default implementation stores nothing and returns `false`.
+    ///
+    /// # Parameters
+    ///
+    /// - `headers`: The file headers, i.e. all bytes before the texture data.
+    /// - `checksum`: The checksum of the original texture data, see [`texture_checksum`].
+    ///
+    /// # Returns
+    ///
+    /// `true` if the checksum was stored, or `false` (leaving `headers` unchanged) if the format
+    /// has no room for it in these headers.
+    ///
+    /// [`texture_checksum`]: super::texture_checksum
+    fn embed_checksum(&self, _headers: &mut [u8], _checksum: u64) -> bool {
+        false
+    }
+
+    /// Read the checksum stored by [`FileFormatStreaming::embed_checksum`] from the file headers
+    /// of a transformed file, and restore the original header bytes it replaced.
+    ///
+    /// Only called when [`TransformHeader::has_checksum`] is set.
+    ///
+    /// The default implementation returns [`FormatHandlerError::InvalidRestoredFileHeader`], as
+    /// handlers which never store a checksum can't have produced such a file.
+    ///
+    /// # Parameters
+    ///
+    /// - `headers`: The file headers, i.e. all bytes before the texture data.
+    fn restore_checksum(&self, _headers: &mut [u8]) -> TransformResult<u64> {
+        Err(FormatHandlerError::InvalidRestoredFileHeader.into())
+    }
+}
@@ -15,7 +15,7 @@
 //! Handlers coordinate between these layers, embedding transform metadata in file headers
 //! and managing the round-trip process (transform → embed metadata → extract metadata → untransform).
 //!
-//! ## Three-Trait System
+//! ## Handler Traits
 //!
 //! ### [`FileFormatHandler`]
 //!
@@ -63,6 +63,33 @@
 //! supporting many formats, creating high risk of false positives. Add extra
 //! validation and safeguards to ensure correct format detection.
 //!
+//! ### [`FileFormatInspection`]
+//!
+//! **Metadata extraction without transforming.**
+//!
+//! This trait extends [`FileFormatHandler`] with the ability to parse a file's headers into
+//! a structured [`TextureInfo`] (format, dimensions, mipmaps, data location and embedded
+//! transform details). It accepts both original and transformed files.
+//!
+//! Use this for:
+//! - **Asset databases**: Indexing textures without reimplementing format parsing
+//! - **Tooling**: Reporting whether (and how) files have been transformed
+//!
+//! ### [`FileFormatStreaming`]
+//!
+//! **Transforming from streams.**
+//!
+//! This trait extends [`FileFormatInspection`] with the ability to embed and restore transform
+//! headers separately from the texture data, which allows transforming files from a
+//! `std::io::Read` with bounded memory usage. See `file_io::transform_reader_to_writer`.
+//!
+//! ## Mixing Handler Types
+//!
+//! [`FileFormatHandler::transform_bundle`] is generic, so handlers cannot be used as trait objects.
+//! Use [`HandlerRegistry`] to combine handlers of different types (e.g. DDS and KTX) at runtime;
+//! it stores them via the object-safe [`DynFileFormatHandler`] trait, which is implemented for
+//! every handler that supports both kinds of detection.
+//!
 //! ## Usage Guidelines
 //!
 //! ### **Known Format (Optimal Performance)**
@@ -75,26 +102,16 @@
 //! ### **Unknown Format at Transform Time**
 //! ```ignore
 //! // CLI tool processing unknown input files
-//! let handlers: Vec<Box<dyn FileFormatDetection>> = get_transform_handlers();
-//! for handler in handlers {
-//!     if handler.can_handle(&input_data) {
-//!         handler.transform_bundle(&input, &mut output, &bundle)?;
-//!         break;
-//!     }
-//! }
+//! let registry = HandlerRegistry::new().with_handler(DdsHandler, &["dds"]);
+//! registry.transform_slice(&input, &mut output, Some("dds"), &bundle)?;
 //! ```
 //!
 //! ### **Unknown Format at Untransform Time (rarely needed)**
 //! ```ignore
 //! // CAUTION: Only use when format information is completely unavailable
 //! // Usually you should store format info in archives/metadata instead
-//! let handlers: Vec<Box<dyn FileFormatUntransformDetection>> = get_untransform_handlers();
-//! for handler in handlers {
-//!     if handler.can_handle_untransform(&transformed_data) {
-//!         handler.untransform(&transformed_data, &mut output)?;
-//!         break;
-//!     }
-//! }
+//! let registry = HandlerRegistry::new().with_handler(DdsHandler, &["dds"]);
+//! registry.untransform_slice(&transformed_data, &mut output, None)?;
 //! ```
 //!
 //! ## Performance Considerations
@@ -105,13 +122,23 @@
 //! - **Archive formats**: Always store format information in metadata rather than relying on detection
 //! - **Game engines/applications**: Control your formats - detection should be unnecessary
 
+pub(crate) mod checksum;
 pub(crate) mod dispatch;
+pub(crate) mod dyn_file_format_handler;
 pub(crate) mod file_format_detection;
 pub(crate) mod file_format_handler;
+pub(crate) mod file_format_inspection;
+pub(crate) mod file_format_streaming;
 pub(crate) mod file_format_untransform_detection;
+pub(crate) mod registry;
 
 // Re-export traits and dispatch functions for convenience
+pub use checksum::*;
 pub use dispatch::*;
+pub use dyn_file_format_handler::*;
 pub use file_format_detection::*;
 pub use file_format_handler::*;
+pub use file_format_inspection::*;
+pub use file_format_streaming::*;
 pub use file_format_untransform_detection::*;
+pub use registry::*;
@@ -0,0 +1,434 @@
+//! Runtime-configurable collection of file format handlers.
+
+use super::{verify_roundtrip, DynFileFormatHandler};
+use crate::bundle::TransformBundle;
+use crate::error::{FormatHandlerError, TransformError, TransformResult};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use dxt_lossless_transform_api_common::estimate::{NoEstimation, SizeEstimationOperations};
+
+/// A handler stored in the registry, alongside the extensions it is preferred for.
+struct RegistryEntry<T>
+where
+    T: SizeEstimationOperations,
+{
+    handler: Box<dyn DynFileFormatHandler<T>>,
+    extensions: Vec<String>,
+}
+
+impl<T> RegistryEntry<T>
+where
+    T: SizeEstimationOperations,
+{
+    fn is_preferred_for(&self, file_extension: Option<&str>) -> bool {
+        file_extension.is_some_and(|ext| {
+            self.extensions
+                .iter()
+                .any(|registered| registered.eq_ignore_ascii_case(ext))
+        })
+    }
+}
+
+/// A collection of file format handlers of different types, configured at runtime.
+///
+/// Unlike [`transform_slice_with_multiple_handlers`], which requires all handlers to be
+/// of the same type, the registry stores handlers as [`DynFileFormatHandler`] trait objects,
+/// so e.g. DDS and KTX handlers can be mixed freely.
+///
+/// # Handler Priority
+///
+/// Each handler is registered with a list of file extensions it is preferred for.
+/// When detecting the format of a file with a known extension, handlers registered for that
+/// extension are tried first, followed by all remaining handlers. Within each group, handlers
+/// are tried in registration order; use [`HandlerRegistry::register_first`] to take precedence
+/// over previously registered handlers.
+///
+/// # Type Parameters
+///
+/// - `T`: The size estimator of the [`TransformBundle`] used for transforming.
+///   If you only untransform, the default ([`NoEstimation`]) can be used.
+///
+/// # Example
+///
+/// ```
+/// use dxt_lossless_transform_file_formats_api::{HandlerRegistry, TransformBundle, TransformResult};
+/// use dxt_lossless_transform_api_common::estimate::NoEstimation;
+/// use dxt_lossless_transform_dds::DdsHandler;
+///
+/// fn example_registry(input: &[u8]) -> TransformResult<Vec<u8>> {
+///     let registry = HandlerRegistry::<NoEstimation>::new().with_handler(DdsHandler, &["dds"]);
+///     let bundle = TransformBundle::<NoEstimation>::default_all();
+///
+///     let handler = registry
+///         .find_transform_handler(input, Some("dds"))
+///         .ok_or(dxt_lossless_transform_file_formats_api::TransformError::NoSupportedHandler)?;
+///     let mut output = vec![0u8; handler.dyn_transformed_size(input)];
+///     registry.transform_slice(input, &mut output, Some("dds"), &bundle)?;
+///     Ok(output)
+/// }
+/// ```
+///
+/// [`transform_slice_with_multiple_handlers`]: crate::api::transform_slice_with_multiple_handlers
+pub struct HandlerRegistry<T = NoEstimation>
+where
+    T: SizeEstimationOperations,
+{
+    entries: Vec<RegistryEntry<T>>,
+}
+
+impl<T> Default for HandlerRegistry<T>
+where
+    T: SizeEstimationOperations,
+{
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<T> HandlerRegistry<T>
+where
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    /// Create a new empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a handler, preferred for the given file extensions (lowercase, without leading dot).
+    ///
+    /// See [`HandlerRegistry::register`].
+    pub fn with_handler<H>(mut self, handler: H, extensions: &[&str]) -> Self
+    where
+        H: DynFileFormatHandler<T> + 'static,
+    {
+        self.register(handler, extensions);
+        self
+    }
+
+    /// Register a handler, preferred for the given file extensions (lowercase, without leading dot).
+    ///
+    /// The handler is tried after all previously registered handlers with the same priority.
+    pub fn register<H>(&mut self, handler: H, extensions: &[&str])
+    where
+        H: DynFileFormatHandler<T> + 'static,
+    {
+        let entry = Self::create_entry(handler, extensions);
+        self.entries.push(entry);
+    }
+
+    /// Register a handler, preferred for the given file extensions (lowercase, without leading dot).
+    ///
+    /// The handler is tried before all previously registered handlers with the same priority.
+    /// This is useful for overriding a built-in handler.
+    pub fn register_first<H>(&mut self, handler: H, extensions: &[&str])
+    where
+        H: DynFileFormatHandler<T> + 'static,
+    {
+        let entry = Self::create_entry(handler, extensions);
+        self.entries.insert(0, entry);
+    }
+
+    /// Returns the number of registered handlers.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no handlers are registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns all handlers in the order they are tried for a file with the given extension.
+    ///
+    /// See [Handler Priority](HandlerRegistry#handler-priority).
+    pub fn handlers<'a, 'e>(
+        &'a self,
+        file_extension: Option<&'e str>,
+    ) -> impl Iterator<Item = &'a dyn DynFileFormatHandler<T>> + 'e
+    where
+        'a: 'e,
+    {
+        let preferred = self
+            .entries
+            .iter()
+            .filter(move |entry| entry.is_preferred_for(file_extension));
+        let remaining = self
+            .entries
+            .iter()
+            .filter(move |entry| !entry.is_preferred_for(file_extension));
+
+        preferred
+            .chain(remaining)
+            .map(|entry| entry.handler.as_ref())
+    }
+
+    /// Find the first handler that can transform the input.
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: The file data to analyze
+    /// - `file_extension`: *Optional* file extension (lowercase, without leading dot)
+    pub fn find_transform_handler(
+        &self,
+        input: &[u8],
+        file_extension: Option<&str>,
+    ) -> Option<&dyn DynFileFormatHandler<T>> {
+        self.handlers(file_extension)
+            .find(|handler| handler.dyn_can_handle(input, file_extension))
+    }
+
+    /// Find the first handler that can untransform the input.
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: The transformed file data to analyze
+    /// - `file_extension`: *Optional* file extension (lowercase, without leading dot)
+    pub fn find_untransform_handler(
+        &self,
+        input: &[u8],
+        file_extension: Option<&str>,
+    ) -> Option<&dyn DynFileFormatHandler<T>> {
+        self.handlers(file_extension)
+            .find(|handler| handler.dyn_can_handle_untransform(input, file_extension))
+    }
+
+    /// Transform a slice using the first handler that accepts it.
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: Input buffer containing the file data
+    /// - `output`: Output buffer (must be at least [`DynFileFormatHandler::dyn_transformed_size`] bytes
+    ///   for the accepting handler)
+    /// - `file_extension`: *Optional* file extension (lowercase, without leading dot)
+    /// - `bundle`: Bundle containing transform builders for different BCx formats
+    ///
+    /// # Returns
+    ///
+    /// The handler that was used, or [`TransformError::NoSupportedHandler`] if no handler can process the data.
+    pub fn transform_slice(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        file_extension: Option<&str>,
+        bundle: &TransformBundle<T>,
+    ) -> TransformResult<&dyn DynFileFormatHandler<T>> {
+        let handler = self
+            .find_transform_handler(input, file_extension)
+            .ok_or(TransformError::NoSupportedHandler)?;
+
+        let required = handler.dyn_transformed_size(input);
+        if output.len() < required {
+            return Err(FormatHandlerError::OutputBufferTooSmall {
+                required,
+                actual: output.len(),
+            }
+            .into());
+        }
+
+        handler.dyn_transform_bundle(input, output, bundle)?;
+        if bundle.verify() {
+            let transformed = &output[..required];
+            verify_roundtrip(
+                input,
+                transformed,
+                handler.dyn_untransformed_size(transformed),
+                |input, output| handler.dyn_untransform(input, output),
+            )?;
+        }
+
+        Ok(handler)
+    }
+
+    /// Untransform a slice using the first handler that accepts it.
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: Input buffer containing transformed data
+    /// - `output`: Output buffer (must be at least [`DynFileFormatHandler::dyn_untransformed_size`] bytes
+    ///   for the accepting handler)
+    /// - `file_extension`: *Optional* file extension (lowercase, without leading dot)
+    ///
+    /// # Returns
+    ///
+    /// The handler that was used, or [`TransformError::NoSupportedHandler`] if no handler can process the data.
+    pub fn untransform_slice(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        file_extension: Option<&str>,
+    ) -> TransformResult<&dyn DynFileFormatHandler<T>> {
+        let handler = self
+            .find_untransform_handler(input, file_extension)
+            .ok_or(TransformError::NoSupportedHandler)?;
+
+        let required = handler.dyn_untransformed_size(input);
+        if output.len() < required {
+            return Err(FormatHandlerError::OutputBufferTooSmall {
+                required,
+                actual: output.len(),
+            }
+            .into());
+        }
+
+        handler.dyn_untransform(input, output)?;
+        Ok(handler)
+    }
+
+    fn create_entry<H>(handler: H, extensions: &[&str]) -> RegistryEntry<T>
+    where
+        H: DynFileFormatHandler<T> + 'static,
+    {
+        RegistryEntry {
+            handler: Box::new(handler),
+            extensions: extensions.iter().map(|ext| ext.to_string()).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use crate::RawBlocksHandler;
+    use alloc::vec;
+
+    #[test]
+    fn find_transform_handler_prefers_matching_extension() {
+        let first = MockHandler::new_extensionless_accepting();
+        let preferred = MockHandler::new_extensionless_accepting();
+        let registry = HandlerRegistry::<NoEstimation>::new()
+            .with_handler(first.clone(), &["ktx"])
+            .with_handler(preferred.clone(), &["dds"]);
+        let input = create_test_data(64);
+        let mut output = vec![0u8; 64];
+
+        registry
+            .transform_slice(
+                &input,
+                &mut output,
+                Some("dds"),
+                &TransformBundle::default_all(),
+            )
+            .unwrap();
+        assert!(preferred.get_calls().transform_bundle_called);
+        assert!(!first.get_calls().transform_bundle_called);
+        assert!(first.get_calls().can_handle_calls.is_empty());
+    }
+
+    #[test]
+    fn find_transform_handler_falls_back_to_other_handlers() {
+        let rejecting = MockHandler::new_rejecting();
+        let accepting = MockHandler::new_extensionless_accepting();
+        let registry = HandlerRegistry::<NoEstimation>::new()
+            .with_handler(rejecting.clone(), &["dds"])
+            .with_handler(accepting.clone(), &[]);
+        let input = create_test_data(64);
+
+        assert!(registry
+            .find_transform_handler(&input, Some("dds"))
+            .is_some());
+        assert_eq!(rejecting.get_calls().can_handle_calls.len(), 1);
+        assert_eq!(accepting.get_calls().can_handle_calls.len(), 1);
+    }
+
+    #[test]
+    fn register_first_takes_precedence() {
+        let builtin = MockHandler::new_extensionless_accepting();
+        let plugin = MockHandler::new_extensionless_accepting();
+        let mut registry =
+            HandlerRegistry::<NoEstimation>::new().with_handler(builtin.clone(), &[]);
+        registry.register_first(plugin.clone(), &[]);
+        let input = create_test_data(64);
+        let mut output = vec![0u8; 64];
+
+        registry
+            .untransform_slice(&input, &mut output, None)
+            .unwrap();
+        assert!(plugin.get_calls().untransform_called);
+        assert!(!builtin.get_calls().untransform_called);
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[test]
+    fn transform_slice_rejects_when_no_handler_accepts() {
+        let registry =
+            HandlerRegistry::<NoEstimation>::new().with_handler(MockHandler::new_rejecting(), &[]);
+        let input = create_test_data(64);
+        let mut output = vec![0u8; 64];
+
+        let result =
+            registry.transform_slice(&input, &mut output, None, &TransformBundle::default_all());
+        assert!(matches!(result, Err(TransformError::NoSupportedHandler)));
+        assert!(HandlerRegistry::<NoEstimation>::new().is_empty());
+    }
+
+    #[test]
+    fn registry_mixes_handler_types() {
+        let registry: HandlerRegistry = HandlerRegistry::new()
+            .with_handler(MockHandler::new_accepting("dds"), &["dds"])
+            .with_handler(
+                RawBlocksHandler::new(crate::embed::TransformFormat::Bc1),
+                &["bc1"],
+            );
+        let input = create_test_data(64);
+        let bundle = TransformBundle::default_all();
+
+        // Raw handler grows the output by its container, so size via the selected handler.
+        let handler = registry
+            .find_transform_handler(&input, Some("bc1"))
+            .unwrap();
+        let mut transformed = vec![0u8; handler.dyn_transformed_size(&input)];
+        registry
+            .transform_slice(&input, &mut transformed, Some("bc1"), &bundle)
+            .unwrap();
+
+        let mut restored = vec![0u8; input.len()];
+        registry
+            .untransform_slice(&transformed, &mut restored, None)
+            .unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[test]
+    fn transform_slice_rejects_output_buffer_too_small() {
+        let registry: HandlerRegistry = HandlerRegistry::new().with_handler(
+            RawBlocksHandler::new(crate::embed::TransformFormat::Bc1),
+            &["bc1"],
+        );
+        let input = create_test_data(64);
+        let mut output = vec![0u8; 64];
+
+        let result = registry.transform_slice(
+            &input,
+            &mut output,
+            Some("bc1"),
+            &TransformBundle::default_all(),
+        );
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::OutputBufferTooSmall { actual: 64, .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn transform_slice_with_verify_detects_mismatch() {
+        let registry: HandlerRegistry =
+            HandlerRegistry::new().with_handler(LossyHandler { corrupt_offset: 5 }, &[]);
+        let input = create_test_data(64);
+        let mut output = vec![0u8; 64];
+        let bundle = TransformBundle::default_all().with_verify(true);
+
+        let result = registry.transform_slice(&input, &mut output, None, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::RoundtripMismatch { offset: 5 })
+        ));
+    }
+}
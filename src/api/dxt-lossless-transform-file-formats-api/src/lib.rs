@@ -12,15 +12,26 @@ pub mod bundle;
 pub mod embed;
 pub mod error;
 pub mod handlers;
+pub mod raw;
+pub mod report;
 
 #[cfg(feature = "file-io")]
 pub mod file_io;
 
+#[cfg(feature = "c-exports")]
+pub mod c_api;
+
 // Re-export key APIs
 pub use api::*; // convenience functions
 pub use bundle::TransformBundle;
+#[cfg(feature = "serde")]
+pub use bundle::TransformBundleConfig;
+#[cfg(feature = "presets")]
+pub use bundle::TransformPreset;
 pub use error::*; // error types
 pub use handlers::*; // file format handler infrastructure (traits + dispatch functions)
+pub use raw::RawBlocksHandler; // handler for headerless block data
+pub use report::{TransformCandidate, TransformReport};
 
 // Test utilities (only available during testing)
 #[cfg(test)]
@@ -0,0 +1,349 @@
+//! Header of the raw block container.
+
+use crate::embed::{TransformHeader, TRANSFORM_HEADER_SIZE};
+use crate::error::{FormatHandlerError, FormatHandlerResult};
+
+/// Magic identifying the raw block container (`"DLTB"`).
+pub const RAW_CONTAINER_MAGIC: [u8; 4] = *b"DLTB";
+
+/// Current version of the raw block container.
+pub const RAW_CONTAINER_VERSION: u8 = 1;
+
+/// Size of the fixed part of the raw block container header in bytes.
+pub const RAW_CONTAINER_HEADER_SIZE: usize = 32;
+
+/// Flag set when the container stores a checksum of the original data.
+const FLAG_HAS_CHECKSUM: u8 = 0b0000_0001;
+
+const VERSION_OFFSET: usize = 4;
+const FLAGS_OFFSET: usize = 5;
+const EXTENDED_LENGTH_OFFSET: usize = 6;
+const TRANSFORM_HEADER_OFFSET: usize = 8;
+const RESERVED_OFFSET: usize = TRANSFORM_HEADER_OFFSET + TRANSFORM_HEADER_SIZE;
+const ORIGINAL_LENGTH_OFFSET: usize = 16;
+const CHECKSUM_OFFSET: usize = 24;
+
+/// Header of the raw block container.
+///
+/// See the [module level documentation](super) for the on-disk layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawContainerHeader {
+    /// Details of the transform applied to the block data.
+    pub transform_header: TransformHeader,
+    /// Length of the original (untransformed) block data in bytes.
+    pub original_length: u64,
+    /// Length of the extended data area that follows the header.
+    pub extended_length: u16,
+    /// XXH3-64 checksum of the original block data, if stored.
+    pub checksum: Option<u64>,
+}
+
+impl RawContainerHeader {
+    /// Offset of the transformed block data from the start of the container.
+    pub fn data_offset(&self) -> usize {
+        RAW_CONTAINER_HEADER_SIZE + self.extended_length as usize
+    }
+
+    /// Returns the extended data area of a container starting with this header.
+    ///
+    /// Formats whose transform details don't fit in the [`TransformHeader`] (BC6H, BC7) store
+    /// them here.
+    ///
+    /// # Errors
+    ///
+    /// - [`FormatHandlerError::InputTooShort`] if the container ends before the extended data area does
+    pub fn extended_data<'a>(&self, container: &'a [u8]) -> FormatHandlerResult<&'a [u8]> {
+        let data_offset = self.data_offset();
+        if container.len() < data_offset {
+            return Err(FormatHandlerError::InputTooShort {
+                required: data_offset,
+                actual: container.len(),
+            });
+        }
+
+        Ok(&container[RAW_CONTAINER_HEADER_SIZE..data_offset])
+    }
+
+    /// Returns the extended data area of a container starting with this header, for writing.
+    ///
+    /// See [`Self::extended_data`].
+    ///
+    /// # Errors
+    ///
+    /// - [`FormatHandlerError::OutputBufferTooSmall`] if the container ends before the extended data area does
+    pub fn extended_data_mut<'a>(
+        &self,
+        container: &'a mut [u8],
+    ) -> FormatHandlerResult<&'a mut [u8]> {
+        let data_offset = self.data_offset();
+        if container.len() < data_offset {
+            return Err(FormatHandlerError::OutputBufferTooSmall {
+                required: data_offset,
+                actual: container.len(),
+            });
+        }
+
+        Ok(&mut container[RAW_CONTAINER_HEADER_SIZE..data_offset])
+    }
+
+    /// Returns `true` if the input starts with the raw container magic.
+    pub fn has_magic(input: &[u8]) -> bool {
+        input.len() >= RAW_CONTAINER_MAGIC.len()
+            && input[..RAW_CONTAINER_MAGIC.len()] == RAW_CONTAINER_MAGIC
+    }
+
+    /// Read the container header from the start of the input.
+    ///
+    /// # Errors
+    ///
+    /// - [`FormatHandlerError::InputTooShort`] if the input is smaller than [`RAW_CONTAINER_HEADER_SIZE`]
+    /// - [`FormatHandlerError::NotTransformed`] if the magic is missing
+    /// - [`FormatHandlerError::InvalidRestoredFileHeader`] if reserved fields are set
+    /// - [`FormatHandlerError::UnsupportedContainerVersion`] if the container was written by a newer version
+    pub fn read(input: &[u8]) -> FormatHandlerResult<Self> {
+        if input.len() < RAW_CONTAINER_HEADER_SIZE {
+            return Err(FormatHandlerError::InputTooShort {
+                required: RAW_CONTAINER_HEADER_SIZE,
+                actual: input.len(),
+            });
+        }
+
+        if !Self::has_magic(input) {
+            return Err(FormatHandlerError::NotTransformed);
+        }
+
+        let version = input[VERSION_OFFSET];
+        if version != RAW_CONTAINER_VERSION {
+            return Err(FormatHandlerError::UnsupportedContainerVersion(version));
+        }
+
+        // SAFETY: input is at least RAW_CONTAINER_HEADER_SIZE bytes long, so the transform header
+        // at TRANSFORM_HEADER_OFFSET is in bounds, even if extended.
+        let transform_header =
+            unsafe { TransformHeader::read_from_ptr(input[TRANSFORM_HEADER_OFFSET..].as_ptr()) };
+
+        // Standard headers leave the second half of the transform header slot unused.
+        let flags = input[FLAGS_OFFSET];
+        let reserved = if transform_header.is_extended() {
+            0
+        } else {
+            read_u32(input, RESERVED_OFFSET)
+        };
+        if flags & !FLAG_HAS_CHECKSUM != 0 || reserved != 0 {
+            return Err(FormatHandlerError::InvalidRestoredFileHeader);
+        }
+
+        let checksum = read_u64(input, CHECKSUM_OFFSET);
+        Ok(Self {
+            transform_header,
+            original_length: read_u64(input, ORIGINAL_LENGTH_OFFSET),
+            extended_length: u16::from_le_bytes([
+                input[EXTENDED_LENGTH_OFFSET],
+                input[EXTENDED_LENGTH_OFFSET + 1],
+            ]),
+            checksum: (flags & FLAG_HAS_CHECKSUM != 0).then_some(checksum),
+        })
+    }
+
+    /// Write the container header to the start of the output.
+    ///
+    /// # Errors
+    ///
+    /// - [`FormatHandlerError::OutputBufferTooSmall`] if the output is smaller than [`RAW_CONTAINER_HEADER_SIZE`]
+    pub fn write(&self, output: &mut [u8]) -> FormatHandlerResult<()> {
+        if output.len() < RAW_CONTAINER_HEADER_SIZE {
+            return Err(FormatHandlerError::OutputBufferTooSmall {
+                required: RAW_CONTAINER_HEADER_SIZE,
+                actual: output.len(),
+            });
+        }
+
+        let flags = if self.checksum.is_some() {
+            FLAG_HAS_CHECKSUM
+        } else {
+            0
+        };
+
+        output[..VERSION_OFFSET].copy_from_slice(&RAW_CONTAINER_MAGIC);
+        output[VERSION_OFFSET] = RAW_CONTAINER_VERSION;
+        output[FLAGS_OFFSET] = flags;
+        output[EXTENDED_LENGTH_OFFSET..TRANSFORM_HEADER_OFFSET]
+            .copy_from_slice(&self.extended_length.to_le_bytes());
+
+        // Standard headers leave the second half of the transform header slot unused.
+        output[TRANSFORM_HEADER_OFFSET..ORIGINAL_LENGTH_OFFSET].fill(0);
+
+        // SAFETY: output is at least RAW_CONTAINER_HEADER_SIZE bytes long, so the transform header
+        // at TRANSFORM_HEADER_OFFSET is in bounds, even if extended.
+        unsafe {
+            self.transform_header
+                .write_to_ptr(output[TRANSFORM_HEADER_OFFSET..].as_mut_ptr());
+        }
+
+        output[ORIGINAL_LENGTH_OFFSET..CHECKSUM_OFFSET]
+            .copy_from_slice(&self.original_length.to_le_bytes());
+        output[CHECKSUM_OFFSET..RAW_CONTAINER_HEADER_SIZE]
+            .copy_from_slice(&self.checksum.unwrap_or(0).to_le_bytes());
+        Ok(())
+    }
+}
+
+#[inline]
+fn read_u32(input: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&input[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+#[inline]
+fn read_u64(input: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&input[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use rstest::rstest;
+
+    fn create_test_header(checksum: Option<u64>) -> RawContainerHeader {
+        RawContainerHeader {
+            transform_header: create_test_bc1_header(),
+            original_length: 0x0123_4567_89AB_CDEF,
+            extended_length: 48,
+            checksum,
+        }
+    }
+
+    #[rstest]
+    #[case::with_checksum(Some(0xDEAD_BEEF_CAFE_F00D))]
+    #[case::without_checksum(None)]
+    fn header_roundtrips(#[case] checksum: Option<u64>) {
+        let header = create_test_header(checksum);
+        let mut buffer = [0u8; RAW_CONTAINER_HEADER_SIZE];
+        header.write(&mut buffer).unwrap();
+
+        assert_eq!(&buffer[..4], b"DLTB");
+        assert_eq!(RawContainerHeader::read(&buffer).unwrap(), header);
+        assert_eq!(header.data_offset(), RAW_CONTAINER_HEADER_SIZE + 48);
+    }
+
+    #[test]
+    fn extended_data_roundtrips() {
+        let header = create_test_header(None);
+        let mut buffer = vec![0u8; header.data_offset() + 8];
+        header.write(&mut buffer).unwrap();
+        let extended: Vec<u8> = (0..48).map(|x| x as u8 + 1).collect();
+        header
+            .extended_data_mut(&mut buffer)
+            .unwrap()
+            .copy_from_slice(&extended);
+
+        let read = RawContainerHeader::read(&buffer).unwrap();
+        assert_eq!(read, header);
+        assert_eq!(read.extended_data(&buffer).unwrap(), extended.as_slice());
+        assert_eq!(&buffer[read.data_offset()..], &[0u8; 8]);
+    }
+
+    #[test]
+    fn extended_data_rejects_short_container() {
+        let header = create_test_header(None);
+        let mut buffer = vec![0u8; header.data_offset() - 1];
+        assert!(matches!(
+            header.extended_data(&buffer),
+            Err(FormatHandlerError::InputTooShort { required, .. }) if required == header.data_offset()
+        ));
+        assert!(matches!(
+            header.extended_data_mut(&mut buffer),
+            Err(FormatHandlerError::OutputBufferTooSmall { required, .. }) if required == header.data_offset()
+        ));
+    }
+
+    #[test]
+    fn header_roundtrips_with_extended_transform_header() {
+        let mut transform_header = [0u8; 8];
+        transform_header[0] = crate::embed::EXTENDED_FORMAT_ESCAPE;
+        transform_header[4..].copy_from_slice(&0xDEAD_BEEFu32.to_le_bytes());
+
+        let mut buffer = [0u8; RAW_CONTAINER_HEADER_SIZE];
+        create_test_header(None).write(&mut buffer).unwrap();
+        buffer[TRANSFORM_HEADER_OFFSET..ORIGINAL_LENGTH_OFFSET].copy_from_slice(&transform_header);
+
+        let header = RawContainerHeader::read(&buffer).unwrap();
+        assert!(header.transform_header.is_extended());
+
+        let mut rewritten = [0u8; RAW_CONTAINER_HEADER_SIZE];
+        header.write(&mut rewritten).unwrap();
+        assert_eq!(rewritten, buffer);
+    }
+
+    #[test]
+    fn read_rejects_nonzero_padding_after_standard_transform_header() {
+        let mut buffer = [0u8; RAW_CONTAINER_HEADER_SIZE];
+        create_test_header(None).write(&mut buffer).unwrap();
+        buffer[RESERVED_OFFSET] = 1;
+
+        assert!(matches!(
+            RawContainerHeader::read(&buffer),
+            Err(FormatHandlerError::InvalidRestoredFileHeader)
+        ));
+    }
+
+    #[test]
+    fn read_rejects_short_input() {
+        let buffer = [0u8; RAW_CONTAINER_HEADER_SIZE - 1];
+        assert!(matches!(
+            RawContainerHeader::read(&buffer),
+            Err(FormatHandlerError::InputTooShort {
+                required: RAW_CONTAINER_HEADER_SIZE,
+                actual
+            }) if actual == RAW_CONTAINER_HEADER_SIZE - 1
+        ));
+    }
+
+    #[test]
+    fn read_rejects_missing_magic() {
+        let buffer = [0u8; RAW_CONTAINER_HEADER_SIZE];
+        assert!(matches!(
+            RawContainerHeader::read(&buffer),
+            Err(FormatHandlerError::NotTransformed)
+        ));
+    }
+
+    #[test]
+    fn read_rejects_newer_version() {
+        let mut buffer = [0u8; RAW_CONTAINER_HEADER_SIZE];
+        create_test_header(None).write(&mut buffer).unwrap();
+        buffer[VERSION_OFFSET] = RAW_CONTAINER_VERSION + 1;
+
+        assert!(matches!(
+            RawContainerHeader::read(&buffer),
+            Err(FormatHandlerError::UnsupportedContainerVersion(v)) if v == RAW_CONTAINER_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn read_rejects_unknown_flags() {
+        let mut buffer = [0u8; RAW_CONTAINER_HEADER_SIZE];
+        create_test_header(None).write(&mut buffer).unwrap();
+        buffer[FLAGS_OFFSET] = 0x80;
+
+        assert!(matches!(
+            RawContainerHeader::read(&buffer),
+            Err(FormatHandlerError::InvalidRestoredFileHeader)
+        ));
+    }
+
+    #[test]
+    fn write_rejects_small_output() {
+        let mut buffer = [0u8; RAW_CONTAINER_HEADER_SIZE - 1];
+        assert!(matches!(
+            create_test_header(None).write(&mut buffer),
+            Err(FormatHandlerError::OutputBufferTooSmall { .. })
+        ));
+    }
+}
@@ -0,0 +1,426 @@
+//! File format handler for headerless block data.
+
+use super::{extended_data_size, RawContainerHeader, RAW_CONTAINER_HEADER_SIZE};
+use crate::bundle::TransformBundle;
+use crate::embed::TransformFormat;
+use crate::error::{FormatHandlerError, TransformError, TransformResult};
+use crate::handlers::{
+    dispatch_transform_with_report, dispatch_untransform, texture_checksum,
+    verify_texture_checksum, FileFormatDetection, FileFormatHandler,
+    FileFormatUntransformDetection,
+};
+use crate::report::TransformReport;
+use core::fmt::Debug;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+
+/// Handler for headerless block data (e.g. raw `.bc1` files or GPU upload buffers).
+///
+/// Raw block data has no header bytes that could be overwritten with transform details, so this
+/// handler wraps the transformed blocks in a small self-describing container instead.
+/// See the [module level documentation](super) for the container layout.
+///
+/// Because of the container, the transformed output is larger than the input; use
+/// [`FileFormatHandler::transformed_size`] and [`FileFormatHandler::untransformed_size`]
+/// to size output buffers.
+///
+/// # Detection
+///
+/// Raw block data has no signature, so [`FileFormatDetection::can_handle`] only accepts input when
+/// a file extension matching the block format is provided (e.g. `bc1` for [`TransformFormat::Bc1`]).
+/// The container itself is detected by its magic, so untransform detection does not need one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawBlocksHandler {
+    format: TransformFormat,
+    checksum: bool,
+}
+
+impl RawBlocksHandler {
+    /// Create a new handler for block data of the given format.
+    pub fn new(format: TransformFormat) -> Self {
+        Self {
+            format,
+            checksum: false,
+        }
+    }
+
+    /// Sets whether to store a checksum of the original data in the container.
+    ///
+    /// When enabled, untransform verifies the restored data against the stored checksum and
+    /// returns [`TransformError::ChecksumMismatch`] on failure. Containers with a stored checksum
+    /// are always verified, regardless of this setting.
+    ///
+    /// A checksum is also stored when enabled via [`TransformBundle::with_checksum`].
+    pub fn with_checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Returns the block format this handler transforms.
+    pub fn format(&self) -> TransformFormat {
+        self.format
+    }
+}
+
+impl FileFormatHandler for RawBlocksHandler {
+    fn transform_bundle<T>(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        bundle: &TransformBundle<T>,
+    ) -> TransformResult<()>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        self.transform_bundle_with_report(input, output, bundle, &mut TransformReport::default())
+    }
+
+    fn transform_bundle_with_report<T>(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        bundle: &TransformBundle<T>,
+        report: &mut TransformReport,
+    ) -> TransformResult<()>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        // Refuse to wrap an existing container in another one
+        if RawContainerHeader::read(input).is_ok() {
+            return Err(FormatHandlerError::AlreadyTransformed.into());
+        }
+
+        // Validate buffer sizes
+        let required = self.transformed_size(input);
+        if output.len() < required {
+            return Err(FormatHandlerError::OutputBufferTooSmall {
+                required,
+                actual: output.len(),
+            }
+            .into());
+        }
+
+        let block_size = self.format.block_size();
+        if !input.len().is_multiple_of(block_size) {
+            return Err(TransformError::InvalidDataAlignment {
+                size: input.len(),
+                required_divisor: block_size,
+            });
+        }
+
+        // Transform block data
+        let extended_length = extended_data_size(self.format);
+        let data_offset = RAW_CONTAINER_HEADER_SIZE + extended_length;
+        let transform_header = dispatch_transform_with_report(
+            self.format,
+            input,
+            &mut output[data_offset..data_offset + input.len()],
+            bundle,
+            report,
+        )?;
+
+        // Write container header, reserving the extended data area
+        let header = RawContainerHeader {
+            transform_header,
+            original_length: input.len() as u64,
+            extended_length: extended_length as u16,
+            checksum: (self.checksum || bundle.checksum()).then(|| texture_checksum(input)),
+        };
+        header.write(output)?;
+        header.extended_data_mut(output)?.fill(0);
+
+        Ok(())
+    }
+
+    fn untransform(&self, input: &[u8], output: &mut [u8]) -> TransformResult<()> {
+        // Read container header
+        let header = RawContainerHeader::read(input)?;
+        let data_offset = header.data_offset();
+        let data_length = usize::try_from(header.original_length)
+            .map_err(|_| FormatHandlerError::InvalidRestoredFileHeader)?;
+
+        // Validate input buffer contains enough data for declared length
+        let total_required = data_offset.saturating_add(data_length);
+        if input.len() < total_required {
+            return Err(FormatHandlerError::InputTooShortForStatedTextureSize {
+                required: total_required,
+                actual: input.len(),
+            }
+            .into());
+        }
+
+        if output.len() < data_length {
+            return Err(FormatHandlerError::OutputBufferTooSmall {
+                required: data_length,
+                actual: output.len(),
+            }
+            .into());
+        }
+
+        // Untransform block data
+        let output = &mut output[..data_length];
+        dispatch_untransform(
+            header.transform_header,
+            &input[data_offset..total_required],
+            output,
+        )?;
+
+        // Verify checksum
+        if let Some(expected) = header.checksum {
+            verify_texture_checksum(expected, output)?;
+        }
+
+        Ok(())
+    }
+
+    fn transformed_size(&self, input: &[u8]) -> usize {
+        RAW_CONTAINER_HEADER_SIZE + extended_data_size(self.format) + input.len()
+    }
+
+    fn untransformed_size(&self, input: &[u8]) -> usize {
+        RawContainerHeader::read(input)
+            .ok()
+            .and_then(|header| usize::try_from(header.original_length).ok())
+            .unwrap_or(input.len())
+    }
+}
+
+impl FileFormatDetection for RawBlocksHandler {
+    fn can_handle(&self, input: &[u8], file_extension: Option<&str>) -> bool {
+        // Raw block data has no signature, so we rely on the extension
+        let Some(ext) = file_extension else {
+            return false;
+        };
+
+        ext == super::file_extension(self.format)
+            && !RawContainerHeader::has_magic(input)
+            && input.len().is_multiple_of(self.format.block_size())
+    }
+}
+
+impl FileFormatUntransformDetection for RawBlocksHandler {
+    fn can_handle_untransform(&self, input: &[u8], file_extension: Option<&str>) -> bool {
+        let Ok(header) = RawContainerHeader::read(input) else {
+            return false;
+        };
+
+        // If an extension is provided, it must match the stored format.
+        // Passthrough containers (and formats from newer versions) don't record the
+        // block format, so fall back to the format this handler was created for.
+        match (file_extension, header.transform_header.format()) {
+            (Some(ext), Some(format)) if format != TransformFormat::None => {
+                ext == super::file_extension(format)
+            }
+            (Some(ext), _) => ext == super::file_extension(self.format),
+            (None, _) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{transform_slice_with_bundle, untransform_slice};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use dxt_lossless_transform_api_common::estimate::NoEstimation;
+    use rstest::rstest;
+
+    /// Create pseudo-random block data so the transform does actual work.
+    fn create_block_data(len: usize) -> Vec<u8> {
+        let mut state = 0x1234_5678u32;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 24) as u8
+            })
+            .collect()
+    }
+
+    fn transform(handler: &RawBlocksHandler, input: &[u8]) -> Vec<u8> {
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+        let mut output = vec![0u8; handler.transformed_size(input)];
+        transform_slice_with_bundle(handler, input, &mut output, &bundle).unwrap();
+        output
+    }
+
+    #[rstest]
+    #[case::bc1(TransformFormat::Bc1, 8 * 64)]
+    #[case::bc2(TransformFormat::Bc2, 16 * 64)]
+    fn roundtrip_restores_original_data(#[case] format: TransformFormat, #[case] len: usize) {
+        for checksum in [false, true] {
+            let handler = RawBlocksHandler::new(format).with_checksum(checksum);
+            let input = create_block_data(len);
+
+            let transformed = transform(&handler, &input);
+            assert_eq!(transformed.len(), RAW_CONTAINER_HEADER_SIZE + len);
+            assert_eq!(handler.untransformed_size(&transformed), len);
+
+            let mut restored = vec![0u8; handler.untransformed_size(&transformed)];
+            untransform_slice(&handler, &transformed, &mut restored).unwrap();
+            assert_eq!(restored, input);
+        }
+    }
+
+    #[test]
+    fn transform_rejects_misaligned_input() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+        let input = create_block_data(15);
+        let mut output = vec![0u8; handler.transformed_size(&input)];
+
+        let result = handler.transform_bundle(&input, &mut output, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::InvalidDataAlignment {
+                size: 15,
+                required_divisor: 8
+            })
+        ));
+    }
+
+    #[test]
+    fn transform_rejects_output_buffer_too_small() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+        let input = create_block_data(64);
+        let mut output = vec![0u8; input.len()];
+
+        let result = handler.transform_bundle(&input, &mut output, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::OutputBufferTooSmall { required, actual: 64 }
+            )) if required == RAW_CONTAINER_HEADER_SIZE + 64
+        ));
+    }
+
+    #[test]
+    fn untransform_detects_corrupted_data_with_checksum() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1).with_checksum(true);
+        let input = create_block_data(8 * 16);
+        let mut transformed = transform(&handler, &input);
+        *transformed.last_mut().unwrap() ^= 0xFF;
+
+        let mut restored = vec![0u8; input.len()];
+        let result = handler.untransform(&transformed, &mut restored);
+        assert!(matches!(
+            result,
+            Err(TransformError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn bundle_checksum_is_stored_and_verified() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_checksum(true);
+        let input = create_block_data(8 * 16);
+        let mut transformed = vec![0u8; handler.transformed_size(&input)];
+        transform_slice_with_bundle(&handler, &input, &mut transformed, &bundle).unwrap();
+
+        let header = RawContainerHeader::read(&transformed).unwrap();
+        assert_eq!(header.checksum, Some(texture_checksum(&input)));
+
+        *transformed.last_mut().unwrap() ^= 0xFF;
+        let mut restored = vec![0u8; input.len()];
+        let result = handler.untransform(&transformed, &mut restored);
+        assert!(matches!(
+            result,
+            Err(TransformError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn transform_rejects_already_transformed_input() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+        let transformed = transform(&handler, &create_block_data(8 * 16));
+        let mut output = vec![0u8; handler.transformed_size(&transformed)];
+
+        let result = handler.transform_bundle(&transformed, &mut output, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::AlreadyTransformed
+            ))
+        ));
+    }
+
+    #[test]
+    fn untransform_rejects_original_input() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let input = create_block_data(8 * 16);
+        let mut restored = vec![0u8; input.len()];
+
+        let result = handler.untransform(&input, &mut restored);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::NotTransformed
+            ))
+        ));
+    }
+
+    #[test]
+    fn untransform_rejects_truncated_container() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let input = create_block_data(8 * 16);
+        let mut transformed = transform(&handler, &input);
+        transformed.truncate(transformed.len() - 8);
+
+        let mut restored = vec![0u8; input.len()];
+        let result = handler.untransform(&transformed, &mut restored);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::InputTooShortForStatedTextureSize { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn bc7_reserves_extended_data_area() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc7);
+        let input = create_block_data(16);
+        assert_eq!(
+            handler.transformed_size(&input),
+            RAW_CONTAINER_HEADER_SIZE + crate::embed::BC7_ADDITIONAL_SPACE + input.len()
+        );
+    }
+
+    #[test]
+    fn can_handle_requires_matching_extension() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let input = create_block_data(64);
+
+        assert!(handler.can_handle(&input, Some("bc1")));
+        assert!(!handler.can_handle(&input, Some("bc3")));
+        assert!(!handler.can_handle(&input, None));
+        assert!(!handler.can_handle(&input[..63], Some("bc1")));
+    }
+
+    #[test]
+    fn can_handle_untransform_detects_container() {
+        let handler = RawBlocksHandler::new(TransformFormat::Bc1);
+        let input = create_block_data(64);
+        let transformed = transform(&handler, &input);
+
+        assert!(handler.can_handle_untransform(&transformed, None));
+        assert!(handler.can_handle_untransform(&transformed, Some("bc1")));
+        assert!(!handler.can_handle_untransform(&transformed, Some("bc3")));
+        assert!(!handler.can_handle_untransform(&input, None));
+        assert!(!handler.can_handle(&transformed, Some("bc1")));
+
+        // Passthrough containers store no block format; the handler's format is used instead
+        let handler = RawBlocksHandler::new(TransformFormat::Bc3);
+        let bundle =
+            TransformBundle::<NoEstimation>::default_all().with_passthrough_unsupported(true);
+        let input = create_block_data(64);
+        let mut passthrough = vec![0u8; handler.transformed_size(&input)];
+        transform_slice_with_bundle(&handler, &input, &mut passthrough, &bundle).unwrap();
+
+        assert!(handler.can_handle_untransform(&passthrough, Some("bc3")));
+        assert!(!handler.can_handle_untransform(&passthrough, Some("bc1")));
+    }
+}
@@ -0,0 +1,62 @@
+//! Support for headerless block data, such as raw `.bc1`/`.bc3` blobs or GPU upload buffers.
+//!
+//! The embedding scheme used by handlers such as DDS relies on the file format having a few
+//! disposable header bytes (e.g. the magic) that can be overwritten with a [`TransformHeader`].
+//! Raw block streams have no such bytes, so this module instead wraps the transformed blocks
+//! in a small, self-describing container.
+//!
+//! # Container Layout
+//!
+//! All values are little endian.
+//!
+//! | Offset | Size | Field                                                                  |
+//! |--------|------|------------------------------------------------------------------------|
+//! | 0      | 4    | Magic ([`RAW_CONTAINER_MAGIC`], `"DLTB"`)                              |
+//! | 4      | 1    | Container version ([`RAW_CONTAINER_VERSION`])                          |
+//! | 5      | 1    | Flags (bit 0: checksum present)                                        |
+//! | 6      | 2    | Length of the extended data area                                       |
+//! | 8      | 8    | [`TransformHeader`] (standard headers use 4 bytes, the rest is zero)   |
+//! | 16     | 8    | Length of the original (untransformed) block data                      |
+//! | 24     | 8    | XXH3-64 checksum of the original block data (zero if not present)      |
+//! | 32     | N    | Extended data area (BC6H: [`BC6H_ADDITIONAL_SPACE`], BC7: [`BC7_ADDITIONAL_SPACE`]) |
+//! | 32 + N | ...  | Transformed block data                                                 |
+//!
+//! Use [`RawBlocksHandler`] to transform block data into this container and back.
+//!
+//! [`TransformHeader`]: crate::embed::TransformHeader
+//! [`BC6H_ADDITIONAL_SPACE`]: crate::embed::BC6H_ADDITIONAL_SPACE
+//! [`BC7_ADDITIONAL_SPACE`]: crate::embed::BC7_ADDITIONAL_SPACE
+
+mod container;
+mod handler;
+
+pub use container::*;
+pub use handler::*;
+
+use crate::embed::{TransformFormat, BC6H_ADDITIONAL_SPACE, BC7_ADDITIONAL_SPACE};
+
+/// Returns the size of the extended data area required by the format.
+pub(crate) fn extended_data_size(format: TransformFormat) -> usize {
+    match format {
+        TransformFormat::Bc6H => BC6H_ADDITIONAL_SPACE,
+        TransformFormat::Bc7 => BC7_ADDITIONAL_SPACE,
+        _ => 0,
+    }
+}
+
+/// Returns the conventional file extension for raw block data of the given format.
+pub(crate) fn file_extension(format: TransformFormat) -> &'static str {
+    match format {
+        TransformFormat::Bc1 => "bc1",
+        TransformFormat::Bc2 => "bc2",
+        TransformFormat::Bc3 => "bc3",
+        TransformFormat::Bc4 => "bc4",
+        TransformFormat::Bc5 => "bc5",
+        TransformFormat::Bc6H => "bc6h",
+        TransformFormat::Bc7 => "bc7",
+        TransformFormat::Rgba8888 => "rgba",
+        TransformFormat::Bgra8888 => "bgra",
+        TransformFormat::Bgr888 => "bgr",
+        TransformFormat::None => "bin",
+    }
+}
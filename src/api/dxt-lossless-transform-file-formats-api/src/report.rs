@@ -0,0 +1,126 @@
+//! Reports describing how data was transformed.
+//!
+//! See [`TransformReport`], as returned by the `*_with_report` APIs
+//! (e.g. [`transform_slice_with_report`]).
+//!
+//! [`transform_slice_with_report`]: crate::api::transform_slice_with_report
+
+use crate::embed::{TransformDetails, TransformFormat};
+use alloc::vec::Vec;
+use core::time::Duration;
+
+/// A transform configuration tested by an automatic transform builder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransformCandidate {
+    /// The tested settings.
+    pub details: TransformDetails,
+    /// Compressed size of the transformed data according to the bundle's size estimator, in bytes.
+    ///
+    /// Estimators only look at the parts of the data affected by the transform, so this is
+    /// only meaningful when compared against other candidates.
+    pub estimated_size: usize,
+}
+
+/// Describes a completed transform.
+///
+/// Returned by the `*_with_report` APIs, for use in telemetry and diagnostics.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransformReport {
+    /// Format of the texture data, as detected by the file format handler.
+    ///
+    /// [`None`] if the handler does not support reporting;
+    /// see [`FileFormatHandler::transform_bundle_with_report`].
+    ///
+    /// [`FileFormatHandler::transform_bundle_with_report`]: crate::handlers::FileFormatHandler::transform_bundle_with_report
+    pub format: Option<TransformFormat>,
+    /// The settings the texture data was transformed with.
+    ///
+    /// This is [`TransformDetails::None`] if the data was stored unchanged, e.g. with
+    /// [`TransformBundle::with_passthrough_unsupported`]. [`None`] if the handler does not
+    /// support reporting.
+    ///
+    /// [`TransformBundle::with_passthrough_unsupported`]: crate::TransformBundle::with_passthrough_unsupported
+    pub details: Option<TransformDetails>,
+    /// Every configuration tested by an automatic transform builder, in the order tested.
+    ///
+    /// Empty when manual settings were used.
+    pub candidates: Vec<TransformCandidate>,
+    /// Number of input bytes processed, including file headers.
+    pub bytes_processed: usize,
+    /// Time taken by the whole operation, including verification if enabled.
+    ///
+    /// [`None`] without the `std` feature.
+    pub elapsed: Option<Duration>,
+}
+
+impl TransformReport {
+    /// Returns the estimated compressed size of the chosen settings,
+    /// or [`None`] if no candidates were tested.
+    pub fn estimated_size(&self) -> Option<usize> {
+        let details = self.details?;
+        self.candidates
+            .iter()
+            .find(|x| x.details == details)
+            .map(|x| x.estimated_size)
+    }
+}
+
+/// Measures [`TransformReport::elapsed`]; always returns [`None`] without the `std` feature.
+pub(crate) struct Stopwatch {
+    #[cfg(feature = "std")]
+    start: std::time::Instant,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    pub(crate) fn elapsed(&self) -> Option<Duration> {
+        #[cfg(feature = "std")]
+        return Some(self.start.elapsed());
+        #[cfg(not(feature = "std"))]
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use dxt_lossless_transform_bc1::Bc1TransformSettings;
+    use dxt_lossless_transform_common::color_565::YCoCgVariant;
+
+    #[test]
+    fn estimated_size_returns_size_of_chosen_candidate() {
+        let chosen = Bc1TransformSettings {
+            decorrelation_mode: YCoCgVariant::Variant1,
+            split_colour_endpoints: true,
+        };
+        let other = Bc1TransformSettings {
+            decorrelation_mode: YCoCgVariant::None,
+            split_colour_endpoints: false,
+        };
+        let report = TransformReport {
+            format: Some(TransformFormat::Bc1),
+            details: Some(TransformDetails::Bc1(chosen)),
+            candidates: vec![
+                TransformCandidate {
+                    details: TransformDetails::Bc1(other),
+                    estimated_size: 200,
+                },
+                TransformCandidate {
+                    details: TransformDetails::Bc1(chosen),
+                    estimated_size: 100,
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(report.estimated_size(), Some(100));
+        assert_eq!(TransformReport::default().estimated_size(), None);
+    }
+}
@@ -166,6 +166,99 @@ impl FileFormatHandler for MockHandler {
     }
 }
 
+/// A handler which copies data on transform, but corrupts the byte at `corrupt_offset` on
+/// untransform. Used to test roundtrip verification.
+#[derive(Debug, Clone, Copy)]
+pub struct LossyHandler {
+    pub corrupt_offset: usize,
+}
+
+impl FileFormatDetection for LossyHandler {
+    fn can_handle(&self, _data: &[u8], _file_extension: Option<&str>) -> bool {
+        true
+    }
+}
+
+impl FileFormatUntransformDetection for LossyHandler {
+    fn can_handle_untransform(&self, _data: &[u8], _file_extension: Option<&str>) -> bool {
+        true
+    }
+}
+
+impl FileFormatHandler for LossyHandler {
+    fn transform_bundle<T>(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        _bundle: &TransformBundle<T>,
+    ) -> TransformResult<()>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        output[..input.len()].copy_from_slice(input);
+        Ok(())
+    }
+
+    fn untransform(&self, input: &[u8], output: &mut [u8]) -> TransformResult<()> {
+        output[..input.len()].copy_from_slice(input);
+        output[self.corrupt_offset] ^= 0xFF;
+        Ok(())
+    }
+}
+
+/// A handler which transforms the whole input as BC1 blocks, without storing the transform
+/// header. Used to test the default in-place implementations.
+#[derive(Debug, Clone, Copy)]
+pub struct Bc1BlocksHandler;
+
+impl FileFormatHandler for Bc1BlocksHandler {
+    fn transform_bundle<T>(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        bundle: &TransformBundle<T>,
+    ) -> TransformResult<()>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        crate::handlers::dispatch_transform(
+            crate::embed::TransformFormat::Bc1,
+            input,
+            output,
+            bundle,
+        )?;
+        Ok(())
+    }
+
+    fn untransform(&self, input: &[u8], output: &mut [u8]) -> TransformResult<()> {
+        crate::handlers::dispatch_untransform(create_test_bc1_header(), input, output)
+    }
+}
+
+/// A size estimator which always fails, for testing error paths of automatic transforms.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FailingEstimator;
+
+impl SizeEstimationOperations for FailingEstimator {
+    type Error = &'static str;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        Ok(len_bytes)
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        _input_ptr: *const u8,
+        _len_bytes: usize,
+        _output_ptr: *mut u8,
+        _output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        Err("estimation failed")
+    }
+}
+
 /// Create generic test data for testing.
 pub fn create_test_data(size: usize) -> Vec<u8> {
     vec![0u8; size]
@@ -476,6 +476,7 @@ mod tests {
         let transform_options = Bc1EstimateSettings {
             size_estimator: DummyEstimator,
             use_all_decorrelation_modes: false,
+            progress: None,
         };
 
         // This should not crash
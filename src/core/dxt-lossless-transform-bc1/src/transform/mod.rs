@@ -59,8 +59,10 @@ pub use transform_with_settings::*;
 
 // Re-export safe module functions
 pub use safe::{
-    transform_bc1_auto_safe, transform_bc1_with_settings_safe, untransform_bc1_with_settings_safe,
-    Bc1AutoTransformError, Bc1ValidationError,
+    transform_bc1_auto_safe, transform_bc1_auto_with_candidates_safe,
+    transform_bc1_auto_with_workspace_and_candidates_safe, transform_bc1_auto_with_workspace_safe,
+    transform_bc1_with_settings_safe, untransform_bc1_with_settings_safe, Bc1AutoTransformError,
+    Bc1ValidationError,
 };
 
 #[cfg(test)]
@@ -87,6 +89,7 @@ mod tests {
         let transform_options = Bc1EstimateSettings {
             size_estimator: DummyEstimator,
             use_all_decorrelation_modes: false,
+            progress: None,
         };
 
         // This should not crash and should produce transformed data
@@ -140,6 +143,7 @@ mod tests {
         let transform_options = Bc1EstimateSettings {
             size_estimator: FailingEstimator,
             use_all_decorrelation_modes: false,
+            progress: None,
         };
 
         let result = unsafe {
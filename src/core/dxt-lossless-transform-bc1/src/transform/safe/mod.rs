@@ -16,7 +16,13 @@ pub mod transform_auto;
 pub mod transform_with_settings;
 
 // Re-export the main functions with _safe suffix for discoverability
-pub use transform_auto::{transform_bc1_auto as transform_bc1_auto_safe, Bc1AutoTransformError};
+pub use transform_auto::{
+    transform_bc1_auto as transform_bc1_auto_safe,
+    transform_bc1_auto_with_candidates as transform_bc1_auto_with_candidates_safe,
+    transform_bc1_auto_with_workspace as transform_bc1_auto_with_workspace_safe,
+    transform_bc1_auto_with_workspace_and_candidates as transform_bc1_auto_with_workspace_and_candidates_safe,
+    Bc1AutoTransformError,
+};
 pub use transform_with_settings::{
     transform_bc1_with_settings as transform_bc1_with_settings_safe,
     untransform_bc1_with_settings as untransform_bc1_with_settings_safe, Bc1ValidationError,
@@ -3,8 +3,10 @@
 //! This module provides optimization functionality to determine the best
 //! transformation parameters for BC1 data compression.
 
-use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
-use dxt_lossless_transform_common::allocate::{allocate_align_64, AllocateError};
+use dxt_lossless_transform_api_common::estimate::{
+    AutoTransformWorkspace, ProgressCallback, SizeEstimationOperations,
+};
+use dxt_lossless_transform_common::allocate::AllocateError;
 use thiserror::Error;
 
 use super::settings::{Bc1TransformSettings, COMPREHENSIVE_TEST_ORDER, FAST_TEST_ORDER};
@@ -20,6 +22,10 @@ pub enum DetermineBestTransformError<E> {
     /// An error that happened during size estimation
     #[error("Size estimation failed: {0:?}")]
     SizeEstimationError(E),
+
+    /// The transform was cancelled by the [`ProgressCallback`] in the estimate settings
+    #[error("Transform was cancelled")]
+    Cancelled,
 }
 
 /// The settings for [`transform_bc1_auto`], regarding how the estimation is done,
@@ -66,6 +72,12 @@ where
     /// [YCoCgVariant::Variant3]: dxt_lossless_transform_common::color_565::YCoCgVariant::Variant3
     /// [YCoCgVariant::None]: dxt_lossless_transform_common::color_565::YCoCgVariant::None
     pub use_all_decorrelation_modes: bool,
+
+    /// Optional callback notified after each tested configuration, which can cancel the transform.
+    ///
+    /// When cancelled, [`transform_bc1_auto`] returns [`DetermineBestTransformError::Cancelled`]
+    /// and the contents of the output buffer are unspecified.
+    pub progress: Option<ProgressCallback>,
 }
 
 /// Transform BC1 data using the best determined settings.
@@ -162,6 +174,7 @@ where
 /// let options = Bc1EstimateSettings {
 ///     size_estimator: MyCompressionEstimator,
 ///     use_all_decorrelation_modes: false, // Fast mode
+///     progress: None,
 /// };
 ///
 /// // Transform with optimal settings (unsafe due to raw pointers)
@@ -203,6 +216,91 @@ pub unsafe fn transform_bc1_auto<T>(
     len: usize,
     transform_options: &Bc1EstimateSettings<T>,
 ) -> Result<Bc1TransformSettings, DetermineBestTransformError<T::Error>>
+where
+    T: SizeEstimationOperations,
+{
+    transform_bc1_auto_with_candidates(input_ptr, output_ptr, len, transform_options, |_, _| {})
+}
+
+/// Transform BC1 data using the best determined settings, reporting every tested candidate.
+///
+/// This is [`transform_bc1_auto`], but `on_candidate` is called with the settings and the
+/// estimated compressed size of each configuration tested, in the order they were tested.
+/// Useful for diagnostics and telemetry.
+///
+/// # Safety
+///
+/// Same requirements as [`transform_bc1_auto`].
+pub unsafe fn transform_bc1_auto_with_candidates<T>(
+    input_ptr: *const u8,
+    output_ptr: *mut u8,
+    len: usize,
+    transform_options: &Bc1EstimateSettings<T>,
+    on_candidate: impl FnMut(Bc1TransformSettings, usize),
+) -> Result<Bc1TransformSettings, DetermineBestTransformError<T::Error>>
+where
+    T: SizeEstimationOperations,
+{
+    transform_bc1_auto_with_workspace_and_candidates(
+        input_ptr,
+        output_ptr,
+        len,
+        transform_options,
+        &mut AutoTransformWorkspace::new(),
+        on_candidate,
+    )
+}
+
+/// Transform Bc1 data using the best determined settings, reusing the scratch memory
+/// held by `workspace`.
+///
+/// This is [`transform_bc1_auto`], but the compression buffer used during estimation is taken
+/// from (and kept in) the [`AutoTransformWorkspace`] rather than allocated for each call.
+/// Reusing one workspace when transforming many textures avoids repeated allocations.
+///
+/// The workspace only holds memory owned by this crate; state internal to the size estimator
+/// (e.g. a compression context) is cached by the estimator itself, so reuse the same
+/// [`Bc1EstimateSettings`] alongside the workspace.
+///
+/// # Safety
+///
+/// Same requirements as [`transform_bc1_auto`].
+pub unsafe fn transform_bc1_auto_with_workspace<T>(
+    input_ptr: *const u8,
+    output_ptr: *mut u8,
+    len: usize,
+    transform_options: &Bc1EstimateSettings<T>,
+    workspace: &mut AutoTransformWorkspace,
+) -> Result<Bc1TransformSettings, DetermineBestTransformError<T::Error>>
+where
+    T: SizeEstimationOperations,
+{
+    transform_bc1_auto_with_workspace_and_candidates(
+        input_ptr,
+        output_ptr,
+        len,
+        transform_options,
+        workspace,
+        |_, _| {},
+    )
+}
+
+/// Transform BC1 data using the best determined settings, reusing the scratch memory held by
+/// `workspace` and reporting every tested candidate.
+///
+/// This combines [`transform_bc1_auto_with_workspace`] and [`transform_bc1_auto_with_candidates`].
+///
+/// # Safety
+///
+/// Same requirements as [`transform_bc1_auto`].
+pub unsafe fn transform_bc1_auto_with_workspace_and_candidates<T>(
+    input_ptr: *const u8,
+    output_ptr: *mut u8,
+    len: usize,
+    transform_options: &Bc1EstimateSettings<T>,
+    workspace: &mut AutoTransformWorkspace,
+    mut on_candidate: impl FnMut(Bc1TransformSettings, usize),
+) -> Result<Bc1TransformSettings, DetermineBestTransformError<T::Error>>
 where
     T: SizeEstimationOperations,
 {
@@ -217,14 +315,8 @@ where
         .max_compressed_size(len / 2)
         .map_err(DetermineBestTransformError::SizeEstimationError)?;
 
-    // Allocate compression buffer if needed (reused across all calls)
-    let (comp_buffer_ptr, comp_buffer_len, _comp_buffer) = if max_comp_size == 0 {
-        (core::ptr::null_mut(), 0, None)
-    } else {
-        let mut comp_buffer = allocate_align_64(max_comp_size)?;
-        let ptr = comp_buffer.as_mut_ptr();
-        (ptr, max_comp_size, Some(comp_buffer))
-    };
+    // Take the compression buffer from the workspace (reused across all calls)
+    let (comp_buffer_ptr, comp_buffer_len) = workspace.compression_buffer(max_comp_size)?;
 
     // Test transforms in order of decreasing probability, with most common (YCoCg1/Split) last
     // This minimizes redundant final transforms since YCoCg1/Split is optimal ~71% of the time
@@ -234,7 +326,7 @@ where
         FAST_TEST_ORDER
     };
 
-    for &(decorrelation_mode, split_colours) in test_order {
+    for (index, &(decorrelation_mode, split_colours)) in test_order.iter().enumerate() {
         // Get the current mode we're testing.
         let current_mode = Bc1TransformSettings {
             decorrelation_mode,
@@ -254,10 +346,18 @@ where
             .size_estimator
             .estimate_compressed_size(output_ptr, len / 2, comp_buffer_ptr, comp_buffer_len)
             .map_err(DetermineBestTransformError::SizeEstimationError)?;
+        on_candidate(current_mode, result_size);
         if result_size < best_size {
             best_size = result_size;
             best_transform_settings = current_mode;
         }
+
+        // Report progress, stopping early if the caller requested cancellation
+        if let Some(progress) = &transform_options.progress {
+            if progress.report(index + 1, test_order.len()).is_break() {
+                return Err(DetermineBestTransformError::Cancelled);
+            }
+        }
     }
 
     // If the best option wasn't the last one tested, we need to transform again
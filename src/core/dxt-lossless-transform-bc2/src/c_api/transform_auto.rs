@@ -184,6 +184,7 @@ pub unsafe extern "C" fn dltbc2core_transform_auto(
     let options = Bc2EstimateSettings {
         size_estimator: estimator_ref,
         use_all_decorrelation_modes: settings.use_all_modes,
+        progress: None,
     };
 
     // Transform with automatic optimization using core crate's safe function
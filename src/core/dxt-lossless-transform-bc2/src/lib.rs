@@ -25,8 +25,11 @@ pub mod test_prelude;
 
 // Re-export transform module contents for advanced BC2 operations
 pub use transform::{
-    transform_bc2_auto, transform_bc2_auto_safe, transform_bc2_with_settings,
-    transform_bc2_with_settings_safe, untransform_bc2_with_settings,
+    transform_bc2_auto, transform_bc2_auto_safe, transform_bc2_auto_with_candidates,
+    transform_bc2_auto_with_candidates_safe, transform_bc2_auto_with_workspace,
+    transform_bc2_auto_with_workspace_and_candidates,
+    transform_bc2_auto_with_workspace_and_candidates_safe, transform_bc2_auto_with_workspace_safe,
+    transform_bc2_with_settings, transform_bc2_with_settings_safe, untransform_bc2_with_settings,
     untransform_bc2_with_settings_safe, Bc2AutoTransformError, Bc2EstimateSettings,
     Bc2TransformSettings, Bc2UntransformSettings, Bc2ValidationError, DetermineBestTransformError,
 };
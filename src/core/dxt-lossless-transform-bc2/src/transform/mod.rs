@@ -59,6 +59,8 @@ pub use transform_with_settings::*;
 
 // Re-export safe module functions
 pub use safe::{
-    transform_bc2_auto_safe, transform_bc2_with_settings_safe, untransform_bc2_with_settings_safe,
-    Bc2AutoTransformError, Bc2ValidationError,
+    transform_bc2_auto_safe, transform_bc2_auto_with_candidates_safe,
+    transform_bc2_auto_with_workspace_and_candidates_safe, transform_bc2_auto_with_workspace_safe,
+    transform_bc2_with_settings_safe, untransform_bc2_with_settings_safe, Bc2AutoTransformError,
+    Bc2ValidationError,
 };
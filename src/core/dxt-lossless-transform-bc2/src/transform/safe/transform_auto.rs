@@ -7,10 +7,12 @@
 //! `dxt-lossless-transform-bc2-api::Bc2AutoTransformBuilder`.
 
 use crate::transform::{
-    transform_bc2_auto as unsafe_transform_bc2_auto, Bc2EstimateSettings, Bc2TransformSettings,
-    DetermineBestTransformError,
+    transform_bc2_auto_with_workspace_and_candidates as unsafe_transform_bc2_auto_with_workspace_and_candidates,
+    Bc2EstimateSettings, Bc2TransformSettings, DetermineBestTransformError,
+};
+use dxt_lossless_transform_api_common::estimate::{
+    AutoTransformWorkspace, SizeEstimationOperations,
 };
-use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 
 /// Extended error type that includes validation errors.
 #[derive(Debug)]
@@ -66,6 +68,7 @@ pub enum Bc2AutoTransformError<T> {
 /// let options = Bc2EstimateSettings {
 ///     size_estimator: estimator,
 ///     use_all_decorrelation_modes: false,
+///     progress: None,
 /// };
 ///
 /// let _transform_details = transform_bc2_auto_safe(&bc2_data, &mut output, &options)?;
@@ -100,6 +103,93 @@ pub fn transform_bc2_auto<T>(
 where
     T: SizeEstimationOperations,
 {
+    transform_bc2_auto_with_candidates(input, output, options, |_, _| {})
+}
+
+/// Transform BC2 data using automatically determined optimal settings, reporting every tested candidate.
+///
+/// This is [`transform_bc2_auto`], but `on_candidate` is called with the settings and the
+/// estimated compressed size of each configuration tested, in the order they were tested.
+///
+/// # Errors
+///
+/// Same as [`transform_bc2_auto`].
+pub fn transform_bc2_auto_with_candidates<T>(
+    input: &[u8],
+    output: &mut [u8],
+    options: &Bc2EstimateSettings<T>,
+    on_candidate: impl FnMut(Bc2TransformSettings, usize),
+) -> Result<Bc2TransformSettings, Bc2AutoTransformError<T::Error>>
+where
+    T: SizeEstimationOperations,
+{
+    transform_bc2_auto_with_workspace_and_candidates(
+        input,
+        output,
+        options,
+        &mut AutoTransformWorkspace::new(),
+        on_candidate,
+    )
+}
+
+/// Transform Bc2 data using automatically determined optimal settings, reusing the scratch
+/// memory held by `workspace`.
+///
+/// This is [`transform_bc2_auto`], but the compression buffer used during estimation is kept
+/// in the [`AutoTransformWorkspace`], so transforming many textures with the same workspace
+/// avoids allocating for each one.
+///
+/// # Errors
+///
+/// Same as [`transform_bc2_auto`].
+pub fn transform_bc2_auto_with_workspace<T>(
+    input: &[u8],
+    output: &mut [u8],
+    options: &Bc2EstimateSettings<T>,
+    workspace: &mut AutoTransformWorkspace,
+) -> Result<Bc2TransformSettings, Bc2AutoTransformError<T::Error>>
+where
+    T: SizeEstimationOperations,
+{
+    transform_bc2_auto_with_workspace_and_candidates(input, output, options, workspace, |_, _| {})
+}
+
+/// Transform Bc2 data using automatically determined optimal settings, reusing the scratch
+/// memory held by `workspace` and reporting every tested candidate.
+///
+/// This combines [`transform_bc2_auto_with_workspace`] and [`transform_bc2_auto_with_candidates`].
+///
+/// # Errors
+///
+/// Same as [`transform_bc2_auto`].
+pub fn transform_bc2_auto_with_workspace_and_candidates<T>(
+    input: &[u8],
+    output: &mut [u8],
+    options: &Bc2EstimateSettings<T>,
+    workspace: &mut AutoTransformWorkspace,
+    on_candidate: impl FnMut(Bc2TransformSettings, usize),
+) -> Result<Bc2TransformSettings, Bc2AutoTransformError<T::Error>>
+where
+    T: SizeEstimationOperations,
+{
+    validate_buffers(input, output)?;
+
+    // Safety: We're passing valid slices to the unsafe function
+    let result = unsafe {
+        unsafe_transform_bc2_auto_with_workspace_and_candidates(
+            input.as_ptr(),
+            output.as_mut_ptr(),
+            input.len(),
+            options,
+            workspace,
+            on_candidate,
+        )
+    };
+
+    result.map_err(Bc2AutoTransformError::DetermineBestTransform)
+}
+
+fn validate_buffers<E>(input: &[u8], output: &[u8]) -> Result<(), Bc2AutoTransformError<E>> {
     // Validate input length
     if !input.len().is_multiple_of(16) {
         return Err(Bc2AutoTransformError::InvalidLength(input.len()));
@@ -113,18 +203,16 @@ where
         });
     }
 
-    // Safety: We're passing valid slices to the unsafe function
-    let result = unsafe {
-        unsafe_transform_bc2_auto(input.as_ptr(), output.as_mut_ptr(), input.len(), options)
-    };
-
-    result.map_err(Bc2AutoTransformError::DetermineBestTransform)
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_prelude::DummyEstimator;
+    use crate::test_prelude::{vec, Arc, DummyEstimator, Vec};
+    use core::ops::ControlFlow;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use dxt_lossless_transform_api_common::estimate::ProgressCallback;
 
     #[test]
     #[allow(unreachable_code)]
@@ -145,6 +233,7 @@ mod tests {
         let options = Bc2EstimateSettings {
             size_estimator: DummyEstimator,
             use_all_decorrelation_modes: false,
+            progress: None,
         };
 
         let result = super::transform_bc2_auto(&bc2_data, &mut output, &options);
@@ -153,4 +242,138 @@ mod tests {
             "Function should not fail with valid BC2 data"
         );
     }
+
+    #[rstest::rstest]
+    #[case::first_candidate(1)]
+    #[case::last_candidate(crate::transform::settings::FAST_TEST_ORDER.len())]
+    #[case::never(usize::MAX)]
+    fn test_transform_bc2_auto_reports_progress_and_cancels(#[case] cancel_after: usize) {
+        let bc2_data = [0u8; 64];
+        let mut output = [0u8; 64];
+        let total = crate::transform::settings::FAST_TEST_ORDER.len();
+        let reported = Arc::new(AtomicUsize::new(0));
+        let options = Bc2EstimateSettings {
+            size_estimator: DummyEstimator,
+            use_all_decorrelation_modes: false,
+            progress: Some(ProgressCallback::new({
+                let reported = reported.clone();
+                move |progress| {
+                    assert_eq!(progress.total, total);
+                    assert_eq!(
+                        progress.completed,
+                        reported.fetch_add(1, Ordering::Relaxed) + 1
+                    );
+                    if progress.completed == cancel_after {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                }
+            })),
+        };
+
+        let result = transform_bc2_auto(&bc2_data, &mut output, &options);
+        if cancel_after <= total {
+            assert!(matches!(
+                result,
+                Err(Bc2AutoTransformError::DetermineBestTransform(
+                    DetermineBestTransformError::Cancelled
+                ))
+            ));
+        } else {
+            assert!(result.is_ok());
+        }
+        assert_eq!(reported.load(Ordering::Relaxed), cancel_after.min(total));
+    }
+
+    /// Estimator which needs a compression buffer, to exercise the workspace.
+    struct BufferedEstimator;
+
+    impl SizeEstimationOperations for BufferedEstimator {
+        type Error = &'static str;
+
+        fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+            Ok(len_bytes)
+        }
+
+        unsafe fn estimate_compressed_size(
+            &self,
+            input_ptr: *const u8,
+            len_bytes: usize,
+            output_ptr: *mut u8,
+            output_len: usize,
+        ) -> Result<usize, Self::Error> {
+            assert!(output_len >= len_bytes);
+            core::ptr::copy_nonoverlapping(input_ptr, output_ptr, len_bytes);
+            Ok(len_bytes)
+        }
+    }
+
+    #[test]
+    fn test_transform_bc2_auto_with_workspace_reuses_buffer() {
+        let input: Vec<u8> = (0..16 * 16).map(|x| x as u8).collect();
+        let options = Bc2EstimateSettings {
+            size_estimator: BufferedEstimator,
+            use_all_decorrelation_modes: false,
+            progress: None,
+        };
+
+        let mut expected = vec![0u8; input.len()];
+        let expected_settings = transform_bc2_auto(&input, &mut expected, &options).unwrap();
+
+        let mut workspace = AutoTransformWorkspace::new();
+        let mut output = vec![0u8; input.len()];
+        let settings =
+            transform_bc2_auto_with_workspace(&input, &mut output, &options, &mut workspace)
+                .unwrap();
+        let capacity = workspace.capacity();
+        assert!(capacity > 0);
+        assert_eq!(settings, expected_settings);
+        assert_eq!(output, expected);
+
+        // Smaller inputs reuse the existing buffer
+        let mut output = vec![0u8; input.len() / 2];
+        transform_bc2_auto_with_workspace(
+            &input[..input.len() / 2],
+            &mut output,
+            &options,
+            &mut workspace,
+        )
+        .unwrap();
+        assert_eq!(workspace.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_transform_bc2_auto_with_workspace_and_candidates() {
+        let input: Vec<u8> = (0..16 * 16).map(|x| x as u8).collect();
+        let options = Bc2EstimateSettings {
+            size_estimator: BufferedEstimator,
+            use_all_decorrelation_modes: false,
+            progress: None,
+        };
+
+        let mut expected = vec![0u8; input.len()];
+        let mut expected_candidates = Vec::new();
+        let expected_settings =
+            transform_bc2_auto_with_candidates(&input, &mut expected, &options, |x, size| {
+                expected_candidates.push((x, size))
+            })
+            .unwrap();
+
+        let mut workspace = AutoTransformWorkspace::new();
+        let mut output = vec![0u8; input.len()];
+        let mut candidates = Vec::new();
+        let settings = transform_bc2_auto_with_workspace_and_candidates(
+            &input,
+            &mut output,
+            &options,
+            &mut workspace,
+            |x, size| candidates.push((x, size)),
+        )
+        .unwrap();
+        assert!(workspace.capacity() > 0);
+        assert_eq!(settings, expected_settings);
+        assert_eq!(candidates, expected_candidates);
+        assert_eq!(output, expected);
+    }
 }
@@ -10,7 +10,7 @@ extern crate alloc;
 extern crate std;
 
 // Re-export commonly used alloc types for tests
-pub use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+pub use alloc::{boxed::Box, format, string::String, sync::Arc, vec, vec::Vec};
 
 // Re-export std items for tests that need them
 pub use std::is_x86_feature_detected;
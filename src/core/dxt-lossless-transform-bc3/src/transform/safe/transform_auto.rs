@@ -66,6 +66,7 @@ pub enum Bc3AutoTransformError<T> {
 /// let options = Bc3EstimateSettings {
 ///     size_estimator: estimator,
 ///     use_all_decorrelation_modes: false,
+///     progress: None,
 /// };
 ///
 /// let _transform_details = transform_bc3_auto_safe(&bc3_data, &mut output, &options)?;
@@ -123,7 +124,10 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+    use crate::test_prelude::Arc;
+    use core::ops::ControlFlow;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use dxt_lossless_transform_api_common::estimate::{ProgressCallback, SizeEstimationOperations};
 
     // Mock estimator for testing
     struct MockEstimator;
@@ -154,6 +158,7 @@ mod tests {
         let options = Bc3EstimateSettings {
             size_estimator: estimator,
             use_all_decorrelation_modes: false,
+            progress: None,
         };
 
         let result = transform_bc3_auto(&bc3_data, &mut output, &options);
@@ -171,6 +176,7 @@ mod tests {
         let options = Bc3EstimateSettings {
             size_estimator: estimator,
             use_all_decorrelation_modes: false,
+            progress: None,
         };
 
         let result = transform_bc3_auto(&bc3_data, &mut output, &options);
@@ -182,4 +188,47 @@ mod tests {
             })
         ));
     }
+
+    #[rstest::rstest]
+    #[case::first_candidate(1)]
+    #[case::last_candidate(crate::transform::settings::FAST_TEST_ORDER.len())]
+    #[case::never(usize::MAX)]
+    fn test_transform_bc3_auto_reports_progress_and_cancels(#[case] cancel_after: usize) {
+        let bc3_data = [0u8; 64];
+        let mut output = [0u8; 64];
+        let total = crate::transform::settings::FAST_TEST_ORDER.len();
+        let reported = Arc::new(AtomicUsize::new(0));
+        let options = Bc3EstimateSettings {
+            size_estimator: MockEstimator,
+            use_all_decorrelation_modes: false,
+            progress: Some(ProgressCallback::new({
+                let reported = reported.clone();
+                move |progress| {
+                    assert_eq!(progress.total, total);
+                    assert_eq!(
+                        progress.completed,
+                        reported.fetch_add(1, Ordering::Relaxed) + 1
+                    );
+                    if progress.completed == cancel_after {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                }
+            })),
+        };
+
+        let result = transform_bc3_auto(&bc3_data, &mut output, &options);
+        if cancel_after <= total {
+            assert!(matches!(
+                result,
+                Err(Bc3AutoTransformError::DetermineBestTransform(
+                    DetermineBestTransformError::Cancelled
+                ))
+            ));
+        } else {
+            assert!(result.is_ok());
+        }
+        assert_eq!(reported.load(Ordering::Relaxed), cancel_after.min(total));
+    }
 }
@@ -3,7 +3,7 @@
 //! This module provides optimization functionality to determine the best
 //! transformation parameters for BC3 data compression.
 
-use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use dxt_lossless_transform_api_common::estimate::{ProgressCallback, SizeEstimationOperations};
 use dxt_lossless_transform_common::allocate::{allocate_align_64, AllocateError};
 use thiserror::Error;
 
@@ -20,6 +20,10 @@ pub enum DetermineBestTransformError<E> {
     /// An error that happened during size estimation
     #[error("Size estimation failed: {0:?}")]
     SizeEstimationError(E),
+
+    /// The transform was cancelled by the [`ProgressCallback`] in the estimate settings
+    #[error("Transform was cancelled")]
+    Cancelled,
 }
 
 /// The settings for [`transform_bc3_auto`], regarding how the estimation is done,
@@ -66,6 +70,12 @@ where
     /// [YCoCgVariant::Variant3]: dxt_lossless_transform_common::color_565::YCoCgVariant::Variant3
     /// [YCoCgVariant::None]: dxt_lossless_transform_common::color_565::YCoCgVariant::None
     pub use_all_decorrelation_modes: bool,
+
+    /// Optional callback notified after each tested configuration, which can cancel the transform.
+    ///
+    /// When cancelled, [`transform_bc3_auto`] returns [`DetermineBestTransformError::Cancelled`]
+    /// and the contents of the output buffer are unspecified.
+    pub progress: Option<ProgressCallback>,
 }
 
 /// Transform BC3 data using the best determined settings.
@@ -160,6 +170,7 @@ where
 /// let options = Bc3EstimateSettings {
 ///     size_estimator: MyCompressionEstimator,
 ///     use_all_decorrelation_modes: false, // Fast mode
+///     progress: None,
 /// };
 ///
 /// // Transform with optimal settings (unsafe due to raw pointers)
@@ -231,7 +242,8 @@ where
         FAST_TEST_ORDER
     };
 
-    for &(decorrelation_mode, split_alphas, split_colours) in test_order {
+    for (index, &(decorrelation_mode, split_alphas, split_colours)) in test_order.iter().enumerate()
+    {
         // Get the current mode we're testing.
         let current_mode = Bc3TransformSettings {
             decorrelation_mode,
@@ -282,6 +294,13 @@ where
             best_size = total_result_size;
             best_transform_settings = current_mode;
         }
+
+        // Report progress, stopping early if the caller requested cancellation
+        if let Some(progress) = &transform_options.progress {
+            if progress.report(index + 1, test_order.len()).is_break() {
+                return Err(DetermineBestTransformError::Cancelled);
+            }
+        }
     }
 
     // If the best option wasn't the last one tested, we need to transform again
@@ -0,0 +1,55 @@
+//! Zero-copy access to objects supporting the Python buffer protocol.
+//!
+//! The returned slices borrow the [`PyBuffer`], which keeps the underlying memory alive
+//! (and prevents e.g. a `bytearray` from being resized) until it is dropped.
+
+use core::slice;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::{PyBufferError, PyValueError};
+use pyo3::PyResult;
+
+/// Returns the contents of a C-contiguous buffer.
+pub(crate) fn as_slice(buffer: &PyBuffer<u8>) -> PyResult<&[u8]> {
+    if !buffer.is_c_contiguous() {
+        return Err(PyBufferError::new_err("buffer must be C-contiguous"));
+    }
+
+    if buffer.len_bytes() == 0 {
+        return Ok(&[]);
+    }
+
+    // SAFETY: The buffer is contiguous and stays valid while `buffer` is borrowed.
+    Ok(unsafe { slice::from_raw_parts(buffer.buf_ptr() as *const u8, buffer.len_bytes()) })
+}
+
+/// Returns the contents of a writable, C-contiguous buffer which does not overlap `input`.
+///
+/// Takes the [`PyBuffer`] mutably, so only one mutable slice can exist per buffer.
+pub(crate) fn as_mut_slice<'a>(
+    buffer: &'a mut PyBuffer<u8>,
+    input: &[u8],
+) -> PyResult<&'a mut [u8]> {
+    if buffer.readonly() {
+        return Err(PyBufferError::new_err("output buffer must be writable"));
+    }
+    if !buffer.is_c_contiguous() {
+        return Err(PyBufferError::new_err("buffer must be C-contiguous"));
+    }
+
+    if buffer.len_bytes() == 0 {
+        return Ok(&mut []);
+    }
+
+    let start = buffer.buf_ptr() as *mut u8;
+    let output = start as usize..start as usize + buffer.len_bytes();
+    let input = input.as_ptr_range();
+    if output.start < input.end as usize && (input.start as usize) < output.end {
+        return Err(PyValueError::new_err(
+            "input and output buffers must not overlap",
+        ));
+    }
+
+    // SAFETY: The buffer is writable, contiguous, doesn't alias `input` and
+    // stays valid while `buffer` is borrowed.
+    Ok(unsafe { slice::from_raw_parts_mut(start, buffer.len_bytes()) })
+}
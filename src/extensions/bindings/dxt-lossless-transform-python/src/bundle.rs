@@ -0,0 +1,183 @@
+//! Transform settings for every supported format, used by file-level transforms.
+
+use crate::estimator::Estimator;
+use crate::settings::{Bc1Settings, Bc2Settings};
+use core::mem;
+use core::str::FromStr;
+use dxt_lossless_transform_bc1_api::Bc1AutoTransformBuilder;
+use dxt_lossless_transform_bc2_api::Bc2AutoTransformBuilder;
+use dxt_lossless_transform_file_formats_api::bundle::{PresetEstimator, TransformPreset};
+use dxt_lossless_transform_file_formats_api::TransformBundle;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Transform settings for every supported format, used by `transform` and `transform_file`.
+///
+/// `TransformBundle(preset)` creates a bundle from a preset (`"low"`, `"medium"`,
+/// `"optimal"` or `"max"`); `TransformBundle()` creates an empty bundle, to be configured
+/// with the `with_*` methods. Formats without settings can't be transformed, unless
+/// `passthrough_unsupported` is set.
+#[pyclass(name = "TransformBundle", module = "dxt_lossless_transform")]
+pub struct PyTransformBundle {
+    pub(crate) bundle: TransformBundle<PresetEstimator>,
+}
+
+#[pymethods]
+impl PyTransformBundle {
+    #[new]
+    #[pyo3(signature = (preset = None))]
+    fn new(preset: Option<&str>) -> PyResult<Self> {
+        let bundle = match preset {
+            Some(preset) => TransformPreset::from_str(preset)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+                .bundle(),
+            None => TransformBundle::new(),
+        };
+        Ok(Self { bundle })
+    }
+
+    /// Transform BC1 data with fixed settings. Returns the bundle.
+    fn with_bc1_manual(mut slf: PyRefMut<'_, Self>, settings: Bc1Settings) -> PyRefMut<'_, Self> {
+        slf.update(|bundle| bundle.with_bc1_manual(settings.0));
+        slf
+    }
+
+    /// Transform BC1 data with the settings that compress best according to `estimator`.
+    ///
+    /// `ultra` tests every setting combination; typically less than 0.1% smaller, but much slower.
+    /// Returns the bundle.
+    #[pyo3(signature = (estimator = Estimator::default(), ultra = false))]
+    fn with_bc1_auto(
+        mut slf: PyRefMut<'_, Self>,
+        estimator: Estimator,
+        ultra: bool,
+    ) -> PyRefMut<'_, Self> {
+        let builder =
+            Bc1AutoTransformBuilder::new(estimator.create()).use_all_decorrelation_modes(ultra);
+        slf.update(|bundle| bundle.with_bc1_auto(builder));
+        slf
+    }
+
+    /// Transform BC2 data with fixed settings. Returns the bundle.
+    fn with_bc2_manual(mut slf: PyRefMut<'_, Self>, settings: Bc2Settings) -> PyRefMut<'_, Self> {
+        slf.update(|bundle| bundle.with_bc2_manual(settings.0));
+        slf
+    }
+
+    /// Transform BC2 data with the settings that compress best according to `estimator`.
+    ///
+    /// `ultra` tests every setting combination; typically less than 0.1% smaller, but much slower.
+    /// Returns the bundle.
+    #[pyo3(signature = (estimator = Estimator::default(), ultra = false))]
+    fn with_bc2_auto(
+        mut slf: PyRefMut<'_, Self>,
+        estimator: Estimator,
+        ultra: bool,
+    ) -> PyRefMut<'_, Self> {
+        let builder =
+            Bc2AutoTransformBuilder::new(estimator.create()).use_all_decorrelation_modes(ultra);
+        slf.update(|bundle| bundle.with_bc2_auto(builder));
+        slf
+    }
+
+    /// Store texture data of formats without settings unchanged, instead of failing.
+    #[getter]
+    fn passthrough_unsupported(&self) -> bool {
+        self.bundle.passthrough_unsupported()
+    }
+
+    #[setter]
+    fn set_passthrough_unsupported(&mut self, enabled: bool) {
+        self.update(|bundle| bundle.with_passthrough_unsupported(enabled));
+    }
+
+    /// Untransform every transformed file and compare it against the original.
+    #[getter]
+    fn verify(&self) -> bool {
+        self.bundle.verify()
+    }
+
+    #[setter]
+    fn set_verify(&mut self, enabled: bool) {
+        self.update(|bundle| bundle.with_verify(enabled));
+    }
+
+    /// Store a checksum of the texture data, verified when untransforming.
+    #[getter]
+    fn checksum(&self) -> bool {
+        self.bundle.checksum()
+    }
+
+    #[setter]
+    fn set_checksum(&mut self, enabled: bool) {
+        self.update(|bundle| bundle.with_checksum(enabled));
+    }
+
+    /// Transform files with less texture data than their header states, instead of failing.
+    #[getter]
+    fn allow_truncated(&self) -> bool {
+        self.bundle.allow_truncated()
+    }
+
+    #[setter]
+    fn set_allow_truncated(&mut self, enabled: bool) {
+        self.update(|bundle| bundle.with_allow_truncated(enabled));
+    }
+}
+
+impl PyTransformBundle {
+    /// Applies a builder method of [`TransformBundle`] in place.
+    fn update(
+        &mut self,
+        f: impl FnOnce(TransformBundle<PresetEstimator>) -> TransformBundle<PresetEstimator>,
+    ) {
+        let bundle = mem::replace(&mut self.bundle, TransformBundle::new());
+        self.bundle = f(bundle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_prelude::*;
+
+    #[test]
+    fn bundle_flags_can_be_set() {
+        run_python(
+            c"
+bundle = dlt.TransformBundle('low')
+assert not bundle.checksum
+bundle.checksum = True
+bundle.verify = True
+bundle.passthrough_unsupported = True
+bundle.allow_truncated = True
+assert bundle.checksum and bundle.verify
+assert bundle.passthrough_unsupported and bundle.allow_truncated
+",
+        );
+    }
+
+    #[test]
+    fn bundle_builder_methods_chain() {
+        run_python(
+            c"
+bundle = dlt.TransformBundle() \\
+    .with_bc1_manual(dlt.Bc1Settings('variant2')) \\
+    .with_bc2_auto(dlt.Estimator.ltu(), ultra=True)
+assert isinstance(bundle, dlt.TransformBundle)
+",
+        );
+    }
+
+    #[test]
+    fn bundle_rejects_unknown_preset() {
+        run_python(
+            c"
+try:
+    dlt.TransformBundle('fastest')
+    raise AssertionError('expected ValueError')
+except ValueError as e:
+    assert 'fastest' in str(e)
+",
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Python exceptions raised by the bindings.
+//!
+//! Invalid arguments (e.g. an unknown preset name) raise `ValueError`, and file I/O
+//! failures raise `OSError`. All other failures raise [`TransformError`].
+
+use core::fmt::Display;
+use dxt_lossless_transform_file_formats_api::file_io::FileOperationError;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyOSError};
+use pyo3::PyErr;
+
+create_exception!(
+    dxt_lossless_transform,
+    TransformError,
+    PyException,
+    "Raised when transforming, untransforming or inspecting data fails."
+);
+
+/// Converts a transform error of the Rust API into a [`TransformError`] exception.
+pub(crate) fn transform_error(error: impl Display) -> PyErr {
+    TransformError::new_err(error.to_string())
+}
+
+/// Converts a [`FileOperationError`] into an `OSError` (I/O failures) or [`TransformError`].
+pub(crate) fn file_operation_error(error: FileOperationError) -> PyErr {
+    match error {
+        FileOperationError::Io(_) => PyOSError::new_err(error.to_string()),
+        _ => transform_error(error),
+    }
+}
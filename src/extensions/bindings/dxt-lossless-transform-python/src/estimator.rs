@@ -0,0 +1,101 @@
+//! Size estimators, used by automatic transforms to pick the best settings.
+
+use dxt_lossless_transform_file_formats_api::bundle::PresetEstimator;
+use dxt_lossless_transform_ltu::LosslessTransformUtilsSizeEstimation;
+use dxt_lossless_transform_zstd::ZStandardSizeEstimation;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Size estimator used by automatic transforms to pick the settings that compress best.
+///
+/// Create with `Estimator.ltu()` or `Estimator.zstd(level)`.
+#[pyclass(frozen, eq, module = "dxt_lossless_transform")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Estimator(EstimatorKind);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EstimatorKind {
+    /// Lossless-transform-utils estimator.
+    Ltu,
+    /// ZStandard estimator with the given compression level.
+    ZStandard(i32),
+}
+
+#[pymethods]
+impl Estimator {
+    /// Fast estimator based on LZ match analysis.
+    ///
+    /// Recommended when compressing with ZStandard levels below 4 (or similar).
+    #[staticmethod]
+    fn ltu() -> Self {
+        Self(EstimatorKind::Ltu)
+    }
+
+    /// Estimator that compresses the data with ZStandard at `level` (1-22).
+    ///
+    /// Slower than `ltu`, but more accurate; use the level you compress with.
+    /// Raises `ValueError` if the level is out of range.
+    #[staticmethod]
+    #[pyo3(signature = (level = 1))]
+    fn zstd(level: i32) -> PyResult<Self> {
+        ZStandardSizeEstimation::new(level).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(Self(EstimatorKind::ZStandard(level)))
+    }
+
+    fn __repr__(&self) -> String {
+        match self.0 {
+            EstimatorKind::Ltu => "Estimator.ltu()".into(),
+            EstimatorKind::ZStandard(level) => format!("Estimator.zstd({level})"),
+        }
+    }
+}
+
+impl Default for Estimator {
+    /// ZStandard level 1, as used by the `optimal` preset.
+    fn default() -> Self {
+        Self(EstimatorKind::ZStandard(1))
+    }
+}
+
+impl Estimator {
+    /// Creates the Rust size estimator.
+    pub(crate) fn create(self) -> PresetEstimator {
+        match self.0 {
+            EstimatorKind::Ltu => PresetEstimator::Ltu(LosslessTransformUtilsSizeEstimation::new()),
+            EstimatorKind::ZStandard(level) => PresetEstimator::ZStandard(
+                ZStandardSizeEstimation::new(level).expect("level is validated by Estimator.zstd"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_prelude::*;
+
+    #[test]
+    fn estimators_are_created_and_compared() {
+        run_python(
+            c"
+assert dlt.Estimator.ltu() == dlt.Estimator.ltu()
+assert dlt.Estimator.zstd(3) == dlt.Estimator.zstd(level=3)
+assert dlt.Estimator.zstd() != dlt.Estimator.zstd(3)
+assert repr(dlt.Estimator.zstd(9)) == 'Estimator.zstd(9)'
+",
+        );
+    }
+
+    #[test]
+    fn zstd_rejects_invalid_levels() {
+        run_python(
+            c"
+for level in (0, 23):
+    try:
+        dlt.Estimator.zstd(level)
+        raise AssertionError('expected ValueError')
+    except ValueError as e:
+        assert str(level) in str(e)
+",
+        );
+    }
+}
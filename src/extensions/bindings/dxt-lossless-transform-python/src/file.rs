@@ -0,0 +1,378 @@
+//! File-level transforms, with format detection and the transform settings stored in the file.
+//!
+//! Supported are DDS files, and raw BC1/BC2 block data (extension `bc1`/`bc2`), which is
+//! wrapped in a small container when transformed.
+
+use crate::buffer;
+use crate::bundle::PyTransformBundle;
+use crate::error::{file_operation_error, transform_error};
+use crate::settings::details_to_py;
+use dxt_lossless_transform_dds::DdsHandler;
+use dxt_lossless_transform_file_formats_api::bundle::{PresetEstimator, TransformPreset};
+use dxt_lossless_transform_file_formats_api::embed::{TransformDetails, TransformFormat};
+use dxt_lossless_transform_file_formats_api::file_io::{
+    transform_file_with_registry, untransform_file_with_registry,
+};
+use dxt_lossless_transform_file_formats_api::{
+    FileFormatInspection, HandlerRegistry, RawBlocksHandler, TextureInfo, TransformBundle,
+    TransformError, TransformResult,
+};
+use pyo3::buffer::PyBuffer;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+/// Handlers for every supported file format.
+// Raw block containers are checked first; they start with a signature, whereas transformed
+// DDS files are detected from the rest of their header, which raw data may resemble.
+static REGISTRY: LazyLock<HandlerRegistry<PresetEstimator>> = LazyLock::new(|| {
+    HandlerRegistry::new()
+        .with_handler(RawBlocksHandler::new(TransformFormat::Bc1), &["bc1"])
+        .with_handler(RawBlocksHandler::new(TransformFormat::Bc2), &["bc2"])
+        .with_handler(DdsHandler, &["dds"])
+});
+
+/// Transform a file held in memory (`bytes`, `bytearray`, `memoryview`, ...), returning `bytes`.
+///
+/// `extension` (e.g. `"dds"`) speeds up format detection, and is required for raw block
+/// data (`"bc1"`, `"bc2"`). Uses the `optimal` preset if no `bundle` is given.
+/// Raises `TransformError` if the file is not supported or can't be transformed.
+#[pyfunction]
+#[pyo3(signature = (data, bundle = None, extension = None))]
+pub fn transform(
+    py: Python<'_>,
+    data: PyBuffer<u8>,
+    bundle: Option<PyRef<'_, PyTransformBundle>>,
+    extension: Option<&str>,
+) -> PyResult<Py<PyBytes>> {
+    let input = buffer::as_slice(&data)?;
+    let extension = extension.map(str::to_ascii_lowercase);
+    let default_bundle;
+    let bundle = match &bundle {
+        Some(bundle) => &bundle.bundle,
+        None => {
+            default_bundle = TransformPreset::default().bundle();
+            &default_bundle
+        }
+    };
+
+    let output = py
+        .detach(|| transform_to_vec(input, extension.as_deref(), bundle))
+        .map_err(transform_error)?;
+    Ok(PyBytes::new(py, &output).unbind())
+}
+
+/// Restore a file transformed by `transform`, returning the original as `bytes`.
+///
+/// Raises `TransformError` if the data is not a transformed file, or is corrupted.
+#[pyfunction]
+#[pyo3(signature = (data, extension = None))]
+pub fn untransform(
+    py: Python<'_>,
+    data: PyBuffer<u8>,
+    extension: Option<&str>,
+) -> PyResult<Py<PyBytes>> {
+    let input = buffer::as_slice(&data)?;
+    let extension = extension.map(str::to_ascii_lowercase);
+
+    let output = py
+        .detach(|| untransform_to_vec(input, extension.as_deref()))
+        .map_err(transform_error)?;
+    Ok(PyBytes::new(py, &output).unbind())
+}
+
+/// Transform the file at `input_path`, writing the result to `output_path`.
+///
+/// The format is detected from the file extension and contents. Uses the `optimal` preset
+/// if no `bundle` is given. Raises `OSError` if a file can't be read or written.
+#[pyfunction]
+#[pyo3(signature = (input_path, output_path, bundle = None))]
+pub fn transform_file(
+    py: Python<'_>,
+    input_path: PathBuf,
+    output_path: PathBuf,
+    bundle: Option<PyRef<'_, PyTransformBundle>>,
+) -> PyResult<()> {
+    let default_bundle;
+    let bundle = match &bundle {
+        Some(bundle) => &bundle.bundle,
+        None => {
+            default_bundle = TransformPreset::default().bundle();
+            &default_bundle
+        }
+    };
+
+    py.detach(|| transform_file_with_registry(&REGISTRY, &input_path, &output_path, bundle))
+        .map(|_| ())
+        .map_err(file_operation_error)
+}
+
+/// Restore the transformed file at `input_path`, writing the original to `output_path`.
+///
+/// Raises `OSError` if a file can't be read or written.
+#[pyfunction]
+pub fn untransform_file(py: Python<'_>, input_path: PathBuf, output_path: PathBuf) -> PyResult<()> {
+    py.detach(|| untransform_file_with_registry(&REGISTRY, &input_path, &output_path))
+        .map(|_| ())
+        .map_err(file_operation_error)
+}
+
+/// Read the texture metadata of a DDS file, which may or may not be transformed.
+///
+/// Only the file headers need to be present in `data`.
+/// Raises `TransformError` if the data is not a DDS file.
+#[pyfunction]
+pub fn inspect(data: PyBuffer<u8>) -> PyResult<PyTextureInfo> {
+    let input = buffer::as_slice(&data)?;
+    DdsHandler
+        .inspect(input)
+        .map(PyTextureInfo)
+        .map_err(transform_error)
+}
+
+/// Texture metadata of a file, as returned by `inspect`.
+#[pyclass(name = "TextureInfo", frozen, module = "dxt_lossless_transform")]
+#[derive(Debug, Clone, Copy)]
+pub struct PyTextureInfo(TextureInfo);
+
+#[pymethods]
+impl PyTextureInfo {
+    /// Format of the texture data (e.g. `"bc1"`), or `None` if it can't be transformed.
+    #[getter]
+    fn format(&self) -> Option<&'static str> {
+        self.0.format.map(format_name)
+    }
+
+    /// Width of the top level mipmap in pixels.
+    #[getter]
+    fn width(&self) -> u32 {
+        self.0.width
+    }
+
+    /// Height of the top level mipmap in pixels.
+    #[getter]
+    fn height(&self) -> u32 {
+        self.0.height
+    }
+
+    /// Depth of the top level mipmap in pixels (1 unless this is a volume texture).
+    #[getter]
+    fn depth(&self) -> u32 {
+        self.0.depth
+    }
+
+    /// Number of mipmap levels, including the top level.
+    #[getter]
+    fn mip_count(&self) -> u32 {
+        self.0.mip_count
+    }
+
+    /// Number of array elements (1 unless this is a texture array).
+    #[getter]
+    fn array_size(&self) -> u32 {
+        self.0.array_size
+    }
+
+    /// Number of faces per array element (6 for complete cube maps, 1 otherwise).
+    #[getter]
+    fn face_count(&self) -> u32 {
+        self.0.face_count
+    }
+
+    /// Offset of the texture data from the start of the file.
+    #[getter]
+    fn data_offset(&self) -> usize {
+        self.0.data_offset
+    }
+
+    /// Length of the texture data that is subject to transformation.
+    #[getter]
+    fn data_length(&self) -> usize {
+        self.0.data_length
+    }
+
+    /// Whether the file has already been transformed.
+    #[getter]
+    fn is_transformed(&self) -> bool {
+        self.0.is_transformed()
+    }
+
+    /// The settings a transformed file was transformed with (`Bc1Settings` or `Bc2Settings`).
+    ///
+    /// `None` if the file is not transformed, its data was stored unchanged, or the settings
+    /// are not known to this version of the library.
+    #[getter]
+    fn transform_settings(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        details_to_py(
+            py,
+            self.0.transform_details.unwrap_or(TransformDetails::None),
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TextureInfo(format={}, width={}, height={}, mip_count={}, is_transformed={})",
+            self.format()
+                .map_or_else(|| "None".into(), |x| format!("'{x}'")),
+            self.0.width,
+            self.0.height,
+            self.0.mip_count,
+            if self.0.is_transformed() {
+                "True"
+            } else {
+                "False"
+            }
+        )
+    }
+}
+
+fn transform_to_vec(
+    input: &[u8],
+    extension: Option<&str>,
+    bundle: &TransformBundle<PresetEstimator>,
+) -> TransformResult<Vec<u8>> {
+    let handler = REGISTRY
+        .find_transform_handler(input, extension)
+        .ok_or(TransformError::NoSupportedHandler)?;
+    let mut output = vec![0u8; handler.dyn_transformed_size(input)];
+    REGISTRY.transform_slice(input, &mut output, extension, bundle)?;
+    Ok(output)
+}
+
+fn untransform_to_vec(input: &[u8], extension: Option<&str>) -> TransformResult<Vec<u8>> {
+    let handler = REGISTRY
+        .find_untransform_handler(input, extension)
+        .ok_or(TransformError::NoSupportedHandler)?;
+    let mut output = vec![0u8; handler.dyn_untransformed_size(input)];
+    REGISTRY.untransform_slice(input, &mut output, extension)?;
+    Ok(output)
+}
+
+fn format_name(format: TransformFormat) -> &'static str {
+    match format {
+        TransformFormat::Bc1 => "bc1",
+        TransformFormat::Bc2 => "bc2",
+        TransformFormat::Bc3 => "bc3",
+        TransformFormat::Bc4 => "bc4",
+        TransformFormat::Bc5 => "bc5",
+        TransformFormat::Bc6H => "bc6h",
+        TransformFormat::Bc7 => "bc7",
+        TransformFormat::Rgba8888 => "rgba8888",
+        TransformFormat::Bgra8888 => "bgra8888",
+        TransformFormat::Bgr888 => "bgr888",
+        TransformFormat::None => "none",
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_prelude::*;
+
+    #[test]
+    fn dds_roundtrips_with_every_preset() {
+        run_python(
+            c"
+original = open(f'{ASSETS}/r2-256-bc1.dds', 'rb').read()
+for preset in ('low', 'medium', 'optimal', 'max'):
+    transformed = dlt.transform(original, dlt.TransformBundle(preset), extension='dds')
+    assert isinstance(transformed, bytes)
+    assert transformed != original
+    assert dlt.untransform(transformed) == original
+",
+        );
+    }
+
+    #[test]
+    fn dds_roundtrips_from_bytearray_with_default_bundle() {
+        run_python(
+            c"
+original = bytearray(open(f'{ASSETS}/r2-256-bc2.dds', 'rb').read())
+transformed = dlt.transform(memoryview(original))
+assert dlt.untransform(bytearray(transformed), 'DDS') == original
+",
+        );
+    }
+
+    #[test]
+    fn raw_blocks_roundtrip_with_manual_settings() {
+        run_python(
+            c"
+blocks = bytes(range(256)) * 8
+bundle = dlt.TransformBundle().with_bc1_manual(dlt.Bc1Settings('variant2', False))
+transformed = dlt.transform(blocks, bundle, 'bc1')
+assert len(transformed) > len(blocks)
+assert dlt.untransform(transformed, 'bc1') == blocks
+assert dlt.untransform(transformed) == blocks
+",
+        );
+    }
+
+    #[test]
+    fn unsupported_data_raises_transform_error() {
+        run_python(
+            c"
+for call in (lambda: dlt.transform(b'not a texture'), lambda: dlt.untransform(b'not a texture')):
+    try:
+        call()
+        raise AssertionError('expected TransformError')
+    except dlt.TransformError:
+        pass
+
+# Formats without settings are rejected
+try:
+    dlt.transform(bytes(256), dlt.TransformBundle(), 'bc1')
+    raise AssertionError('expected TransformError')
+except dlt.TransformError:
+    pass
+",
+        );
+    }
+
+    #[test]
+    fn files_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        run_python_with(
+            c"
+import os, shutil
+original = os.path.join(TEMP, 'texture.dds')
+transformed = os.path.join(TEMP, 'transformed.dds')
+restored = os.path.join(TEMP, 'restored.dds')
+shutil.copy(f'{ASSETS}/r2-256-bc1.dds', original)
+
+dlt.transform_file(original, transformed, dlt.TransformBundle('medium'))
+dlt.untransform_file(transformed, restored)
+assert open(restored, 'rb').read() == open(original, 'rb').read()
+
+try:
+    dlt.untransform_file(os.path.join(TEMP, 'missing.dds'), restored)
+    raise AssertionError('expected OSError')
+except OSError:
+    pass
+",
+            &[("TEMP", dir.path().to_str().unwrap())],
+        );
+    }
+
+    #[test]
+    fn inspect_reports_texture_info_and_settings() {
+        run_python(
+            c"
+original = open(f'{ASSETS}/r2-256-bc1.dds', 'rb').read()
+info = dlt.inspect(original)
+assert info.format == 'bc1'
+assert (info.width, info.height, info.depth) == (256, 256, 1)
+assert info.data_offset + info.data_length <= len(original)
+assert not info.is_transformed
+assert info.transform_settings is None
+
+settings = dlt.Bc1Settings('variant3', False)
+transformed = dlt.transform(original, dlt.TransformBundle().with_bc1_manual(settings))
+info = dlt.inspect(transformed)
+assert info.is_transformed
+assert info.transform_settings == settings
+",
+        );
+    }
+}
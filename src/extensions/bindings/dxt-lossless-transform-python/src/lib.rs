@@ -0,0 +1,42 @@
+#![doc = include_str!(concat!("../", core::env!("CARGO_PKG_README")))]
+#![warn(missing_docs)]
+
+pub mod bundle;
+pub mod error;
+pub mod estimator;
+pub mod file;
+pub mod raw;
+pub mod settings;
+
+mod buffer;
+
+#[cfg(test)]
+pub mod test_prelude;
+
+use pyo3::prelude::*;
+
+/// Transforms texture files and raw BCx blocks for better compression ratios.
+#[pymodule]
+fn dxt_lossless_transform(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("TransformError", m.py().get_type::<error::TransformError>())?;
+
+    m.add_class::<estimator::Estimator>()?;
+    m.add_class::<settings::Bc1Settings>()?;
+    m.add_class::<settings::Bc2Settings>()?;
+    m.add_class::<bundle::PyTransformBundle>()?;
+    m.add_class::<file::PyTextureInfo>()?;
+
+    m.add_function(wrap_pyfunction!(file::transform, m)?)?;
+    m.add_function(wrap_pyfunction!(file::untransform, m)?)?;
+    m.add_function(wrap_pyfunction!(file::transform_file, m)?)?;
+    m.add_function(wrap_pyfunction!(file::untransform_file, m)?)?;
+    m.add_function(wrap_pyfunction!(file::inspect, m)?)?;
+
+    m.add_function(wrap_pyfunction!(raw::bc1_transform, m)?)?;
+    m.add_function(wrap_pyfunction!(raw::bc1_transform_auto, m)?)?;
+    m.add_function(wrap_pyfunction!(raw::bc1_untransform, m)?)?;
+    m.add_function(wrap_pyfunction!(raw::bc2_transform, m)?)?;
+    m.add_function(wrap_pyfunction!(raw::bc2_transform_auto, m)?)?;
+    m.add_function(wrap_pyfunction!(raw::bc2_untransform, m)?)?;
+    Ok(())
+}
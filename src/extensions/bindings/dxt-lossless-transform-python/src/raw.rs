@@ -0,0 +1,208 @@
+//! Transforms for raw BC1/BC2 block data, without a file header.
+//!
+//! These read from and write to any object supporting the buffer protocol (`bytes`,
+//! `bytearray`, `memoryview`, numpy arrays, ...) without copying. The output buffer must be
+//! writable and at least as long as the input. The GIL is released while transforming, so
+//! the buffers must not be modified by other threads until the call returns.
+//!
+//! Unlike [`file`](crate::file) transforms, the settings are not stored alongside the data;
+//! keep the settings to untransform it later.
+
+use crate::buffer;
+use crate::error::transform_error;
+use crate::estimator::Estimator;
+use crate::settings::{Bc1Settings, Bc2Settings};
+use dxt_lossless_transform_bc1_api::{Bc1AutoTransformBuilder, Bc1ManualTransformBuilder};
+use dxt_lossless_transform_bc2_api::{Bc2AutoTransformBuilder, Bc2ManualTransformBuilder};
+use pyo3::buffer::PyBuffer;
+use pyo3::prelude::*;
+
+/// Transform BC1 blocks from `input` into `output` with fixed `settings` (default settings if unset).
+#[pyfunction]
+#[pyo3(signature = (input, output, settings = None))]
+pub fn bc1_transform(
+    py: Python<'_>,
+    input: PyBuffer<u8>,
+    mut output: PyBuffer<u8>,
+    settings: Option<Bc1Settings>,
+) -> PyResult<()> {
+    let builder = settings.map_or_else(Bc1ManualTransformBuilder::new, |x| x.0);
+    let input = buffer::as_slice(&input)?;
+    let output = buffer::as_mut_slice(&mut output, input)?;
+
+    py.detach(|| builder.transform(input, output))
+        .map_err(transform_error)
+}
+
+/// Transform BC1 blocks from `input` into `output` with the settings that compress best
+/// according to `estimator` (ZStandard level 1 if unset).
+///
+/// Returns the `Bc1Settings` used, which are needed to untransform the data.
+#[pyfunction]
+#[pyo3(signature = (input, output, estimator = Estimator::default(), ultra = false))]
+pub fn bc1_transform_auto(
+    py: Python<'_>,
+    input: PyBuffer<u8>,
+    mut output: PyBuffer<u8>,
+    estimator: Estimator,
+    ultra: bool,
+) -> PyResult<Bc1Settings> {
+    let input = buffer::as_slice(&input)?;
+    let output = buffer::as_mut_slice(&mut output, input)?;
+
+    py.detach(|| {
+        Bc1AutoTransformBuilder::new(estimator.create())
+            .use_all_decorrelation_modes(ultra)
+            .transform(input, output)
+            .map_err(|e| e.to_string())
+    })
+    .map(Bc1Settings)
+    .map_err(transform_error)
+}
+
+/// Restore BC1 blocks transformed with `settings` from `input` into `output`.
+#[pyfunction]
+pub fn bc1_untransform(
+    py: Python<'_>,
+    input: PyBuffer<u8>,
+    mut output: PyBuffer<u8>,
+    settings: Bc1Settings,
+) -> PyResult<()> {
+    let input = buffer::as_slice(&input)?;
+    let output = buffer::as_mut_slice(&mut output, input)?;
+
+    py.detach(|| settings.0.untransform(input, output))
+        .map_err(transform_error)
+}
+
+/// Transform BC2 blocks from `input` into `output` with fixed `settings` (default settings if unset).
+#[pyfunction]
+#[pyo3(signature = (input, output, settings = None))]
+pub fn bc2_transform(
+    py: Python<'_>,
+    input: PyBuffer<u8>,
+    mut output: PyBuffer<u8>,
+    settings: Option<Bc2Settings>,
+) -> PyResult<()> {
+    let builder = settings.map_or_else(Bc2ManualTransformBuilder::new, |x| x.0);
+    let input = buffer::as_slice(&input)?;
+    let output = buffer::as_mut_slice(&mut output, input)?;
+
+    py.detach(|| builder.transform(input, output))
+        .map_err(transform_error)
+}
+
+/// Transform BC2 blocks from `input` into `output` with the settings that compress best
+/// according to `estimator` (ZStandard level 1 if unset).
+///
+/// Returns the `Bc2Settings` used, which are needed to untransform the data.
+#[pyfunction]
+#[pyo3(signature = (input, output, estimator = Estimator::default(), ultra = false))]
+pub fn bc2_transform_auto(
+    py: Python<'_>,
+    input: PyBuffer<u8>,
+    mut output: PyBuffer<u8>,
+    estimator: Estimator,
+    ultra: bool,
+) -> PyResult<Bc2Settings> {
+    let input = buffer::as_slice(&input)?;
+    let output = buffer::as_mut_slice(&mut output, input)?;
+
+    py.detach(|| {
+        Bc2AutoTransformBuilder::new(estimator.create())
+            .use_all_decorrelation_modes(ultra)
+            .transform(input, output)
+            .map_err(|e| e.to_string())
+    })
+    .map(Bc2Settings)
+    .map_err(transform_error)
+}
+
+/// Restore BC2 blocks transformed with `settings` from `input` into `output`.
+#[pyfunction]
+pub fn bc2_untransform(
+    py: Python<'_>,
+    input: PyBuffer<u8>,
+    mut output: PyBuffer<u8>,
+    settings: Bc2Settings,
+) -> PyResult<()> {
+    let input = buffer::as_slice(&input)?;
+    let output = buffer::as_mut_slice(&mut output, input)?;
+
+    py.detach(|| settings.0.untransform(input, output))
+        .map_err(transform_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_prelude::*;
+
+    #[test]
+    fn blocks_roundtrip_in_place_of_python_buffers() {
+        run_python(
+            c"
+blocks = bytes(range(256)) * 8
+for transform, untransform, settings in (
+    (dlt.bc1_transform, dlt.bc1_untransform, dlt.Bc1Settings('variant1', False)),
+    (dlt.bc2_transform, dlt.bc2_untransform, dlt.Bc2Settings('none', True)),
+):
+    transformed = bytearray(len(blocks))
+    transform(blocks, transformed, settings)
+    assert transformed != blocks
+
+    restored = bytearray(len(blocks))
+    untransform(memoryview(transformed), restored, settings)
+    assert restored == blocks
+",
+        );
+    }
+
+    #[test]
+    fn auto_transform_returns_settings_to_untransform_with() {
+        run_python(
+            c"
+blocks = bytes(range(256)) * 8
+for transform, untransform, estimator in (
+    (dlt.bc1_transform_auto, dlt.bc1_untransform, dlt.Estimator.ltu()),
+    (dlt.bc2_transform_auto, dlt.bc2_untransform, dlt.Estimator.zstd(3)),
+):
+    transformed = bytearray(len(blocks))
+    settings = transform(blocks, transformed, estimator, ultra=True)
+
+    restored = bytearray(len(blocks))
+    untransform(transformed, restored, settings)
+    assert restored == blocks
+",
+        );
+    }
+
+    #[test]
+    fn invalid_buffers_are_rejected() {
+        run_python(
+            c"
+blocks = bytearray(64)
+
+# Read-only output
+try:
+    dlt.bc1_transform(blocks, bytes(64))
+    raise AssertionError('expected BufferError')
+except BufferError:
+    pass
+
+# Overlapping input and output
+try:
+    dlt.bc1_transform(blocks, blocks)
+    raise AssertionError('expected ValueError')
+except ValueError:
+    pass
+
+# Invalid length
+try:
+    dlt.bc1_transform(bytes(7), bytearray(7))
+    raise AssertionError('expected TransformError')
+except dlt.TransformError:
+    pass
+",
+        );
+    }
+}
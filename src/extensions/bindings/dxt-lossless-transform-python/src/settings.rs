@@ -0,0 +1,169 @@
+//! Manual transform settings for each supported format.
+
+use dxt_lossless_transform_api_common::reexports::color_565::YCoCgVariant;
+use dxt_lossless_transform_bc1_api::Bc1ManualTransformBuilder;
+use dxt_lossless_transform_bc2_api::Bc2ManualTransformBuilder;
+use dxt_lossless_transform_file_formats_api::embed::TransformDetails;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Defines a Python class wrapping a manual transform builder with
+/// `decorrelation_mode` and `split_colour_endpoints` settings.
+macro_rules! manual_settings {
+    ($name:ident, $builder:ident, $format:literal) => {
+        #[doc = concat!("Manual ", $format, " transform settings.")]
+        ///
+        /// Unset arguments use the library defaults. `decorrelation_mode` is one of
+        /// `"none"`, `"variant1"`, `"variant2"` or `"variant3"`.
+        #[pyclass(frozen, module = "dxt_lossless_transform")]
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name(pub(crate) $builder);
+
+        #[pymethods]
+        impl $name {
+            #[new]
+            #[pyo3(signature = (decorrelation_mode = None, split_colour_endpoints = None))]
+            fn new(
+                decorrelation_mode: Option<&str>,
+                split_colour_endpoints: Option<bool>,
+            ) -> PyResult<Self> {
+                let mut builder = $builder::new();
+                if let Some(mode) = decorrelation_mode {
+                    builder = builder.decorrelation_mode(parse_decorrelation_mode(mode)?);
+                }
+                if let Some(split) = split_colour_endpoints {
+                    builder = builder.split_colour_endpoints(split);
+                }
+                Ok(Self(builder))
+            }
+
+            /// The YCoCg-R decorrelation variant, e.g. `"variant1"`.
+            #[getter]
+            fn decorrelation_mode(&self) -> &'static str {
+                decorrelation_mode_name(YCoCgVariant::from_internal_variant(
+                    self.0.get_settings().decorrelation_mode,
+                ))
+            }
+
+            /// Whether the colour endpoints are split.
+            #[getter]
+            fn split_colour_endpoints(&self) -> bool {
+                self.0.get_settings().split_colour_endpoints
+            }
+
+            fn __eq__(&self, other: &Self) -> bool {
+                self.0.get_settings() == other.0.get_settings()
+            }
+
+            fn __repr__(&self) -> String {
+                format!(
+                    "{}(decorrelation_mode='{}', split_colour_endpoints={})",
+                    stringify!($name),
+                    self.decorrelation_mode(),
+                    if self.split_colour_endpoints() {
+                        "True"
+                    } else {
+                        "False"
+                    }
+                )
+            }
+        }
+    };
+}
+
+manual_settings!(Bc1Settings, Bc1ManualTransformBuilder, "BC1");
+manual_settings!(Bc2Settings, Bc2ManualTransformBuilder, "BC2");
+
+/// Converts the settings stored in a transformed file into a `Bc1Settings` or `Bc2Settings`.
+///
+/// Returns `None` if the data was stored unchanged, or the settings are unknown.
+pub(crate) fn details_to_py(py: Python<'_>, details: TransformDetails) -> PyResult<Py<PyAny>> {
+    Ok(match details {
+        TransformDetails::Bc1(settings) => Bc1Settings(
+            Bc1ManualTransformBuilder::new()
+                .decorrelation_mode(YCoCgVariant::from_internal_variant(
+                    settings.decorrelation_mode,
+                ))
+                .split_colour_endpoints(settings.split_colour_endpoints),
+        )
+        .into_pyobject(py)?
+        .into_any()
+        .unbind(),
+        TransformDetails::Bc2(settings) => Bc2Settings(
+            Bc2ManualTransformBuilder::new()
+                .decorrelation_mode(YCoCgVariant::from_internal_variant(
+                    settings.decorrelation_mode,
+                ))
+                .split_colour_endpoints(settings.split_colour_endpoints),
+        )
+        .into_pyobject(py)?
+        .into_any()
+        .unbind(),
+        _ => py.None(),
+    })
+}
+
+fn parse_decorrelation_mode(mode: &str) -> PyResult<YCoCgVariant> {
+    YCoCgVariant::all_variants()
+        .iter()
+        .copied()
+        .find(|variant| decorrelation_mode_name(*variant).eq_ignore_ascii_case(mode))
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Unknown decorrelation mode: {mode}. Valid options: none, variant1, variant2, variant3"
+            ))
+        })
+}
+
+fn decorrelation_mode_name(mode: YCoCgVariant) -> &'static str {
+    match mode {
+        YCoCgVariant::None => "none",
+        YCoCgVariant::Variant1 => "variant1",
+        YCoCgVariant::Variant2 => "variant2",
+        YCoCgVariant::Variant3 => "variant3",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_prelude::*;
+
+    #[test]
+    fn settings_use_library_defaults() {
+        run_python(
+            c"
+for cls in (dlt.Bc1Settings, dlt.Bc2Settings):
+    settings = cls()
+    assert settings.decorrelation_mode == 'variant1'
+    assert settings.split_colour_endpoints
+",
+        );
+    }
+
+    #[test]
+    fn settings_store_arguments() {
+        run_python(
+            c"
+settings = dlt.Bc1Settings(decorrelation_mode='Variant3', split_colour_endpoints=False)
+assert settings.decorrelation_mode == 'variant3'
+assert not settings.split_colour_endpoints
+assert settings == dlt.Bc1Settings('variant3', False)
+assert settings != dlt.Bc1Settings()
+assert repr(settings) == \"Bc1Settings(decorrelation_mode='variant3', split_colour_endpoints=False)\"
+",
+        );
+    }
+
+    #[test]
+    fn settings_reject_unknown_decorrelation_mode() {
+        run_python(
+            c"
+try:
+    dlt.Bc2Settings(decorrelation_mode='variant4')
+    raise AssertionError('expected ValueError')
+except ValueError as e:
+    assert 'variant4' in str(e)
+",
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! Common test imports and utilities for the Python bindings tests
+//!
+//! Tests run Python code against the module using the local Python interpreter,
+//! embedded via pyo3's `auto-initialize` feature.
+#![allow(unused_imports)]
+
+pub use pyo3::prelude::*;
+pub use pyo3::types::{PyDict, PyModule};
+use std::ffi::CStr;
+
+/// Runs Python code with the module available as `dlt`, and the path of the
+/// test assets directory as `ASSETS`.
+///
+/// Panics (printing the traceback) if the code raises an exception.
+pub fn run_python(code: &CStr) {
+    run_python_with(code, &[]);
+}
+
+/// [`run_python`], with additional string variables available to the code.
+pub fn run_python_with(code: &CStr, variables: &[(&str, &str)]) {
+    Python::attach(|py| {
+        let module = PyModule::new(py, "dxt_lossless_transform").unwrap();
+        crate::dxt_lossless_transform(&module).unwrap();
+
+        let globals = PyDict::new(py);
+        globals.set_item("dlt", module).unwrap();
+        globals
+            .set_item(
+                "ASSETS",
+                concat!(env!("CARGO_MANIFEST_DIR"), "/../../../assets/tests"),
+            )
+            .unwrap();
+        for (name, value) in variables {
+            globals.set_item(name, value).unwrap();
+        }
+
+        if let Err(e) = py.run(code, Some(&globals), None) {
+            e.print(py);
+            panic!("Python code raised an exception: {e}");
+        }
+    });
+}
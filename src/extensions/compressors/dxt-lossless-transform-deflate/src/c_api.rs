@@ -0,0 +1,337 @@
+//! C API for Deflate Size Estimation
+//!
+//! This module provides a C-compatible interface for the deflate size estimation
+//! functionality. It exposes the core [`DeflateSizeEstimation`] type through the [`DltSizeEstimator`]
+//! interface from `dxt-lossless-transform-api-common`.
+//!
+//! ## Usage Pattern
+//!
+//! 1. Create an estimator instance using [`dltdeflate_new_size_estimator`]
+//! 2. Use the estimator with any API that accepts a [`DltSizeEstimator`]
+//! 3. Free the estimator when done using [`dltdeflate_free_size_estimator`]
+//!
+//! ## Important Notes
+//!
+//! This estimator performs actual compression, so its estimates match the real compressed size.
+//! Data is compressed as a raw deflate stream; use the level you compress your files with.
+//!
+//! ## Thread Safety
+//!
+//! The deflate estimator is thread-safe and can be used from multiple threads simultaneously.
+//! The estimator has no internal state, making it safe for concurrent use.
+//!
+//! # Required Headers
+//!
+//! When using this API from C/C++, you must include the common API header first:
+//! ```c
+//! #include "dxt-lossless-transform-api-common.h"
+//! #include "dxt-lossless-transform-deflate.h"
+//! ```
+//!
+//! # Usage with Transform APIs (BC1, BC2, BC3, BC7, etc.)
+//!
+//! The deflate estimator implements the [`DltSizeEstimator`] interface and can be used
+//! directly with BCX automatic transform builders such as
+//! [`dltbc1_new_AutoTransformBuilder`].
+//!
+//! ```c
+//! DltSizeEstimator* estimator = NULL;
+//! DltdeflateResult result = dltdeflate_new_size_estimator(6, &estimator);
+//! if (result.error_code != DLTDEFLATE_SUCCESS) {
+//!     printf("Failed to create estimator: %s\n", dltdeflate_error_message(result.error_code));
+//!     return;
+//! }
+//!
+//! Dltbc1AutoTransformBuilder* builder = dltbc1_new_AutoTransformBuilder(estimator);
+//! // Transform with the builder...
+//!
+//! dltbc1_free_AutoTransformBuilder(builder);
+//! dltdeflate_free_size_estimator(estimator);
+//! ```
+//!
+//! # Available Functions
+//!
+//! - [`dltdeflate_new_size_estimator`] - Create a new estimator
+//! - [`dltdeflate_free_size_estimator`] - Free an estimator
+//! - [`dltdeflate_error_message`] - Describe an error code
+//!
+//! [`dltbc1_new_AutoTransformBuilder`]: https://docs.rs/dxt-lossless-transform-bc1-api/latest/dxt_lossless_transform_bc1_api/c_api/transform/auto_transform_builder/fn.dltbc1_new_AutoTransformBuilder.html
+
+use crate::DeflateSizeEstimation;
+use alloc::boxed::Box;
+use core::ffi::{c_char, c_void};
+use dxt_lossless_transform_api_common::c_api::size_estimation::DltSizeEstimator;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+
+/// C-compatible error codes for deflate estimator operations.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DltdeflateErrorCode {
+    /// Operation succeeded
+    Success = 0,
+    /// Null pointer provided for estimator output parameter
+    NullOutputPointer = 1,
+    /// Invalid compression level: Level must be between 1 and 10
+    InvalidLevel = 2,
+}
+
+/// C-compatible Result type for deflate estimator operations.
+#[repr(C)]
+pub struct DltdeflateResult {
+    /// Error code (0 = success, non-zero = error)
+    pub error_code: DltdeflateErrorCode,
+}
+
+impl DltdeflateResult {
+    /// Create a success result
+    pub const fn success() -> Self {
+        Self {
+            error_code: DltdeflateErrorCode::Success,
+        }
+    }
+
+    /// Create an error result from an error code
+    pub const fn from_error_code(error_code: DltdeflateErrorCode) -> Self {
+        Self { error_code }
+    }
+
+    /// Check if the result is successful
+    pub fn is_success(&self) -> bool {
+        matches!(self.error_code, DltdeflateErrorCode::Success)
+    }
+}
+
+/// Create a new deflate size estimator.
+///
+/// The estimator compresses data at the given level to determine its compressed size.
+///
+/// # Parameters
+/// * `level` - Deflate compression level (1-10). Use the level you compress your files with.
+/// * `out_estimator` - Output pointer where the created estimator will be written.
+///   On success, this will be set to a pointer that must be freed with
+///   [`dltdeflate_free_size_estimator`]. On error, this will be set to null.
+///
+/// # Returns
+///
+/// A [`DltdeflateResult`] indicating success, or [`DltdeflateErrorCode::InvalidLevel`] if
+/// `level` is out of range.
+///
+/// # Safety
+///
+/// `out_estimator` must be a valid pointer to write the result.
+///
+/// # Remarks
+/// This function corresponds to [`DeflateSizeEstimation::new`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltdeflate_new_size_estimator(
+    level: i32,
+    out_estimator: *mut *mut DltSizeEstimator,
+) -> DltdeflateResult {
+    if out_estimator.is_null() {
+        return DltdeflateResult::from_error_code(DltdeflateErrorCode::NullOutputPointer);
+    }
+
+    match DeflateSizeEstimation::new(level) {
+        Ok(deflate) => {
+            let estimator = create_c_size_estimator(Box::new(deflate));
+            unsafe { *out_estimator = Box::into_raw(Box::new(estimator)) };
+            DltdeflateResult::success()
+        }
+        // Level validation is the only way creating the estimator can fail
+        Err(_) => {
+            unsafe { *out_estimator = core::ptr::null_mut() };
+            DltdeflateResult::from_error_code(DltdeflateErrorCode::InvalidLevel)
+        }
+    }
+}
+
+/// Free a deflate size estimator created by [`dltdeflate_new_size_estimator`].
+///
+/// # Parameters
+/// * `estimator` - Pointer to the estimator to free (can be null)
+///
+/// # Safety
+/// The estimator pointer must have been returned by [`dltdeflate_new_size_estimator`],
+/// or be null. After calling this function, the pointer becomes invalid.
+#[no_mangle]
+pub unsafe extern "C" fn dltdeflate_free_size_estimator(estimator: *mut DltSizeEstimator) {
+    if !estimator.is_null() {
+        // First free the boxed deflate implementation
+        let estimator_ref = unsafe { &*estimator };
+        if !estimator_ref.context.is_null() {
+            let _ = unsafe { Box::from_raw(estimator_ref.context as *mut DeflateSizeEstimation) };
+        }
+        // Then free the DltSizeEstimator itself
+        let _ = unsafe { Box::from_raw(estimator) };
+    }
+}
+
+/// Get a null-terminated string description of the error code.
+///
+/// The returned string is a static string literal that does not need to be freed.
+///
+/// # Safety
+/// This function is safe to call with any error code value.
+#[no_mangle]
+pub unsafe extern "C" fn dltdeflate_error_message(
+    error_code: DltdeflateErrorCode,
+) -> *const c_char {
+    match error_code {
+        DltdeflateErrorCode::Success => c"Success".as_ptr(),
+        DltdeflateErrorCode::NullOutputPointer => {
+            c"Null pointer provided for estimator output parameter".as_ptr()
+        }
+        DltdeflateErrorCode::InvalidLevel => {
+            c"Invalid compression level: Level must be between 1 and 10".as_ptr()
+        }
+    }
+}
+
+/// C-compatible callback for [`DltSizeEstimator::max_compressed_size`].
+unsafe extern "C" fn deflate_max_compressed_size(
+    context: *mut c_void,
+    len_bytes: usize,
+    out_size: *mut usize,
+) -> u32 {
+    if context.is_null() || out_size.is_null() {
+        return 1; // Error: null pointer
+    }
+
+    let deflate = unsafe { &*(context as *const DeflateSizeEstimation) };
+
+    match deflate.max_compressed_size(len_bytes) {
+        Ok(size) => {
+            unsafe { *out_size = size };
+            0 // Success
+        }
+        Err(_) => 2, // Error: max_compressed_size failed
+    }
+}
+
+/// C-compatible callback for [`DltSizeEstimator::estimate_compressed_size`].
+unsafe extern "C" fn deflate_estimate_compressed_size(
+    context: *mut c_void,
+    input_ptr: *const u8,
+    len_bytes: usize,
+    output_ptr: *mut u8,
+    output_len: usize,
+    out_size: *mut usize,
+) -> u32 {
+    if context.is_null() || out_size.is_null() {
+        return 1; // Error: null pointer
+    }
+
+    let deflate = unsafe { &*(context as *const DeflateSizeEstimation) };
+    match unsafe { deflate.estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len) }
+    {
+        Ok(size) => {
+            unsafe { *out_size = size };
+            0 // Success
+        }
+        Err(_) => 3, // Error: estimate_compressed_size failed
+    }
+}
+
+/// Creates a C-compatible [`DltSizeEstimator`] from a deflate implementation.
+fn create_c_size_estimator(deflate: Box<DeflateSizeEstimation>) -> DltSizeEstimator {
+    DltSizeEstimator {
+        context: Box::into_raw(deflate) as *mut c_void,
+        max_compressed_size: deflate_max_compressed_size,
+        estimate_compressed_size: deflate_estimate_compressed_size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use core::ffi::CStr;
+
+    #[test]
+    fn test_create_and_free_estimator() {
+        unsafe {
+            let mut estimator = core::ptr::null_mut();
+            let result = dltdeflate_new_size_estimator(3, &mut estimator);
+            assert!(result.is_success());
+            assert!(!estimator.is_null());
+            dltdeflate_free_size_estimator(estimator);
+        }
+    }
+
+    #[test]
+    fn test_free_null_estimator() {
+        // Should not crash
+        unsafe {
+            dltdeflate_free_size_estimator(core::ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_invalid_level_returns_error() {
+        for level in [-1, 0, 11] {
+            unsafe {
+                let mut estimator = core::ptr::NonNull::dangling().as_ptr();
+                let result = dltdeflate_new_size_estimator(level, &mut estimator);
+                assert_eq!(result.error_code, DltdeflateErrorCode::InvalidLevel);
+                assert!(estimator.is_null());
+            }
+        }
+    }
+
+    #[test]
+    fn test_null_output_pointer_returns_error() {
+        unsafe {
+            let result = dltdeflate_new_size_estimator(1, core::ptr::null_mut());
+            assert_eq!(result.error_code, DltdeflateErrorCode::NullOutputPointer);
+        }
+    }
+
+    #[test]
+    fn test_error_message() {
+        unsafe {
+            let message =
+                CStr::from_ptr(dltdeflate_error_message(DltdeflateErrorCode::InvalidLevel));
+            assert_eq!(
+                message.to_str().unwrap(),
+                "Invalid compression level: Level must be between 1 and 10"
+            );
+        }
+    }
+
+    #[test]
+    fn test_estimator_functionality() {
+        unsafe {
+            let mut estimator = core::ptr::null_mut();
+            assert!(dltdeflate_new_size_estimator(1, &mut estimator).is_success());
+            let estimator_ref = &*estimator;
+
+            // Test max_compressed_size
+            let test_data = [0u8; 256];
+            let mut max_size = 0;
+            let result = (estimator_ref.max_compressed_size)(
+                estimator_ref.context,
+                test_data.len(),
+                &mut max_size,
+            );
+            assert_eq!(result, 0); // Success
+            assert!(max_size >= test_data.len());
+
+            // Test estimate_compressed_size
+            let mut output = vec![0u8; max_size];
+            let mut estimated_size = 0;
+            let result = (estimator_ref.estimate_compressed_size)(
+                estimator_ref.context,
+                test_data.as_ptr(),
+                test_data.len(),
+                output.as_mut_ptr(),
+                output.len(),
+                &mut estimated_size,
+            );
+            assert_eq!(result, 0); // Success
+            assert!(estimated_size > 0);
+            assert!(estimated_size < test_data.len()); // Should be smaller for repetitive data
+
+            dltdeflate_free_size_estimator(estimator);
+        }
+    }
+}
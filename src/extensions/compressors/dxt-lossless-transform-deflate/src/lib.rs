@@ -0,0 +1,339 @@
+#![doc = include_str!(concat!("../", core::env!("CARGO_PKG_README")))]
+#![no_std]
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+extern crate alloc;
+
+#[cfg(test)]
+pub mod test_prelude;
+
+#[cfg(feature = "c-exports")]
+pub mod c_api;
+
+use alloc::boxed::Box;
+use core::ptr::null_mut;
+use core::slice;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use miniz_oxide::deflate::core::{
+    compress, create_comp_flags_from_zip_params, CompressionStrategy, CompressorOxide, TDEFLFlush,
+    TDEFLStatus,
+};
+use miniz_oxide::DataFormat;
+use thiserror::Error;
+
+/// Lowest supported compression level.
+pub const DEFLATE_MIN_LEVEL: i32 = 1;
+
+/// Default compression level, as used by zlib.
+pub const DEFLATE_DEFAULT_LEVEL: i32 = 6;
+
+/// Highest supported compression level.
+///
+/// Level 10 is an extra level offered by miniz; zlib and most zip tools stop at 9.
+pub const DEFLATE_MAX_LEVEL: i32 = 10;
+
+/// Errors that can occur during deflate size estimation.
+#[derive(Debug, Error)]
+pub enum DeflateError {
+    /// Invalid compression level
+    #[error("Invalid compression level: {0}")]
+    InvalidLevel(i32),
+
+    /// Input is too large to compute the maximum compressed size for
+    #[error("Input of {0} bytes is too large for deflate")]
+    InputTooLarge(usize),
+
+    /// Deflate compression failed (e.g. the output buffer was too small)
+    #[error("Deflate compression failed")]
+    CompressionFailed,
+}
+
+/// Deflate implementation of [`SizeEstimationOperations`].
+///
+/// This implementation compresses the data as a raw deflate stream using [`miniz_oxide`],
+/// a pure Rust implementation, to estimate its size. Deflate's 32 KiB window and Huffman
+/// coding favour different transforms than ZStandard, so use this estimator if your textures
+/// end up in zip, gzip or zlib containers.
+///
+/// The zlib, gzip and zip formats wrap the raw deflate stream with a fixed size header and
+/// checksum, which does not affect which transform compresses best.
+///
+/// The compressor state (a few hundred KiB) is only allocated once; it is freed when the estimator
+/// is dropped. Reuse the same estimator across textures to avoid reallocating it.
+///
+/// The estimator can be shared between threads. If it is used by multiple threads at once,
+/// the extra calls use a temporary compressor of their own.
+pub struct DeflateSizeEstimation {
+    compression_level: i32,
+    /// Cached compressor; null if not yet created, or while taken by a call.
+    compressor: AtomicPtr<CompressorOxide>,
+}
+
+impl DeflateSizeEstimation {
+    /// Creates a new deflate size estimator with the specified compression level.
+    ///
+    /// # Parameters
+    /// * `compression_level` - Compression level (1-10). Use the level you compress your files with.
+    pub fn new(compression_level: i32) -> Result<Self, DeflateError> {
+        // Validate compression level
+        if !(DEFLATE_MIN_LEVEL..=DEFLATE_MAX_LEVEL).contains(&compression_level) {
+            return Err(DeflateError::InvalidLevel(compression_level));
+        }
+
+        Ok(Self::with_level(compression_level))
+    }
+
+    /// Creates a new deflate size estimator with the default compression level (6).
+    pub fn new_default() -> Self {
+        Self::with_level(DEFLATE_DEFAULT_LEVEL)
+    }
+
+    /// Returns the compression level used by this estimator.
+    pub fn compression_level(&self) -> i32 {
+        self.compression_level
+    }
+
+    const fn with_level(compression_level: i32) -> Self {
+        Self {
+            compression_level,
+            compressor: AtomicPtr::new(null_mut()),
+        }
+    }
+
+    fn compressor_flags(&self) -> u32 {
+        create_comp_flags_from_zip_params(
+            self.compression_level,
+            DataFormat::Raw.to_window_bits(),
+            CompressionStrategy::Default as i32,
+        )
+    }
+
+    /// Takes the cached compressor, or creates a new one if it is unavailable, ready for a
+    /// new stream.
+    fn take_compressor(&self) -> Box<CompressorOxide> {
+        let compressor = self.compressor.swap(null_mut(), Ordering::Acquire);
+        if compressor.is_null() {
+            return Box::new(CompressorOxide::new(self.compressor_flags()));
+        }
+
+        // SAFETY: Non-null pointers in `self.compressor` always come from `Box::into_raw`,
+        // and swapping in null gives this call sole ownership.
+        let mut compressor = unsafe { Box::from_raw(compressor) };
+        // Resetting keeps the flags, and reuses the memory.
+        compressor.reset();
+        compressor
+    }
+
+    /// Caches a compressor taken with [`Self::take_compressor`], freeing it if another is
+    /// already cached.
+    fn return_compressor(&self, compressor: Box<CompressorOxide>) {
+        let compressor = Box::into_raw(compressor);
+        if self
+            .compressor
+            .compare_exchange(null_mut(), compressor, Ordering::Release, Ordering::Relaxed)
+            .is_err()
+        {
+            // SAFETY: The pointer was not stored, so it is still owned here.
+            drop(unsafe { Box::from_raw(compressor) });
+        }
+    }
+}
+
+impl Drop for DeflateSizeEstimation {
+    fn drop(&mut self) {
+        let compressor = *self.compressor.get_mut();
+        if !compressor.is_null() {
+            // SAFETY: Non-null pointers in `self.compressor` always come from `Box::into_raw`.
+            drop(unsafe { Box::from_raw(compressor) });
+        }
+    }
+}
+
+impl Default for DeflateSizeEstimation {
+    fn default() -> Self {
+        Self::new_default()
+    }
+}
+
+impl SizeEstimationOperations for DeflateSizeEstimation {
+    type Error = DeflateError;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        if len_bytes == 0 {
+            return Ok(0);
+        }
+
+        // Same bound as miniz's `mz_compressBound`; incompressible data is emitted as
+        // stored blocks, each with a 5 byte header.
+        let too_large = || DeflateError::InputTooLarge(len_bytes);
+        let expanded = len_bytes
+            .checked_mul(110)
+            .map(|x| x / 100 + 128)
+            .ok_or_else(too_large)?;
+        let stored = (len_bytes / (31 * 1024) + 1)
+            .checked_mul(5)
+            .and_then(|x| x.checked_add(len_bytes + 128))
+            .ok_or_else(too_large)?;
+        Ok(expanded.max(stored))
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        input_ptr: *const u8,
+        len_bytes: usize,
+        output_ptr: *mut u8,
+        output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        if input_ptr.is_null() {
+            return Ok(0);
+        }
+
+        if len_bytes == 0 {
+            return Ok(0);
+        }
+
+        // Output buffer is guaranteed to be non-null and sufficient size
+        let input = slice::from_raw_parts(input_ptr, len_bytes);
+        let output = slice::from_raw_parts_mut(output_ptr, output_len);
+
+        let mut compressor = self.take_compressor();
+        let result = match compress(&mut compressor, input, output, TDEFLFlush::Finish) {
+            (TDEFLStatus::Done, _, compressed_size) => Ok(compressed_size),
+            _ => Err(DeflateError::CompressionFailed),
+        };
+        self.return_compressor(compressor);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use miniz_oxide::inflate::decompress_to_vec;
+
+    fn compress(estimator: &DeflateSizeEstimation, data: &[u8]) -> Vec<u8> {
+        let max_size = estimator.max_compressed_size(data.len()).unwrap();
+        let mut output_buffer = vec![0u8; max_size];
+        let size = unsafe {
+            estimator
+                .estimate_compressed_size(
+                    data.as_ptr(),
+                    data.len(),
+                    output_buffer.as_mut_ptr(),
+                    max_size,
+                )
+                .unwrap()
+        };
+        output_buffer.truncate(size);
+        output_buffer
+    }
+
+    #[test]
+    fn estimate_empty_data() {
+        let estimator = DeflateSizeEstimation::default();
+        let result = unsafe {
+            estimator.estimate_compressed_size(core::ptr::null(), 0, core::ptr::null_mut(), 0)
+        };
+        assert_eq!(result.unwrap(), 0);
+        assert_eq!(estimator.max_compressed_size(0).unwrap(), 0);
+    }
+
+    #[rstest::rstest]
+    #[case::fastest(DEFLATE_MIN_LEVEL)]
+    #[case::default(DEFLATE_DEFAULT_LEVEL)]
+    #[case::zlib_max(9)]
+    #[case::max(DEFLATE_MAX_LEVEL)]
+    fn estimate_is_raw_deflate_stream(#[case] level: i32) {
+        let estimator = DeflateSizeEstimation::new(level).unwrap();
+        let data =
+            b"Hello, world! This is a test string for compression. test test test test test test!!";
+
+        let compressed = compress(&estimator, data);
+        assert!(compressed.len() < data.len()); // Should be smaller than input for this test case
+        assert_eq!(decompress_to_vec(&compressed).unwrap(), data);
+    }
+
+    #[test]
+    fn max_compressed_size_fits_incompressible_data() {
+        // xorshift, so the data does not compress
+        let mut state = 0x2545_F491u32;
+        let data: Vec<u8> = (0..200_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+
+        let estimator = DeflateSizeEstimation::new(DEFLATE_MAX_LEVEL).unwrap();
+        let compressed = compress(&estimator, &data);
+        assert!(compressed.len() >= data.len());
+        assert_eq!(decompress_to_vec(&compressed).unwrap(), data);
+    }
+
+    #[test]
+    fn output_buffer_too_small_returns_error() {
+        let estimator = DeflateSizeEstimation::default();
+        let data = [0x12u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+        let mut output_buffer = [0u8; 1];
+        let result = unsafe {
+            estimator.estimate_compressed_size(
+                data.as_ptr(),
+                data.len(),
+                output_buffer.as_mut_ptr(),
+                output_buffer.len(),
+            )
+        };
+        assert!(matches!(result, Err(DeflateError::CompressionFailed)));
+    }
+
+    #[test]
+    fn reused_compressor_gives_same_result() {
+        let estimator = DeflateSizeEstimation::default();
+        let data: Vec<u8> = (0..64 * 1024)
+            .map(|x| (x % 251) as u8 ^ (x >> 9) as u8)
+            .collect();
+        let first = compress(&estimator, &data);
+
+        // Failed estimates must not leave state behind in the cached compressor
+        let mut output_buffer = [0u8; 1];
+        let result = unsafe {
+            estimator.estimate_compressed_size(
+                data.as_ptr(),
+                data.len(),
+                output_buffer.as_mut_ptr(),
+                output_buffer.len(),
+            )
+        };
+        assert!(result.is_err());
+
+        assert_eq!(compress(&estimator, &data), first);
+        assert_eq!(
+            compress(&estimator, b"other data"),
+            compress(&DeflateSizeEstimation::default(), b"other data")
+        );
+    }
+
+    #[test]
+    fn test_invalid_compression_level() {
+        for level in [-1, 0, 11] {
+            let result = DeflateSizeEstimation::new(level);
+            assert!(matches!(result, Err(DeflateError::InvalidLevel(x)) if x == level));
+        }
+    }
+
+    #[test]
+    fn input_too_large_returns_error() {
+        let estimator = DeflateSizeEstimation::default();
+        assert!(matches!(
+            estimator.max_compressed_size(usize::MAX),
+            Err(DeflateError::InputTooLarge(usize::MAX))
+        ));
+    }
+}
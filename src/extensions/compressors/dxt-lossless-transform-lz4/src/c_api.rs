@@ -0,0 +1,335 @@
+//! C API for LZ4 Size Estimation
+//!
+//! This module provides a C-compatible interface for the LZ4 and LZ4-HC size estimation
+//! functionality. It exposes the core [`Lz4SizeEstimation`] type through the [`DltSizeEstimator`]
+//! interface from `dxt-lossless-transform-api-common`.
+//!
+//! ## Usage Pattern
+//!
+//! 1. Create an estimator instance using [`dltlz4_new_size_estimator`]
+//! 2. Use the estimator with any API that accepts a [`DltSizeEstimator`]
+//! 3. Free the estimator when done using [`dltlz4_free_size_estimator`]
+//!
+//! ## Important Notes
+//!
+//! This estimator performs actual compression, so its estimates match the real compressed size.
+//! Levels 1-2 use the fast LZ4 compressor, and levels 3-12 use LZ4-HC; use the level you
+//! compress your files with.
+//!
+//! ## Thread Safety
+//!
+//! The LZ4 estimator is thread-safe and can be used from multiple threads simultaneously.
+//! The estimator has no internal state, making it safe for concurrent use.
+//!
+//! # Required Headers
+//!
+//! When using this API from C/C++, you must include the common API header first:
+//! ```c
+//! #include "dxt-lossless-transform-api-common.h"
+//! #include "dxt-lossless-transform-lz4.h"
+//! ```
+//!
+//! # Usage with Transform APIs (BC1, BC2, BC3, BC7, etc.)
+//!
+//! The LZ4 estimator implements the [`DltSizeEstimator`] interface and can be used
+//! directly with BCX automatic transform builders such as
+//! [`dltbc1_new_AutoTransformBuilder`].
+//!
+//! ```c
+//! DltSizeEstimator* estimator = NULL;
+//! Dltlz4Result result = dltlz4_new_size_estimator(9, &estimator);
+//! if (result.error_code != DLTLZ4_SUCCESS) {
+//!     printf("Failed to create estimator: %s\n", dltlz4_error_message(result.error_code));
+//!     return;
+//! }
+//!
+//! Dltbc1AutoTransformBuilder* builder = dltbc1_new_AutoTransformBuilder(estimator);
+//! // Transform with the builder...
+//!
+//! dltbc1_free_AutoTransformBuilder(builder);
+//! dltlz4_free_size_estimator(estimator);
+//! ```
+//!
+//! # Available Functions
+//!
+//! - [`dltlz4_new_size_estimator`] - Create a new estimator
+//! - [`dltlz4_free_size_estimator`] - Free an estimator
+//! - [`dltlz4_error_message`] - Describe an error code
+//!
+//! [`dltbc1_new_AutoTransformBuilder`]: https://docs.rs/dxt-lossless-transform-bc1-api/latest/dxt_lossless_transform_bc1_api/c_api/transform/auto_transform_builder/fn.dltbc1_new_AutoTransformBuilder.html
+
+use crate::Lz4SizeEstimation;
+use alloc::boxed::Box;
+use core::ffi::{c_char, c_void};
+use dxt_lossless_transform_api_common::c_api::size_estimation::DltSizeEstimator;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+
+/// C-compatible error codes for LZ4 estimator operations.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dltlz4ErrorCode {
+    /// Operation succeeded
+    Success = 0,
+    /// Null pointer provided for estimator output parameter
+    NullOutputPointer = 1,
+    /// Invalid compression level: Level must be between 1 and 12
+    InvalidLevel = 2,
+}
+
+/// C-compatible Result type for LZ4 estimator operations.
+#[repr(C)]
+pub struct Dltlz4Result {
+    /// Error code (0 = success, non-zero = error)
+    pub error_code: Dltlz4ErrorCode,
+}
+
+impl Dltlz4Result {
+    /// Create a success result
+    pub const fn success() -> Self {
+        Self {
+            error_code: Dltlz4ErrorCode::Success,
+        }
+    }
+
+    /// Create an error result from an error code
+    pub const fn from_error_code(error_code: Dltlz4ErrorCode) -> Self {
+        Self { error_code }
+    }
+
+    /// Check if the result is successful
+    pub fn is_success(&self) -> bool {
+        matches!(self.error_code, Dltlz4ErrorCode::Success)
+    }
+}
+
+/// Create a new LZ4 size estimator.
+///
+/// The estimator compresses data at the given level to determine its compressed size.
+///
+/// # Parameters
+/// * `level` - LZ4 compression level (1-12); 1-2 use LZ4 and 3-12 use LZ4-HC.
+///   Use the level you compress your files with.
+/// * `out_estimator` - Output pointer where the created estimator will be written.
+///   On success, this will be set to a pointer that must be freed with
+///   [`dltlz4_free_size_estimator`]. On error, this will be set to null.
+///
+/// # Returns
+///
+/// A [`Dltlz4Result`] indicating success, or [`Dltlz4ErrorCode::InvalidLevel`] if
+/// `level` is out of range.
+///
+/// # Safety
+///
+/// `out_estimator` must be a valid pointer to write the result.
+///
+/// # Remarks
+/// This function corresponds to [`Lz4SizeEstimation::new`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltlz4_new_size_estimator(
+    level: i32,
+    out_estimator: *mut *mut DltSizeEstimator,
+) -> Dltlz4Result {
+    if out_estimator.is_null() {
+        return Dltlz4Result::from_error_code(Dltlz4ErrorCode::NullOutputPointer);
+    }
+
+    match Lz4SizeEstimation::new(level) {
+        Ok(lz4) => {
+            let estimator = create_c_size_estimator(Box::new(lz4));
+            unsafe { *out_estimator = Box::into_raw(Box::new(estimator)) };
+            Dltlz4Result::success()
+        }
+        // Level validation is the only way creating the estimator can fail
+        Err(_) => {
+            unsafe { *out_estimator = core::ptr::null_mut() };
+            Dltlz4Result::from_error_code(Dltlz4ErrorCode::InvalidLevel)
+        }
+    }
+}
+
+/// Free an LZ4 size estimator created by [`dltlz4_new_size_estimator`].
+///
+/// # Parameters
+/// * `estimator` - Pointer to the estimator to free (can be null)
+///
+/// # Safety
+/// The estimator pointer must have been returned by [`dltlz4_new_size_estimator`],
+/// or be null. After calling this function, the pointer becomes invalid.
+#[no_mangle]
+pub unsafe extern "C" fn dltlz4_free_size_estimator(estimator: *mut DltSizeEstimator) {
+    if !estimator.is_null() {
+        // First free the boxed LZ4 implementation
+        let estimator_ref = unsafe { &*estimator };
+        if !estimator_ref.context.is_null() {
+            let _ = unsafe { Box::from_raw(estimator_ref.context as *mut Lz4SizeEstimation) };
+        }
+        // Then free the DltSizeEstimator itself
+        let _ = unsafe { Box::from_raw(estimator) };
+    }
+}
+
+/// Get a null-terminated string description of the error code.
+///
+/// The returned string is a static string literal that does not need to be freed.
+///
+/// # Safety
+/// This function is safe to call with any error code value.
+#[no_mangle]
+pub unsafe extern "C" fn dltlz4_error_message(error_code: Dltlz4ErrorCode) -> *const c_char {
+    match error_code {
+        Dltlz4ErrorCode::Success => c"Success".as_ptr(),
+        Dltlz4ErrorCode::NullOutputPointer => {
+            c"Null pointer provided for estimator output parameter".as_ptr()
+        }
+        Dltlz4ErrorCode::InvalidLevel => {
+            c"Invalid compression level: Level must be between 1 and 12".as_ptr()
+        }
+    }
+}
+
+/// C-compatible callback for [`DltSizeEstimator::max_compressed_size`].
+unsafe extern "C" fn lz4_max_compressed_size(
+    context: *mut c_void,
+    len_bytes: usize,
+    out_size: *mut usize,
+) -> u32 {
+    if context.is_null() || out_size.is_null() {
+        return 1; // Error: null pointer
+    }
+
+    let lz4 = unsafe { &*(context as *const Lz4SizeEstimation) };
+
+    match lz4.max_compressed_size(len_bytes) {
+        Ok(size) => {
+            unsafe { *out_size = size };
+            0 // Success
+        }
+        Err(_) => 2, // Error: max_compressed_size failed
+    }
+}
+
+/// C-compatible callback for [`DltSizeEstimator::estimate_compressed_size`].
+unsafe extern "C" fn lz4_estimate_compressed_size(
+    context: *mut c_void,
+    input_ptr: *const u8,
+    len_bytes: usize,
+    output_ptr: *mut u8,
+    output_len: usize,
+    out_size: *mut usize,
+) -> u32 {
+    if context.is_null() || out_size.is_null() {
+        return 1; // Error: null pointer
+    }
+
+    let lz4 = unsafe { &*(context as *const Lz4SizeEstimation) };
+    match unsafe { lz4.estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len) } {
+        Ok(size) => {
+            unsafe { *out_size = size };
+            0 // Success
+        }
+        Err(_) => 3, // Error: estimate_compressed_size failed
+    }
+}
+
+/// Creates a C-compatible [`DltSizeEstimator`] from an LZ4 implementation.
+fn create_c_size_estimator(lz4: Box<Lz4SizeEstimation>) -> DltSizeEstimator {
+    DltSizeEstimator {
+        context: Box::into_raw(lz4) as *mut c_void,
+        max_compressed_size: lz4_max_compressed_size,
+        estimate_compressed_size: lz4_estimate_compressed_size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use core::ffi::CStr;
+
+    #[test]
+    fn test_create_and_free_estimator() {
+        unsafe {
+            let mut estimator = core::ptr::null_mut();
+            let result = dltlz4_new_size_estimator(3, &mut estimator);
+            assert!(result.is_success());
+            assert!(!estimator.is_null());
+            dltlz4_free_size_estimator(estimator);
+        }
+    }
+
+    #[test]
+    fn test_free_null_estimator() {
+        // Should not crash
+        unsafe {
+            dltlz4_free_size_estimator(core::ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_invalid_level_returns_error() {
+        for level in [-1, 0, 13] {
+            unsafe {
+                let mut estimator = core::ptr::NonNull::dangling().as_ptr();
+                let result = dltlz4_new_size_estimator(level, &mut estimator);
+                assert_eq!(result.error_code, Dltlz4ErrorCode::InvalidLevel);
+                assert!(estimator.is_null());
+            }
+        }
+    }
+
+    #[test]
+    fn test_null_output_pointer_returns_error() {
+        unsafe {
+            let result = dltlz4_new_size_estimator(1, core::ptr::null_mut());
+            assert_eq!(result.error_code, Dltlz4ErrorCode::NullOutputPointer);
+        }
+    }
+
+    #[test]
+    fn test_error_message() {
+        unsafe {
+            let message = CStr::from_ptr(dltlz4_error_message(Dltlz4ErrorCode::InvalidLevel));
+            assert_eq!(
+                message.to_str().unwrap(),
+                "Invalid compression level: Level must be between 1 and 12"
+            );
+        }
+    }
+
+    #[test]
+    fn test_estimator_functionality() {
+        unsafe {
+            let mut estimator = core::ptr::null_mut();
+            assert!(dltlz4_new_size_estimator(1, &mut estimator).is_success());
+            let estimator_ref = &*estimator;
+
+            // Test max_compressed_size
+            let test_data = [0u8; 256];
+            let mut max_size = 0;
+            let result = (estimator_ref.max_compressed_size)(
+                estimator_ref.context,
+                test_data.len(),
+                &mut max_size,
+            );
+            assert_eq!(result, 0); // Success
+            assert!(max_size >= test_data.len());
+
+            // Test estimate_compressed_size
+            let mut output = vec![0u8; max_size];
+            let mut estimated_size = 0;
+            let result = (estimator_ref.estimate_compressed_size)(
+                estimator_ref.context,
+                test_data.as_ptr(),
+                test_data.len(),
+                output.as_mut_ptr(),
+                output.len(),
+                &mut estimated_size,
+            );
+            assert_eq!(result, 0); // Success
+            assert!(estimated_size > 0);
+            assert!(estimated_size < test_data.len()); // Should be smaller for repetitive data
+
+            dltlz4_free_size_estimator(estimator);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+#![doc = include_str!(concat!("../", core::env!("CARGO_PKG_README")))]
+#![no_std]
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+extern crate alloc;
+
+#[cfg(test)]
+pub mod test_prelude;
+
+#[cfg(feature = "c-exports")]
+pub mod c_api;
+
+use core::ffi::{c_char, c_int};
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use lz4_sys::{LZ4_compressBound, LZ4_compress_HC, LZ4_compress_default};
+use thiserror::Error;
+
+/// Lowest compression level, which uses the fast LZ4 compressor.
+pub const LZ4_MIN_LEVEL: i32 = 1;
+
+/// Lowest compression level which uses the LZ4-HC compressor.
+pub const LZ4HC_MIN_LEVEL: i32 = 3;
+
+/// Default LZ4-HC compression level.
+pub const LZ4HC_DEFAULT_LEVEL: i32 = 9;
+
+/// Highest compression level (LZ4-HC).
+pub const LZ4HC_MAX_LEVEL: i32 = 12;
+
+/// Errors that can occur during LZ4 size estimation.
+#[derive(Debug, Error)]
+pub enum Lz4Error {
+    /// Invalid compression level
+    #[error("Invalid compression level: {0}")]
+    InvalidLevel(i32),
+
+    /// Input is larger than LZ4 can compress in a single block
+    #[error("Input of {0} bytes is too large for LZ4")]
+    InputTooLarge(usize),
+
+    /// LZ4 compression failed (e.g. the output buffer was too small)
+    #[error("LZ4 compression failed")]
+    CompressionFailed,
+}
+
+/// LZ4 and LZ4-HC implementation of [`SizeEstimationOperations`].
+///
+/// This implementation compresses the data as an LZ4 block to estimate its size,
+/// so estimates match the real compressed size.
+///
+/// The compression level is configured when creating the estimator instance, and uses the same
+/// scale as the `lz4` command line tool:
+///
+/// - Levels 1-2 use the fast LZ4 compressor (`LZ4_compress_default`).
+/// - Levels 3-12 use the LZ4-HC compressor (`LZ4_compress_HC`).
+///
+/// Use the level you compress your files with.
+pub struct Lz4SizeEstimation {
+    compression_level: i32,
+}
+
+impl Lz4SizeEstimation {
+    /// Creates a new LZ4 size estimator with the specified compression level.
+    ///
+    /// # Parameters
+    /// * `compression_level` - Compression level (1-12, where 1-2 are LZ4 and 3-12 are LZ4-HC)
+    pub fn new(compression_level: i32) -> Result<Self, Lz4Error> {
+        // Validate compression level
+        if !(LZ4_MIN_LEVEL..=LZ4HC_MAX_LEVEL).contains(&compression_level) {
+            return Err(Lz4Error::InvalidLevel(compression_level));
+        }
+
+        Ok(Self { compression_level })
+    }
+
+    /// Creates a new LZ4 size estimator using the fast LZ4 compressor (level 1).
+    pub fn new_fast() -> Self {
+        Self {
+            compression_level: LZ4_MIN_LEVEL,
+        }
+    }
+
+    /// Creates a new LZ4 size estimator using LZ4-HC at its default level (9).
+    pub fn new_hc() -> Self {
+        Self {
+            compression_level: LZ4HC_DEFAULT_LEVEL,
+        }
+    }
+
+    /// Creates a new LZ4 size estimator using LZ4-HC at its highest level (12).
+    pub fn new_best() -> Self {
+        Self {
+            compression_level: LZ4HC_MAX_LEVEL,
+        }
+    }
+
+    /// Returns whether this estimator uses the LZ4-HC compressor.
+    pub fn is_hc(&self) -> bool {
+        self.compression_level >= LZ4HC_MIN_LEVEL
+    }
+}
+
+impl Default for Lz4SizeEstimation {
+    fn default() -> Self {
+        Self::new_fast()
+    }
+}
+
+impl SizeEstimationOperations for Lz4SizeEstimation {
+    type Error = Lz4Error;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        if len_bytes == 0 {
+            return Ok(0);
+        }
+
+        // Calculate maximum compressed size using LZ4 bounds; 0 if the input is too large
+        let len = c_int::try_from(len_bytes).map_err(|_| Lz4Error::InputTooLarge(len_bytes))?;
+        match unsafe { LZ4_compressBound(len) } {
+            0 => Err(Lz4Error::InputTooLarge(len_bytes)),
+            max_size => Ok(max_size as usize),
+        }
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        input_ptr: *const u8,
+        len_bytes: usize,
+        output_ptr: *mut u8,
+        output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        if input_ptr.is_null() {
+            return Ok(0);
+        }
+
+        if len_bytes == 0 {
+            return Ok(0);
+        }
+
+        let len = c_int::try_from(len_bytes).map_err(|_| Lz4Error::InputTooLarge(len_bytes))?;
+        // A larger buffer than LZ4 can address is fine; it will never write past the bound
+        let capacity = c_int::try_from(output_len).unwrap_or(c_int::MAX);
+
+        // Output buffer is guaranteed to be non-null and sufficient size
+        let source = input_ptr as *const c_char;
+        let destination = output_ptr as *mut c_char;
+        let compressed_size = if self.is_hc() {
+            LZ4_compress_HC(source, destination, len, capacity, self.compression_level)
+        } else {
+            LZ4_compress_default(source, destination, len, capacity)
+        };
+
+        match compressed_size {
+            0 => Err(Lz4Error::CompressionFailed),
+            size => Ok(size as usize),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+
+    fn estimate(estimator: &Lz4SizeEstimation, data: &[u8]) -> usize {
+        let max_size = estimator.max_compressed_size(data.len()).unwrap();
+        let mut output_buffer = vec![0u8; max_size];
+        unsafe {
+            estimator
+                .estimate_compressed_size(
+                    data.as_ptr(),
+                    data.len(),
+                    output_buffer.as_mut_ptr(),
+                    max_size,
+                )
+                .unwrap()
+        }
+    }
+
+    #[test]
+    fn estimate_empty_data() {
+        let estimator = Lz4SizeEstimation::default();
+        let result = unsafe {
+            estimator.estimate_compressed_size(core::ptr::null(), 0, core::ptr::null_mut(), 0)
+        };
+        assert_eq!(result.unwrap(), 0);
+        assert_eq!(estimator.max_compressed_size(0).unwrap(), 0);
+    }
+
+    #[rstest::rstest]
+    #[case::fast(Lz4SizeEstimation::new_fast(), false)]
+    #[case::level_2(Lz4SizeEstimation::new(2).unwrap(), false)]
+    #[case::hc_min(Lz4SizeEstimation::new(LZ4HC_MIN_LEVEL).unwrap(), true)]
+    #[case::hc(Lz4SizeEstimation::new_hc(), true)]
+    #[case::best(Lz4SizeEstimation::new_best(), true)]
+    fn estimate_simple_data(#[case] estimator: Lz4SizeEstimation, #[case] is_hc: bool) {
+        let data =
+            b"Hello, world! This is a test string for compression. test test test test test test!!";
+
+        assert_eq!(estimator.is_hc(), is_hc);
+        let size = estimate(&estimator, data);
+        assert!(size > 0);
+        assert!(size < data.len()); // Should be smaller than input for this test case
+    }
+
+    #[test]
+    fn hc_compresses_better_than_fast() {
+        let data: Vec<u8> = (0..65536u32)
+            .map(|x| ((x % 251) ^ (x / 509)) as u8)
+            .collect();
+        assert!(
+            estimate(&Lz4SizeEstimation::new_best(), &data)
+                < estimate(&Lz4SizeEstimation::new_fast(), &data)
+        );
+    }
+
+    #[test]
+    fn output_buffer_too_small_returns_error() {
+        let estimator = Lz4SizeEstimation::new_fast();
+        let data = [0x12u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+        let mut output_buffer = [0u8; 1];
+        let result = unsafe {
+            estimator.estimate_compressed_size(
+                data.as_ptr(),
+                data.len(),
+                output_buffer.as_mut_ptr(),
+                output_buffer.len(),
+            )
+        };
+        assert!(matches!(result, Err(Lz4Error::CompressionFailed)));
+    }
+
+    #[test]
+    fn test_invalid_compression_level() {
+        for level in [-1, 0, 13] {
+            let result = Lz4SizeEstimation::new(level);
+            assert!(matches!(result, Err(Lz4Error::InvalidLevel(x)) if x == level));
+        }
+    }
+
+    #[test]
+    fn input_too_large_returns_error() {
+        let estimator = Lz4SizeEstimation::new_fast();
+        assert!(matches!(
+            estimator.max_compressed_size(usize::MAX),
+            Err(Lz4Error::InputTooLarge(usize::MAX))
+        ));
+    }
+}
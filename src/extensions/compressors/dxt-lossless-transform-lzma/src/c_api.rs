@@ -0,0 +1,406 @@
+//! C API for LZMA Size Estimation
+//!
+//! This module provides a C-compatible interface for the LZMA (xz) size estimation
+//! functionality. It exposes the core [`LzmaSizeEstimation`] type through the [`DltSizeEstimator`]
+//! interface from `dxt-lossless-transform-api-common`.
+//!
+//! ## Usage Pattern
+//!
+//! 1. Create an estimator instance using [`dltlzma_new_size_estimator`]
+//! 2. Use the estimator with any API that accepts a [`DltSizeEstimator`]
+//! 3. Free the estimator when done using [`dltlzma_free_size_estimator`]
+//!
+//! ## Important Notes
+//!
+//! This estimator performs actual compression, so its estimates match the real compressed size.
+//! Use the level you compress your files with. LZMA is slow; for large textures, consider
+//! [`dltlzma_new_sampled_size_estimator`], which compresses only a sample of each input.
+//!
+//! ## Thread Safety
+//!
+//! The LZMA estimator is thread-safe and can be used from multiple threads simultaneously.
+//! It caches its encoder between estimates; concurrent calls use a temporary encoder of their own.
+//!
+//! # Required Headers
+//!
+//! When using this API from C/C++, you must include the common API header first:
+//! ```c
+//! #include "dxt-lossless-transform-api-common.h"
+//! #include "dxt-lossless-transform-lzma.h"
+//! ```
+//!
+//! # Usage with Transform APIs (BC1, BC2, BC3, BC7, etc.)
+//!
+//! The LZMA estimator implements the [`DltSizeEstimator`] interface and can be used
+//! directly with BCX automatic transform builders such as
+//! [`dltbc1_new_AutoTransformBuilder`].
+//!
+//! ```c
+//! DltSizeEstimator* estimator = NULL;
+//! DltlzmaResult result = dltlzma_new_size_estimator(6, &estimator);
+//! if (result.error_code != DLTLZMA_SUCCESS) {
+//!     printf("Failed to create estimator: %s\n", dltlzma_error_message(result.error_code));
+//!     return;
+//! }
+//!
+//! Dltbc1AutoTransformBuilder* builder = dltbc1_new_AutoTransformBuilder(estimator);
+//! // Transform with the builder...
+//!
+//! dltbc1_free_AutoTransformBuilder(builder);
+//! dltlzma_free_size_estimator(estimator);
+//! ```
+//!
+//! # Available Functions
+//!
+//! - [`dltlzma_new_size_estimator`] - Create a new estimator
+//! - [`dltlzma_new_sampled_size_estimator`] - Create a new estimator which compresses a sample of each input
+//! - [`dltlzma_free_size_estimator`] - Free an estimator
+//! - [`dltlzma_error_message`] - Describe an error code
+//!
+//! [`dltbc1_new_AutoTransformBuilder`]: https://docs.rs/dxt-lossless-transform-bc1-api/latest/dxt_lossless_transform_bc1_api/c_api/transform/auto_transform_builder/fn.dltbc1_new_AutoTransformBuilder.html
+
+use crate::{LzmaError, LzmaSizeEstimation};
+use alloc::boxed::Box;
+use core::ffi::{c_char, c_void};
+use dxt_lossless_transform_api_common::c_api::size_estimation::DltSizeEstimator;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+
+/// C-compatible error codes for LZMA estimator operations.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DltlzmaErrorCode {
+    /// Operation succeeded
+    Success = 0,
+    /// Null pointer provided for estimator output parameter
+    NullOutputPointer = 1,
+    /// Invalid compression level: Level must be between 0 and 9
+    InvalidLevel = 2,
+    /// Invalid sample size: Sample size must be at least 256 bytes
+    InvalidSampleSize = 3,
+}
+
+/// C-compatible Result type for LZMA estimator operations.
+#[repr(C)]
+pub struct DltlzmaResult {
+    /// Error code (0 = success, non-zero = error)
+    pub error_code: DltlzmaErrorCode,
+}
+
+impl DltlzmaResult {
+    /// Create a success result
+    pub const fn success() -> Self {
+        Self {
+            error_code: DltlzmaErrorCode::Success,
+        }
+    }
+
+    /// Create an error result from an error code
+    pub const fn from_error_code(error_code: DltlzmaErrorCode) -> Self {
+        Self { error_code }
+    }
+
+    /// Check if the result is successful
+    pub fn is_success(&self) -> bool {
+        matches!(self.error_code, DltlzmaErrorCode::Success)
+    }
+}
+
+/// Create a new LZMA size estimator.
+///
+/// The estimator compresses data at the given level to determine its compressed size.
+///
+/// # Parameters
+/// * `level` - LZMA compression level (0-9, the `xz` preset).
+///   Use the level you compress your files with.
+/// * `out_estimator` - Output pointer where the created estimator will be written.
+///   On success, this will be set to a pointer that must be freed with
+///   [`dltlzma_free_size_estimator`]. On error, this will be set to null.
+///
+/// # Returns
+///
+/// A [`DltlzmaResult`] indicating success, or [`DltlzmaErrorCode::InvalidLevel`] if
+/// `level` is out of range.
+///
+/// # Safety
+///
+/// `out_estimator` must be a valid pointer to write the result.
+///
+/// # Remarks
+/// This function corresponds to [`LzmaSizeEstimation::new`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltlzma_new_size_estimator(
+    level: i32,
+    out_estimator: *mut *mut DltSizeEstimator,
+) -> DltlzmaResult {
+    unsafe { write_estimator(LzmaSizeEstimation::new(level), out_estimator) }
+}
+
+/// Create a new LZMA size estimator which estimates from a sample of each input.
+///
+/// Rather than compressing all of the data, the estimator compresses evenly spaced chunks
+/// totalling `sample_size` bytes, and scales the result up. This is much faster on large
+/// textures, at the cost of accuracy.
+///
+/// # Parameters
+/// * `level` - LZMA compression level (0-9, the `xz` preset).
+/// * `sample_size` - Number of bytes to compress from each input; at least 256.
+///   Inputs no larger than this are compressed in full.
+/// * `out_estimator` - Output pointer where the created estimator will be written.
+///   On success, this will be set to a pointer that must be freed with
+///   [`dltlzma_free_size_estimator`]. On error, this will be set to null.
+///
+/// # Returns
+///
+/// A [`DltlzmaResult`] indicating success, [`DltlzmaErrorCode::InvalidLevel`] if
+/// `level` is out of range, or [`DltlzmaErrorCode::InvalidSampleSize`] if `sample_size`
+/// is too small.
+///
+/// # Safety
+///
+/// `out_estimator` must be a valid pointer to write the result.
+///
+/// # Remarks
+/// This function corresponds to [`LzmaSizeEstimation::new_sampled`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltlzma_new_sampled_size_estimator(
+    level: i32,
+    sample_size: usize,
+    out_estimator: *mut *mut DltSizeEstimator,
+) -> DltlzmaResult {
+    unsafe {
+        write_estimator(
+            LzmaSizeEstimation::new_sampled(level, sample_size),
+            out_estimator,
+        )
+    }
+}
+
+/// Writes a newly created estimator (or null on error) to `out_estimator`.
+unsafe fn write_estimator(
+    estimator: Result<LzmaSizeEstimation, LzmaError>,
+    out_estimator: *mut *mut DltSizeEstimator,
+) -> DltlzmaResult {
+    if out_estimator.is_null() {
+        return DltlzmaResult::from_error_code(DltlzmaErrorCode::NullOutputPointer);
+    }
+
+    let error_code = match estimator {
+        Ok(lzma) => {
+            let estimator = create_c_size_estimator(Box::new(lzma));
+            unsafe { *out_estimator = Box::into_raw(Box::new(estimator)) };
+            return DltlzmaResult::success();
+        }
+        Err(LzmaError::InvalidSampleSize(_)) => DltlzmaErrorCode::InvalidSampleSize,
+        // Only the parameters are validated when creating the estimator
+        Err(_) => DltlzmaErrorCode::InvalidLevel,
+    };
+
+    unsafe { *out_estimator = core::ptr::null_mut() };
+    DltlzmaResult::from_error_code(error_code)
+}
+
+/// Free an LZMA size estimator created by [`dltlzma_new_size_estimator`].
+///
+/// # Parameters
+/// * `estimator` - Pointer to the estimator to free (can be null)
+///
+/// # Safety
+/// The estimator pointer must have been returned by [`dltlzma_new_size_estimator`],
+/// or be null. After calling this function, the pointer becomes invalid.
+#[no_mangle]
+pub unsafe extern "C" fn dltlzma_free_size_estimator(estimator: *mut DltSizeEstimator) {
+    if !estimator.is_null() {
+        // First free the boxed LZMA implementation
+        let estimator_ref = unsafe { &*estimator };
+        if !estimator_ref.context.is_null() {
+            let _ = unsafe { Box::from_raw(estimator_ref.context as *mut LzmaSizeEstimation) };
+        }
+        // Then free the DltSizeEstimator itself
+        let _ = unsafe { Box::from_raw(estimator) };
+    }
+}
+
+/// Get a null-terminated string description of the error code.
+///
+/// The returned string is a static string literal that does not need to be freed.
+///
+/// # Safety
+/// This function is safe to call with any error code value.
+#[no_mangle]
+pub unsafe extern "C" fn dltlzma_error_message(error_code: DltlzmaErrorCode) -> *const c_char {
+    match error_code {
+        DltlzmaErrorCode::Success => c"Success".as_ptr(),
+        DltlzmaErrorCode::NullOutputPointer => {
+            c"Null pointer provided for estimator output parameter".as_ptr()
+        }
+        DltlzmaErrorCode::InvalidLevel => {
+            c"Invalid compression level: Level must be between 0 and 9".as_ptr()
+        }
+        DltlzmaErrorCode::InvalidSampleSize => {
+            c"Invalid sample size: Sample size must be at least 256 bytes".as_ptr()
+        }
+    }
+}
+
+/// C-compatible callback for [`DltSizeEstimator::max_compressed_size`].
+unsafe extern "C" fn lzma_max_compressed_size(
+    context: *mut c_void,
+    len_bytes: usize,
+    out_size: *mut usize,
+) -> u32 {
+    if context.is_null() || out_size.is_null() {
+        return 1; // Error: null pointer
+    }
+
+    let lzma = unsafe { &*(context as *const LzmaSizeEstimation) };
+
+    match lzma.max_compressed_size(len_bytes) {
+        Ok(size) => {
+            unsafe { *out_size = size };
+            0 // Success
+        }
+        Err(_) => 2, // Error: max_compressed_size failed
+    }
+}
+
+/// C-compatible callback for [`DltSizeEstimator::estimate_compressed_size`].
+unsafe extern "C" fn lzma_estimate_compressed_size(
+    context: *mut c_void,
+    input_ptr: *const u8,
+    len_bytes: usize,
+    output_ptr: *mut u8,
+    output_len: usize,
+    out_size: *mut usize,
+) -> u32 {
+    if context.is_null() || out_size.is_null() {
+        return 1; // Error: null pointer
+    }
+
+    let lzma = unsafe { &*(context as *const LzmaSizeEstimation) };
+    match unsafe { lzma.estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len) } {
+        Ok(size) => {
+            unsafe { *out_size = size };
+            0 // Success
+        }
+        Err(_) => 3, // Error: estimate_compressed_size failed
+    }
+}
+
+/// Creates a C-compatible [`DltSizeEstimator`] from an LZMA implementation.
+fn create_c_size_estimator(lzma: Box<LzmaSizeEstimation>) -> DltSizeEstimator {
+    DltSizeEstimator {
+        context: Box::into_raw(lzma) as *mut c_void,
+        max_compressed_size: lzma_max_compressed_size,
+        estimate_compressed_size: lzma_estimate_compressed_size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use core::ffi::CStr;
+
+    #[test]
+    fn test_create_and_free_estimator() {
+        unsafe {
+            let mut estimator = core::ptr::null_mut();
+            let result = dltlzma_new_size_estimator(3, &mut estimator);
+            assert!(result.is_success());
+            assert!(!estimator.is_null());
+            dltlzma_free_size_estimator(estimator);
+        }
+    }
+
+    #[test]
+    fn test_free_null_estimator() {
+        // Should not crash
+        unsafe {
+            dltlzma_free_size_estimator(core::ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_invalid_level_returns_error() {
+        for level in [-1, 10] {
+            unsafe {
+                let mut estimator = core::ptr::NonNull::dangling().as_ptr();
+                let result = dltlzma_new_size_estimator(level, &mut estimator);
+                assert_eq!(result.error_code, DltlzmaErrorCode::InvalidLevel);
+                assert!(estimator.is_null());
+            }
+        }
+    }
+
+    #[test]
+    fn test_sampled_estimator() {
+        unsafe {
+            let mut estimator = core::ptr::null_mut();
+            let result = dltlzma_new_sampled_size_estimator(1, 256, &mut estimator);
+            assert!(result.is_success());
+            assert!(!estimator.is_null());
+            dltlzma_free_size_estimator(estimator);
+
+            let mut estimator = core::ptr::NonNull::dangling().as_ptr();
+            let result = dltlzma_new_sampled_size_estimator(1, 255, &mut estimator);
+            assert_eq!(result.error_code, DltlzmaErrorCode::InvalidSampleSize);
+            assert!(estimator.is_null());
+        }
+    }
+
+    #[test]
+    fn test_null_output_pointer_returns_error() {
+        unsafe {
+            let result = dltlzma_new_size_estimator(1, core::ptr::null_mut());
+            assert_eq!(result.error_code, DltlzmaErrorCode::NullOutputPointer);
+        }
+    }
+
+    #[test]
+    fn test_error_message() {
+        unsafe {
+            let message = CStr::from_ptr(dltlzma_error_message(DltlzmaErrorCode::InvalidLevel));
+            assert_eq!(
+                message.to_str().unwrap(),
+                "Invalid compression level: Level must be between 0 and 9"
+            );
+        }
+    }
+
+    #[test]
+    fn test_estimator_functionality() {
+        unsafe {
+            let mut estimator = core::ptr::null_mut();
+            assert!(dltlzma_new_size_estimator(1, &mut estimator).is_success());
+            let estimator_ref = &*estimator;
+
+            // Test max_compressed_size
+            let test_data = [0u8; 256];
+            let mut max_size = 0;
+            let result = (estimator_ref.max_compressed_size)(
+                estimator_ref.context,
+                test_data.len(),
+                &mut max_size,
+            );
+            assert_eq!(result, 0); // Success
+            assert!(max_size >= test_data.len());
+
+            // Test estimate_compressed_size
+            let mut output = vec![0u8; max_size];
+            let mut estimated_size = 0;
+            let result = (estimator_ref.estimate_compressed_size)(
+                estimator_ref.context,
+                test_data.as_ptr(),
+                test_data.len(),
+                output.as_mut_ptr(),
+                output.len(),
+                &mut estimated_size,
+            );
+            assert_eq!(result, 0); // Success
+            assert!(estimated_size > 0);
+            assert!(estimated_size < test_data.len()); // Should be smaller for repetitive data
+
+            dltlzma_free_size_estimator(estimator);
+        }
+    }
+}
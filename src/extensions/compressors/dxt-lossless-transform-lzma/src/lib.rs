@@ -0,0 +1,445 @@
+#![doc = include_str!(concat!("../", core::env!("CARGO_PKG_README")))]
+#![no_std]
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+extern crate alloc;
+
+#[cfg(test)]
+pub mod test_prelude;
+
+#[cfg(feature = "c-exports")]
+pub mod c_api;
+
+use alloc::boxed::Box;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use liblzma_sys::*;
+use thiserror::Error;
+
+/// Lowest compression level (preset).
+pub const LZMA_MIN_LEVEL: i32 = 0;
+
+/// Default compression level (preset), as used by `xz`.
+pub const LZMA_DEFAULT_LEVEL: i32 = 6;
+
+/// Highest compression level (preset).
+pub const LZMA_MAX_LEVEL: i32 = 9;
+
+/// Suggested sample size for [`LzmaSizeEstimation::new_sampled`].
+pub const LZMA_DEFAULT_SAMPLE_SIZE: usize = 256 * 1024;
+
+/// Number of evenly spaced chunks a sample is made of.
+const SAMPLE_CHUNKS: usize = 16;
+
+/// Alignment of sampled chunks; a multiple of every BCn block size.
+const SAMPLE_ALIGNMENT: usize = 16;
+
+/// Smallest sample size accepted by [`LzmaSizeEstimation::new_sampled`].
+pub const LZMA_MIN_SAMPLE_SIZE: usize = SAMPLE_CHUNKS * SAMPLE_ALIGNMENT;
+
+/// Errors that can occur during LZMA size estimation.
+#[derive(Debug, Error)]
+pub enum LzmaError {
+    /// Invalid compression level
+    #[error("Invalid compression level: {0}")]
+    InvalidLevel(i32),
+
+    /// Sample size below [`LZMA_MIN_SAMPLE_SIZE`]
+    #[error("Invalid sample size: {0}")]
+    InvalidSampleSize(usize),
+
+    /// Input is too large to compute the maximum compressed size for
+    #[error("Input of {0} bytes is too large for LZMA")]
+    InputTooLarge(usize),
+
+    /// LZMA compression failed (e.g. the output buffer was too small)
+    #[error("LZMA compression failed with error code {0}")]
+    CompressionFailed(lzma_ret),
+}
+
+/// LZMA implementation of [`SizeEstimationOperations`].
+///
+/// This implementation compresses the data as an `.xz` stream (LZMA2, without a checksum)
+/// using liblzma to estimate its size. The compression level is the `xz` preset (0-9).
+///
+/// # Sampling
+///
+/// LZMA is slow, especially at high presets. An estimator created with [`Self::new_sampled`]
+/// compresses 16 evenly spaced chunks of the data (totalling the sample size) rather than all
+/// of it, and scales the result up to the full length. This is much faster on large textures,
+/// but less accurate, since matches between distant parts of the data are not seen.
+///
+/// # Encoder Reuse
+///
+/// The estimator keeps its LZMA encoder between calls to
+/// [`SizeEstimationOperations::estimate_compressed_size`], so its (large) match finder memory
+/// is only allocated once; it is freed when the estimator is dropped. Reuse the same estimator
+/// across textures to avoid reallocating it.
+///
+/// The estimator can be shared between threads. If it is used by multiple threads at once,
+/// the extra calls use a temporary encoder of their own.
+pub struct LzmaSizeEstimation {
+    compression_level: i32,
+    /// Number of bytes to compress from each input; `None` compresses all of it.
+    sample_size: Option<usize>,
+    /// Cached encoder; null if not yet created, or while taken by a call.
+    stream: AtomicPtr<lzma_stream>,
+}
+
+impl LzmaSizeEstimation {
+    /// Creates a new LZMA size estimator with the specified compression level.
+    ///
+    /// # Parameters
+    /// * `compression_level` - Compression level (0-9, the `xz` preset). Use the level you
+    ///   compress your files with.
+    pub fn new(compression_level: i32) -> Result<Self, LzmaError> {
+        // Validate compression level
+        if !(LZMA_MIN_LEVEL..=LZMA_MAX_LEVEL).contains(&compression_level) {
+            return Err(LzmaError::InvalidLevel(compression_level));
+        }
+
+        Ok(Self::with_settings(compression_level, None))
+    }
+
+    /// Creates a new LZMA size estimator which estimates from a sample of each input.
+    ///
+    /// See [Sampling](Self#sampling) for details.
+    ///
+    /// # Parameters
+    /// * `compression_level` - Compression level (0-9, the `xz` preset)
+    /// * `sample_size` - Number of bytes to compress from each input, at least
+    ///   [`LZMA_MIN_SAMPLE_SIZE`]; e.g. [`LZMA_DEFAULT_SAMPLE_SIZE`]. Inputs no larger than
+    ///   this are compressed in full.
+    pub fn new_sampled(compression_level: i32, sample_size: usize) -> Result<Self, LzmaError> {
+        if sample_size < LZMA_MIN_SAMPLE_SIZE {
+            return Err(LzmaError::InvalidSampleSize(sample_size));
+        }
+
+        let mut estimator = Self::new(compression_level)?;
+        estimator.sample_size = Some(sample_size);
+        Ok(estimator)
+    }
+
+    /// Creates a new LZMA size estimator with the default compression level (6).
+    pub fn new_default() -> Self {
+        Self::with_settings(LZMA_DEFAULT_LEVEL, None)
+    }
+
+    /// Returns the number of bytes compressed from each input, if sampling.
+    pub fn sample_size(&self) -> Option<usize> {
+        self.sample_size
+    }
+
+    const fn with_settings(compression_level: i32, sample_size: Option<usize>) -> Self {
+        Self {
+            compression_level,
+            sample_size,
+            stream: AtomicPtr::new(null_mut()),
+        }
+    }
+
+    /// Returns the length of each sampled chunk, if only part of an input of `len_bytes`
+    /// is compressed.
+    fn sample_chunk_len(&self, len_bytes: usize) -> Option<usize> {
+        match self.sample_size {
+            Some(sample_size) if len_bytes > sample_size => {
+                Some(sample_size / SAMPLE_CHUNKS / SAMPLE_ALIGNMENT * SAMPLE_ALIGNMENT)
+            }
+            _ => None,
+        }
+    }
+
+    /// Takes the cached encoder, or creates a new one if it is unavailable, and (re)initializes
+    /// it for a new stream.
+    fn take_stream(&self) -> Result<*mut lzma_stream, LzmaError> {
+        let mut stream = self.stream.swap(null_mut(), Ordering::Acquire);
+        if stream.is_null() {
+            // LZMA_STREAM_INIT; all fields zeroed
+            stream = Box::into_raw(Box::new(unsafe { core::mem::zeroed::<lzma_stream>() }));
+        }
+
+        // Reinitializing an existing encoder with the same preset reuses its memory.
+        let result =
+            unsafe { lzma_easy_encoder(stream, self.compression_level as u32, LZMA_CHECK_NONE) };
+        if result != LZMA_OK {
+            free_stream(stream);
+            return Err(LzmaError::CompressionFailed(result));
+        }
+
+        Ok(stream)
+    }
+
+    /// Caches an encoder taken with [`Self::take_stream`], freeing it if another is already cached.
+    fn return_stream(&self, stream: *mut lzma_stream) {
+        if self
+            .stream
+            .compare_exchange(null_mut(), stream, Ordering::Release, Ordering::Relaxed)
+            .is_err()
+        {
+            free_stream(stream);
+        }
+    }
+}
+
+impl Drop for LzmaSizeEstimation {
+    fn drop(&mut self) {
+        let stream = *self.stream.get_mut();
+        if !stream.is_null() {
+            free_stream(stream);
+        }
+    }
+}
+
+impl Default for LzmaSizeEstimation {
+    fn default() -> Self {
+        Self::new_default()
+    }
+}
+
+impl SizeEstimationOperations for LzmaSizeEstimation {
+    type Error = LzmaError;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        if len_bytes == 0 {
+            return Ok(0);
+        }
+
+        // When sampling, only the sample is compressed
+        let compressed_len = self
+            .sample_chunk_len(len_bytes)
+            .map_or(len_bytes, |chunk_len| chunk_len * SAMPLE_CHUNKS);
+
+        // Calculate maximum compressed size using liblzma bounds; 0 if the input is too large
+        match unsafe { lzma_stream_buffer_bound(compressed_len) } {
+            0 => Err(LzmaError::InputTooLarge(len_bytes)),
+            max_size => Ok(max_size),
+        }
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        input_ptr: *const u8,
+        len_bytes: usize,
+        output_ptr: *mut u8,
+        output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        if input_ptr.is_null() {
+            return Ok(0);
+        }
+
+        if len_bytes == 0 {
+            return Ok(0);
+        }
+
+        // Output buffer is guaranteed to be non-null and sufficient size
+        let stream = self.take_stream()?;
+        (*stream).next_out = output_ptr;
+        (*stream).avail_out = output_len;
+
+        let result = match self.sample_chunk_len(len_bytes) {
+            None => compress(stream, &[(input_ptr, len_bytes)]),
+            Some(chunk_len) => {
+                // Evenly spaced chunks, from the start to the end of the input
+                let stride = (len_bytes - chunk_len) / (SAMPLE_CHUNKS - 1) / SAMPLE_ALIGNMENT
+                    * SAMPLE_ALIGNMENT;
+                let chunks: [(*const u8, usize); SAMPLE_CHUNKS] = core::array::from_fn(|x| {
+                    let offset = if x == SAMPLE_CHUNKS - 1 {
+                        (len_bytes - chunk_len) / SAMPLE_ALIGNMENT * SAMPLE_ALIGNMENT
+                    } else {
+                        x * stride
+                    };
+                    (input_ptr.add(offset), chunk_len)
+                });
+
+                // Scale the compressed sample up to the full input
+                let sampled_len = (chunk_len * SAMPLE_CHUNKS) as u128;
+                compress(stream, &chunks).map(|compressed_size| {
+                    (compressed_size as u128 * len_bytes as u128 / sampled_len) as usize
+                })
+            }
+        };
+
+        self.return_stream(stream);
+        result
+    }
+}
+
+/// Compresses the given `(pointer, length)` chunks into a single stream, using the output
+/// buffer already set on the encoder. Returns the compressed size.
+///
+/// # Safety
+///
+/// `stream` must be an initialized encoder, and the chunks must be valid for reads.
+unsafe fn compress(
+    stream: *mut lzma_stream,
+    chunks: &[(*const u8, usize)],
+) -> Result<usize, LzmaError> {
+    for &(chunk_ptr, chunk_len) in chunks {
+        (*stream).next_in = chunk_ptr;
+        (*stream).avail_in = chunk_len;
+        // Fails with LZMA_BUF_ERROR if no progress can be made (output buffer full)
+        loop {
+            match lzma_code(stream, LZMA_RUN) {
+                LZMA_OK if (*stream).avail_in > 0 => continue,
+                LZMA_OK => break,
+                result => return Err(LzmaError::CompressionFailed(result)),
+            }
+        }
+    }
+
+    loop {
+        match lzma_code(stream, LZMA_FINISH) {
+            LZMA_OK => continue,
+            LZMA_STREAM_END => return Ok((*stream).total_out as usize),
+            result => return Err(LzmaError::CompressionFailed(result)),
+        }
+    }
+}
+
+/// Frees an encoder created by [`LzmaSizeEstimation::take_stream`].
+fn free_stream(stream: *mut lzma_stream) {
+    unsafe {
+        lzma_end(stream);
+        drop(Box::from_raw(stream));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+
+    /// Pseudo-random data with short runs, so it compresses, but not trivially.
+    /// Has no long-range repetition, which sampling would not see.
+    fn test_data(len: usize) -> Vec<u8> {
+        let mut state = 0x2545_F491u32;
+        let mut previous = 0u8;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                if state.is_multiple_of(4) {
+                    previous = (state >> 8) as u8;
+                }
+                previous
+            })
+            .collect()
+    }
+
+    fn estimate(estimator: &LzmaSizeEstimation, data: &[u8]) -> usize {
+        let max_size = estimator.max_compressed_size(data.len()).unwrap();
+        let mut output_buffer = vec![0u8; max_size];
+        unsafe {
+            estimator
+                .estimate_compressed_size(
+                    data.as_ptr(),
+                    data.len(),
+                    output_buffer.as_mut_ptr(),
+                    max_size,
+                )
+                .unwrap()
+        }
+    }
+
+    #[test]
+    fn estimate_empty_data() {
+        let estimator = LzmaSizeEstimation::default();
+        let result = unsafe {
+            estimator.estimate_compressed_size(core::ptr::null(), 0, core::ptr::null_mut(), 0)
+        };
+        assert_eq!(result.unwrap(), 0);
+        assert_eq!(estimator.max_compressed_size(0).unwrap(), 0);
+    }
+
+    #[rstest::rstest]
+    #[case::fastest(LZMA_MIN_LEVEL)]
+    #[case::default(LZMA_DEFAULT_LEVEL)]
+    #[case::best(LZMA_MAX_LEVEL)]
+    fn estimate_simple_data(#[case] level: i32) {
+        let estimator = LzmaSizeEstimation::new(level).unwrap();
+        let data = b"Hello, world! This is a test string for compression. test test test test test test test test test test test test!!";
+
+        let size = estimate(&estimator, data);
+        assert!(size > 0);
+        assert!(size < data.len()); // Should be smaller than input for this test case
+    }
+
+    #[test]
+    fn reused_encoder_gives_same_size() {
+        let estimator = LzmaSizeEstimation::new(1).unwrap();
+        let first = estimate(&estimator, &test_data(10000));
+        let _ = estimate(&estimator, &test_data(3000));
+        assert_eq!(estimate(&estimator, &test_data(10000)), first);
+        assert_eq!(
+            estimate(&LzmaSizeEstimation::new(1).unwrap(), &test_data(10000)),
+            first
+        );
+    }
+
+    #[test]
+    fn sampled_estimate_is_close_to_full_estimate() {
+        let data = test_data(1024 * 1024);
+        let full = estimate(&LzmaSizeEstimation::new(1).unwrap(), &data);
+        let sampled = LzmaSizeEstimation::new_sampled(1, 64 * 1024).unwrap();
+        assert!(
+            sampled.max_compressed_size(data.len()).unwrap()
+                < LzmaSizeEstimation::new(1)
+                    .unwrap()
+                    .max_compressed_size(data.len())
+                    .unwrap()
+        );
+
+        let estimated = estimate(&sampled, &data);
+        assert!(
+            estimated.abs_diff(full) < full / 10,
+            "sampled {estimated} vs full {full}"
+        );
+    }
+
+    #[test]
+    fn sampled_estimate_of_small_input_matches_full_estimate() {
+        let data = test_data(4096);
+        let sampled = LzmaSizeEstimation::new_sampled(1, LZMA_DEFAULT_SAMPLE_SIZE).unwrap();
+        assert_eq!(
+            estimate(&sampled, &data),
+            estimate(&LzmaSizeEstimation::new(1).unwrap(), &data)
+        );
+    }
+
+    #[test]
+    fn output_buffer_too_small_returns_error() {
+        let estimator = LzmaSizeEstimation::new(0).unwrap();
+        let data = [0x12u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+        let mut output_buffer = [0u8; 8];
+        let result = unsafe {
+            estimator.estimate_compressed_size(
+                data.as_ptr(),
+                data.len(),
+                output_buffer.as_mut_ptr(),
+                output_buffer.len(),
+            )
+        };
+        assert!(matches!(result, Err(LzmaError::CompressionFailed(_))));
+    }
+
+    #[test]
+    fn test_invalid_compression_level() {
+        for level in [-1, 10] {
+            let result = LzmaSizeEstimation::new(level);
+            assert!(matches!(result, Err(LzmaError::InvalidLevel(x)) if x == level));
+        }
+    }
+
+    #[test]
+    fn test_invalid_sample_size() {
+        let result = LzmaSizeEstimation::new_sampled(1, LZMA_MIN_SAMPLE_SIZE - 1);
+        assert!(
+            matches!(result, Err(LzmaError::InvalidSampleSize(x)) if x == LZMA_MIN_SAMPLE_SIZE - 1)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+use dxt_lossless_transform_bc1_api::Bc1AutoTransformBuilder;
+use dxt_lossless_transform_zstd::{ZStandardError, ZStandardSizeEstimation};
+use std::hint::black_box;
+
+/// Size of the BC1 data used by the benchmarks (a 1024x1024 texture).
+const BC1_SIZE: usize = 524288;
+
+/// Estimator which creates a new compression context for every estimate,
+/// i.e. the behaviour before contexts were reused.
+struct FreshContextEstimation(i32);
+
+impl SizeEstimationOperations for FreshContextEstimation {
+    type Error = ZStandardError;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        ZStandardSizeEstimation::new(self.0)?.max_compressed_size(len_bytes)
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        input_ptr: *const u8,
+        len_bytes: usize,
+        output_ptr: *mut u8,
+        output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        ZStandardSizeEstimation::new(self.0)?
+            .estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len)
+    }
+}
+
+/// Creates BC1 blocks with endpoints from a small palette and pseudo-random indices,
+/// so the data compresses somewhat like a real texture.
+fn create_bc1_data(len: usize) -> Vec<u8> {
+    const PALETTE: [u16; 8] = [
+        0xF800, 0x07E0, 0x001F, 0xFFFF, 0x8410, 0xFC00, 0x03FF, 0x0000,
+    ];
+
+    let mut state = 0x2545F491u32;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+
+    let mut data = Vec::with_capacity(len);
+    while data.len() < len {
+        let colours = next();
+        data.extend_from_slice(&PALETTE[(colours & 7) as usize].to_le_bytes());
+        data.extend_from_slice(&PALETTE[(colours >> 3 & 7) as usize].to_le_bytes());
+        data.extend_from_slice(&(next() & 0x5555_FFFF).to_le_bytes());
+    }
+    data
+}
+
+fn bench_estimate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ZStandard Estimate");
+
+    // The auto transform estimates only the colour endpoints: half of the data.
+    // Context creation matters most for small textures (and mipmaps).
+    for len in [BC1_SIZE / 256, BC1_SIZE / 2] {
+        let data = create_bc1_data(len);
+        group.throughput(Throughput::Bytes(data.len() as u64));
+
+        for level in [1, 3] {
+            let reused = ZStandardSizeEstimation::new(level).unwrap();
+            let max_size = reused.max_compressed_size(data.len()).unwrap();
+            let mut output = vec![0u8; max_size];
+            let parameter = format!("level {level}, {len} bytes");
+
+            group.bench_function(BenchmarkId::new("fresh_context", &parameter), |b| {
+                let estimator = FreshContextEstimation(level);
+                b.iter(|| unsafe {
+                    black_box(estimator.estimate_compressed_size(
+                        data.as_ptr(),
+                        data.len(),
+                        output.as_mut_ptr(),
+                        max_size,
+                    ))
+                })
+            });
+
+            group.bench_function(BenchmarkId::new("reused_context", &parameter), |b| {
+                b.iter(|| unsafe {
+                    black_box(reused.estimate_compressed_size(
+                        data.as_ptr(),
+                        data.len(),
+                        output.as_mut_ptr(),
+                        max_size,
+                    ))
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_auto_transform(c: &mut Criterion) {
+    let mut group = c.benchmark_group("BC1 Auto Transform (ZStandard)");
+    let data = create_bc1_data(BC1_SIZE);
+    let mut output = vec![0u8; data.len()];
+    group.throughput(Throughput::Bytes(data.len() as u64));
+
+    for level in [1, 3] {
+        group.bench_with_input(BenchmarkId::new("fresh_context", level), &level, |b, _| {
+            let builder = Bc1AutoTransformBuilder::new(FreshContextEstimation(level));
+            b.iter(|| black_box(builder.transform(&data, &mut output).unwrap()))
+        });
+
+        group.bench_with_input(BenchmarkId::new("reused_context", level), &level, |b, _| {
+            let builder =
+                Bc1AutoTransformBuilder::new(ZStandardSizeEstimation::new(level).unwrap());
+            b.iter(|| black_box(builder.transform(&data, &mut output).unwrap()))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_estimate, bench_auto_transform);
+criterion_main!(benches);
@@ -0,0 +1,356 @@
+//! Builder for ZStandard size estimators with custom compression parameters.
+
+use crate::{zstd_setcommoncompressparams, ZStandardError, ZStandardSizeEstimation};
+use alloc::boxed::Box;
+use core::ffi::c_void;
+use zstd_sys::ZSTD_ParamSwitch_e::*;
+use zstd_sys::ZSTD_cParameter::*;
+use zstd_sys::*;
+
+/// ZStandard match finding strategy, from fastest to strongest.
+///
+/// Corresponds to `ZSTD_strategy` in the ZStandard library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZStandardStrategy {
+    /// `ZSTD_fast`
+    Fast,
+    /// `ZSTD_dfast`
+    DFast,
+    /// `ZSTD_greedy`
+    Greedy,
+    /// `ZSTD_lazy`
+    Lazy,
+    /// `ZSTD_lazy2`
+    Lazy2,
+    /// `ZSTD_btlazy2`
+    BtLazy2,
+    /// `ZSTD_btopt`
+    BtOpt,
+    /// `ZSTD_btultra`
+    BtUltra,
+    /// `ZSTD_btultra2`
+    BtUltra2,
+}
+
+impl ZStandardStrategy {
+    fn to_zstd(self) -> ZSTD_strategy {
+        match self {
+            Self::Fast => ZSTD_strategy::ZSTD_fast,
+            Self::DFast => ZSTD_strategy::ZSTD_dfast,
+            Self::Greedy => ZSTD_strategy::ZSTD_greedy,
+            Self::Lazy => ZSTD_strategy::ZSTD_lazy,
+            Self::Lazy2 => ZSTD_strategy::ZSTD_lazy2,
+            Self::BtLazy2 => ZSTD_strategy::ZSTD_btlazy2,
+            Self::BtOpt => ZSTD_strategy::ZSTD_btopt,
+            Self::BtUltra => ZSTD_strategy::ZSTD_btultra,
+            Self::BtUltra2 => ZSTD_strategy::ZSTD_btultra2,
+        }
+    }
+}
+
+/// Compression parameters of a [`ZStandardSizeEstimation`].
+///
+/// Parameters left as [`None`] use the defaults for the compression level.
+#[derive(Debug, Clone)]
+pub(crate) struct ZStandardParameters {
+    compression_level: i32,
+    window_log: Option<u32>,
+    strategy: Option<ZStandardStrategy>,
+    long_distance_matching: Option<bool>,
+    target_length: Option<u32>,
+    dictionary: Option<Box<[u8]>>,
+}
+
+impl ZStandardParameters {
+    pub(crate) const fn new(compression_level: i32) -> Self {
+        Self {
+            compression_level,
+            window_log: None,
+            strategy: None,
+            long_distance_matching: None,
+            target_length: None,
+            dictionary: None,
+        }
+    }
+
+    /// Values of the parameters which were set, as passed to `ZSTD_CCtx_setParameter`.
+    fn values(&self) -> [(ZSTD_cParameter, &'static str, Option<i64>); 4] {
+        [
+            (
+                ZSTD_c_windowLog,
+                "windowLog",
+                self.window_log.map(i64::from),
+            ),
+            (
+                ZSTD_c_strategy,
+                "strategy",
+                self.strategy.map(|x| x.to_zstd() as i64),
+            ),
+            (
+                ZSTD_c_enableLongDistanceMatching,
+                "enableLongDistanceMatching",
+                self.long_distance_matching.map(|x| match x {
+                    true => ZSTD_ps_enable as i64,
+                    false => ZSTD_ps_disable as i64,
+                }),
+            ),
+            (
+                ZSTD_c_targetLength,
+                "targetLength",
+                self.target_length.map(i64::from),
+            ),
+        ]
+    }
+
+    /// Checks that the parameters are within the bounds supported by the ZStandard library.
+    pub(crate) fn validate(&self) -> Result<(), ZStandardError> {
+        if !(1..=22).contains(&self.compression_level) {
+            return Err(ZStandardError::InvalidLevel(self.compression_level));
+        }
+
+        for (parameter, name, value) in self.values() {
+            let Some(value) = value else {
+                continue;
+            };
+
+            let bounds = unsafe { ZSTD_cParam_getBounds(parameter) };
+            if unsafe { ZSTD_isError(bounds.error) } != 0
+                || value < i64::from(bounds.lowerBound)
+                || value > i64::from(bounds.upperBound)
+            {
+                return Err(ZStandardError::InvalidParameter {
+                    parameter: name,
+                    value,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the parameters (and dictionary) on a compression context.
+    ///
+    /// The parameters must have been [validated](Self::validate).
+    pub(crate) fn apply(&self, cctx: *mut ZSTD_CCtx) -> Result<(), ZStandardError> {
+        zstd_setcommoncompressparams(cctx, Some(self.compression_level));
+
+        for (parameter, _, value) in self.values() {
+            if let Some(value) = value {
+                // Within bounds (so i32), as validated on creation
+                check(unsafe { ZSTD_CCtx_setParameter(cctx, parameter, value as i32) })?;
+            }
+        }
+
+        if let Some(dictionary) = &self.dictionary {
+            // The dictionary stays loaded for all future compressions with this context
+            check(unsafe {
+                ZSTD_CCtx_loadDictionary(
+                    cctx,
+                    dictionary.as_ptr() as *const c_void,
+                    dictionary.len(),
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a ZStandard function result into a [`Result`].
+fn check(result: usize) -> Result<(), ZStandardError> {
+    if unsafe { ZSTD_isError(result) } == 0 {
+        return Ok(());
+    }
+
+    Err(ZStandardError::ZStandardInternal(unsafe {
+        ZSTD_getErrorCode(result)
+    }))
+}
+
+/// Builder for a [`ZStandardSizeEstimation`] with custom compression parameters.
+///
+/// The estimator ranks transforms best when it compresses like the compressor the data will be
+/// shipped with. If that compressor uses non-default parameters (e.g. a large window with long
+/// distance matching), set the same parameters here.
+///
+/// Parameters which are not set use the defaults for the compression level.
+///
+/// # Examples
+///
+/// ```
+/// use dxt_lossless_transform_zstd::{ZStandardSizeEstimationBuilder, ZStandardStrategy};
+///
+/// let estimator = ZStandardSizeEstimationBuilder::new(19)
+///     .window_log(27)
+///     .long_distance_matching(true)
+///     .strategy(ZStandardStrategy::BtUltra2)
+///     .target_length(256)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZStandardSizeEstimationBuilder {
+    parameters: ZStandardParameters,
+}
+
+impl ZStandardSizeEstimationBuilder {
+    /// Creates a builder for an estimator with the specified compression level.
+    ///
+    /// # Parameters
+    /// * `compression_level` - Compression level (1-22, where 1 is fastest and 22 is best compression)
+    pub fn new(compression_level: i32) -> Self {
+        Self {
+            parameters: ZStandardParameters::new(compression_level),
+        }
+    }
+
+    /// Sets the maximum back-reference distance, as a power of 2 (`ZSTD_c_windowLog`).
+    pub fn window_log(mut self, window_log: u32) -> Self {
+        self.parameters.window_log = Some(window_log);
+        self
+    }
+
+    /// Sets the match finding strategy (`ZSTD_c_strategy`).
+    pub fn strategy(mut self, strategy: ZStandardStrategy) -> Self {
+        self.parameters.strategy = Some(strategy);
+        self
+    }
+
+    /// Enables or disables long distance matching (`ZSTD_c_enableLongDistanceMatching`).
+    ///
+    /// Usually paired with a large [`window_log`](Self::window_log).
+    pub fn long_distance_matching(mut self, enabled: bool) -> Self {
+        self.parameters.long_distance_matching = Some(enabled);
+        self
+    }
+
+    /// Sets the strategy dependent search target length (`ZSTD_c_targetLength`).
+    pub fn target_length(mut self, target_length: u32) -> Self {
+        self.parameters.target_length = Some(target_length);
+        self
+    }
+
+    /// Sets a dictionary to compress with, e.g. one trained with `zstd --train`.
+    ///
+    /// Data which is not a ZStandard dictionary is used as raw content.
+    /// The dictionary is loaded once per compression context and reused between estimates.
+    pub fn dictionary(mut self, dictionary: impl Into<Box<[u8]>>) -> Self {
+        self.parameters.dictionary = Some(dictionary.into());
+        self
+    }
+
+    /// Creates the estimator.
+    ///
+    /// # Errors
+    /// - [`ZStandardError::InvalidLevel`] if the compression level is not within 1-22
+    /// - [`ZStandardError::InvalidParameter`] if a parameter is outside the range supported
+    ///   by the ZStandard library
+    pub fn build(self) -> Result<ZStandardSizeEstimation, ZStandardError> {
+        self.parameters.validate()?;
+        Ok(ZStandardSizeEstimation::with_parameters(self.parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+
+    fn estimate(estimator: &ZStandardSizeEstimation, data: &[u8]) -> usize {
+        let max_size = estimator.max_compressed_size(data.len()).unwrap();
+        let mut output_buffer = vec![0u8; max_size];
+        unsafe {
+            estimator
+                .estimate_compressed_size(
+                    data.as_ptr(),
+                    data.len(),
+                    output_buffer.as_mut_ptr(),
+                    max_size,
+                )
+                .unwrap()
+        }
+    }
+
+    fn create_test_data() -> Vec<u8> {
+        (0..65536u32)
+            .map(|x| ((x % 251) ^ (x / 509)) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn default_parameters_match_level_only_estimator() {
+        let data = create_test_data();
+        let built = ZStandardSizeEstimationBuilder::new(3).build().unwrap();
+        assert_eq!(
+            estimate(&built, &data),
+            estimate(&ZStandardSizeEstimation::new_default(), &data)
+        );
+    }
+
+    #[test]
+    fn all_parameters_can_be_used() {
+        let data = create_test_data();
+        let estimator = ZStandardSizeEstimationBuilder::new(9)
+            .window_log(24)
+            .strategy(ZStandardStrategy::BtOpt)
+            .long_distance_matching(true)
+            .target_length(64)
+            .build()
+            .unwrap();
+        assert!(estimate(&estimator, &data) > 0);
+    }
+
+    #[rstest::rstest]
+    #[case::strategy_fast(ZStandardStrategy::Fast)]
+    #[case::strategy_btultra2(ZStandardStrategy::BtUltra2)]
+    fn strategy_is_within_bounds(#[case] strategy: ZStandardStrategy) {
+        assert!(ZStandardSizeEstimationBuilder::new(1)
+            .strategy(strategy)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn dictionary_is_used() {
+        let data = create_test_data();
+        let (dictionary, sample) = data.split_at(data.len() / 2);
+
+        let without = ZStandardSizeEstimationBuilder::new(3).build().unwrap();
+        let with = ZStandardSizeEstimationBuilder::new(3)
+            .dictionary(dictionary)
+            .build()
+            .unwrap();
+
+        // The sample repeats content from the dictionary, so compresses better with it.
+        // Estimate twice, to cover the dictionary persisting in the reused context.
+        let expected = estimate(&with, sample);
+        assert!(expected < estimate(&without, sample));
+        assert_eq!(estimate(&with, sample), expected);
+    }
+
+    #[rstest::rstest]
+    #[case::window_log_too_small(ZStandardSizeEstimationBuilder::new(3).window_log(1), "windowLog")]
+    #[case::window_log_too_large(ZStandardSizeEstimationBuilder::new(3).window_log(64), "windowLog")]
+    #[case::target_length_too_large(
+        ZStandardSizeEstimationBuilder::new(3).target_length(u32::MAX),
+        "targetLength"
+    )]
+    fn invalid_parameters_are_rejected(
+        #[case] builder: ZStandardSizeEstimationBuilder,
+        #[case] expected: &str,
+    ) {
+        assert!(matches!(
+            builder.build(),
+            Err(ZStandardError::InvalidParameter { parameter, .. }) if parameter == expected
+        ));
+    }
+
+    #[test]
+    fn invalid_level_is_rejected() {
+        assert!(matches!(
+            ZStandardSizeEstimationBuilder::new(23).build(),
+            Err(ZStandardError::InvalidLevel(23))
+        ));
+    }
+}
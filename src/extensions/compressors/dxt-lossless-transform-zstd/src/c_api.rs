@@ -0,0 +1,335 @@
+//! C API for ZStandard Size Estimation
+//!
+//! This module provides a C-compatible interface for the ZStandard size estimation functionality.
+//! It exposes the core [`ZStandardSizeEstimation`] type through the [`DltSizeEstimator`]
+//! interface from `dxt-lossless-transform-api-common`.
+//!
+//! ## Usage Pattern
+//!
+//! 1. Create an estimator instance using [`dltzstd_new_size_estimator`]
+//! 2. Use the estimator with any API that accepts a [`DltSizeEstimator`]
+//! 3. Free the estimator when done using [`dltzstd_free_size_estimator`]
+//!
+//! ## Important Notes
+//!
+//! Unlike the LTU estimator, this estimator performs actual compression. It is slower,
+//! but its estimates match the real compressed size, making it the better choice when
+//! compressing at higher ZStandard levels.
+//!
+//! ## Thread Safety
+//!
+//! The ZStandard estimator is thread-safe and can be used from multiple threads simultaneously.
+//! It keeps one compression context between estimates, which is freed with the estimator;
+//! calls made while that context is in use by another thread use a temporary one.
+//!
+//! # Required Headers
+//!
+//! When using this API from C/C++, you must include the common API header first:
+//! ```c
+//! #include "dxt-lossless-transform-api-common.h"
+//! #include "dxt-lossless-transform-zstd.h"
+//! ```
+//!
+//! # Usage with Transform APIs (BC1, BC2, BC3, BC7, etc.)
+//!
+//! The ZStandard estimator implements the [`DltSizeEstimator`] interface and can be used
+//! directly with BCX automatic transform builders such as
+//! [`dltbc1_new_AutoTransformBuilder`].
+//!
+//! ```c
+//! DltSizeEstimator* estimator = NULL;
+//! DltzstdResult result = dltzstd_new_size_estimator(9, &estimator);
+//! if (result.error_code != DLTZSTD_SUCCESS) {
+//!     printf("Failed to create estimator: %s\n", dltzstd_error_message(result.error_code));
+//!     return;
+//! }
+//!
+//! Dltbc1AutoTransformBuilder* builder = dltbc1_new_AutoTransformBuilder(estimator);
+//! // Transform with the builder...
+//!
+//! dltbc1_free_AutoTransformBuilder(builder);
+//! dltzstd_free_size_estimator(estimator);
+//! ```
+//!
+//! # Available Functions
+//!
+//! - [`dltzstd_new_size_estimator`] - Create a new estimator
+//! - [`dltzstd_free_size_estimator`] - Free an estimator
+//! - [`dltzstd_error_message`] - Describe an error code
+//!
+//! [`dltbc1_new_AutoTransformBuilder`]: https://docs.rs/dxt-lossless-transform-bc1-api/latest/dxt_lossless_transform_bc1_api/c_api/transform/auto_transform_builder/fn.dltbc1_new_AutoTransformBuilder.html
+
+use crate::ZStandardSizeEstimation;
+use alloc::boxed::Box;
+use core::ffi::{c_char, c_void};
+use dxt_lossless_transform_api_common::c_api::size_estimation::DltSizeEstimator;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+
+/// C-compatible error codes for ZStandard estimator operations.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DltzstdErrorCode {
+    /// Operation succeeded
+    Success = 0,
+    /// Null pointer provided for estimator output parameter
+    NullOutputPointer = 1,
+    /// Invalid compression level: Level must be between 1 and 22
+    InvalidLevel = 2,
+}
+
+/// C-compatible Result type for ZStandard estimator operations.
+#[repr(C)]
+pub struct DltzstdResult {
+    /// Error code (0 = success, non-zero = error)
+    pub error_code: DltzstdErrorCode,
+}
+
+impl DltzstdResult {
+    /// Create a success result
+    pub const fn success() -> Self {
+        Self {
+            error_code: DltzstdErrorCode::Success,
+        }
+    }
+
+    /// Create an error result from an error code
+    pub const fn from_error_code(error_code: DltzstdErrorCode) -> Self {
+        Self { error_code }
+    }
+
+    /// Check if the result is successful
+    pub fn is_success(&self) -> bool {
+        matches!(self.error_code, DltzstdErrorCode::Success)
+    }
+}
+
+/// Create a new ZStandard size estimator.
+///
+/// The estimator compresses data at the given level to determine its compressed size.
+///
+/// # Parameters
+/// * `level` - ZStandard compression level (1-22). Use the level you compress your files with.
+/// * `out_estimator` - Output pointer where the created estimator will be written.
+///   On success, this will be set to a pointer that must be freed with
+///   [`dltzstd_free_size_estimator`]. On error, this will be set to null.
+///
+/// # Returns
+///
+/// A [`DltzstdResult`] indicating success, or [`DltzstdErrorCode::InvalidLevel`] if
+/// `level` is out of range.
+///
+/// # Safety
+///
+/// `out_estimator` must be a valid pointer to write the result.
+///
+/// # Remarks
+/// This function corresponds to [`ZStandardSizeEstimation::new`] in the Rust API.
+#[no_mangle]
+pub unsafe extern "C" fn dltzstd_new_size_estimator(
+    level: i32,
+    out_estimator: *mut *mut DltSizeEstimator,
+) -> DltzstdResult {
+    if out_estimator.is_null() {
+        return DltzstdResult::from_error_code(DltzstdErrorCode::NullOutputPointer);
+    }
+
+    match ZStandardSizeEstimation::new(level) {
+        Ok(zstd) => {
+            let estimator = create_c_size_estimator(Box::new(zstd));
+            unsafe { *out_estimator = Box::into_raw(Box::new(estimator)) };
+            DltzstdResult::success()
+        }
+        // Level validation is the only way creating the estimator can fail
+        Err(_) => {
+            unsafe { *out_estimator = core::ptr::null_mut() };
+            DltzstdResult::from_error_code(DltzstdErrorCode::InvalidLevel)
+        }
+    }
+}
+
+/// Free a ZStandard size estimator created by [`dltzstd_new_size_estimator`].
+///
+/// # Parameters
+/// * `estimator` - Pointer to the estimator to free (can be null)
+///
+/// # Safety
+/// The estimator pointer must have been returned by [`dltzstd_new_size_estimator`],
+/// or be null. After calling this function, the pointer becomes invalid.
+#[no_mangle]
+pub unsafe extern "C" fn dltzstd_free_size_estimator(estimator: *mut DltSizeEstimator) {
+    if !estimator.is_null() {
+        // First free the boxed ZStandard implementation
+        let estimator_ref = unsafe { &*estimator };
+        if !estimator_ref.context.is_null() {
+            let _ = unsafe { Box::from_raw(estimator_ref.context as *mut ZStandardSizeEstimation) };
+        }
+        // Then free the DltSizeEstimator itself
+        let _ = unsafe { Box::from_raw(estimator) };
+    }
+}
+
+/// Get a null-terminated string description of the error code.
+///
+/// The returned string is a static string literal that does not need to be freed.
+///
+/// # Safety
+/// This function is safe to call with any error code value.
+#[no_mangle]
+pub unsafe extern "C" fn dltzstd_error_message(error_code: DltzstdErrorCode) -> *const c_char {
+    match error_code {
+        DltzstdErrorCode::Success => c"Success".as_ptr(),
+        DltzstdErrorCode::NullOutputPointer => {
+            c"Null pointer provided for estimator output parameter".as_ptr()
+        }
+        DltzstdErrorCode::InvalidLevel => {
+            c"Invalid compression level: Level must be between 1 and 22".as_ptr()
+        }
+    }
+}
+
+/// C-compatible callback for [`DltSizeEstimator::max_compressed_size`].
+unsafe extern "C" fn zstd_max_compressed_size(
+    context: *mut c_void,
+    len_bytes: usize,
+    out_size: *mut usize,
+) -> u32 {
+    if context.is_null() || out_size.is_null() {
+        return 1; // Error: null pointer
+    }
+
+    let zstd = unsafe { &*(context as *const ZStandardSizeEstimation) };
+
+    match zstd.max_compressed_size(len_bytes) {
+        Ok(size) => {
+            unsafe { *out_size = size };
+            0 // Success
+        }
+        Err(_) => 2, // Error: max_compressed_size failed
+    }
+}
+
+/// C-compatible callback for [`DltSizeEstimator::estimate_compressed_size`].
+unsafe extern "C" fn zstd_estimate_compressed_size(
+    context: *mut c_void,
+    input_ptr: *const u8,
+    len_bytes: usize,
+    output_ptr: *mut u8,
+    output_len: usize,
+    out_size: *mut usize,
+) -> u32 {
+    if context.is_null() || out_size.is_null() {
+        return 1; // Error: null pointer
+    }
+
+    let zstd = unsafe { &*(context as *const ZStandardSizeEstimation) };
+    match unsafe { zstd.estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len) } {
+        Ok(size) => {
+            unsafe { *out_size = size };
+            0 // Success
+        }
+        Err(_) => 3, // Error: estimate_compressed_size failed
+    }
+}
+
+/// Creates a C-compatible [`DltSizeEstimator`] from a ZStandard implementation.
+fn create_c_size_estimator(zstd: Box<ZStandardSizeEstimation>) -> DltSizeEstimator {
+    DltSizeEstimator {
+        context: Box::into_raw(zstd) as *mut c_void,
+        max_compressed_size: zstd_max_compressed_size,
+        estimate_compressed_size: zstd_estimate_compressed_size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use core::ffi::CStr;
+
+    #[test]
+    fn test_create_and_free_estimator() {
+        unsafe {
+            let mut estimator = core::ptr::null_mut();
+            let result = dltzstd_new_size_estimator(3, &mut estimator);
+            assert!(result.is_success());
+            assert!(!estimator.is_null());
+            dltzstd_free_size_estimator(estimator);
+        }
+    }
+
+    #[test]
+    fn test_free_null_estimator() {
+        // Should not crash
+        unsafe {
+            dltzstd_free_size_estimator(core::ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_invalid_level_returns_error() {
+        for level in [-1, 0, 23] {
+            unsafe {
+                let mut estimator = core::ptr::NonNull::dangling().as_ptr();
+                let result = dltzstd_new_size_estimator(level, &mut estimator);
+                assert_eq!(result.error_code, DltzstdErrorCode::InvalidLevel);
+                assert!(estimator.is_null());
+            }
+        }
+    }
+
+    #[test]
+    fn test_null_output_pointer_returns_error() {
+        unsafe {
+            let result = dltzstd_new_size_estimator(1, core::ptr::null_mut());
+            assert_eq!(result.error_code, DltzstdErrorCode::NullOutputPointer);
+        }
+    }
+
+    #[test]
+    fn test_error_message() {
+        unsafe {
+            let message = CStr::from_ptr(dltzstd_error_message(DltzstdErrorCode::InvalidLevel));
+            assert_eq!(
+                message.to_str().unwrap(),
+                "Invalid compression level: Level must be between 1 and 22"
+            );
+        }
+    }
+
+    #[test]
+    fn test_estimator_functionality() {
+        unsafe {
+            let mut estimator = core::ptr::null_mut();
+            assert!(dltzstd_new_size_estimator(1, &mut estimator).is_success());
+            let estimator_ref = &*estimator;
+
+            // Test max_compressed_size
+            let test_data = [0u8; 256];
+            let mut max_size = 0;
+            let result = (estimator_ref.max_compressed_size)(
+                estimator_ref.context,
+                test_data.len(),
+                &mut max_size,
+            );
+            assert_eq!(result, 0); // Success
+            assert!(max_size >= test_data.len());
+
+            // Test estimate_compressed_size
+            let mut output = vec![0u8; max_size];
+            let mut estimated_size = 0;
+            let result = (estimator_ref.estimate_compressed_size)(
+                estimator_ref.context,
+                test_data.as_ptr(),
+                test_data.len(),
+                output.as_mut_ptr(),
+                output.len(),
+                &mut estimated_size,
+            );
+            assert_eq!(result, 0); // Success
+            assert!(estimated_size > 0);
+            assert!(estimated_size < test_data.len()); // Should be smaller for repetitive data
+
+            dltzstd_free_size_estimator(estimator);
+        }
+    }
+}
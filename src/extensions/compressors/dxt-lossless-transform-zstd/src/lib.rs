@@ -10,7 +10,16 @@ extern crate alloc;
 #[cfg(test)]
 pub mod test_prelude;
 
+#[cfg(feature = "c-exports")]
+pub mod c_api;
+
+mod builder;
+pub use builder::{ZStandardSizeEstimationBuilder, ZStandardStrategy};
+
 use alloc::string::String;
+use builder::ZStandardParameters;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicPtr, Ordering};
 use core::{ffi::c_void, slice};
 use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 use dxt_lossless_transform_common::allocate::AllocateError;
@@ -30,6 +39,15 @@ pub enum ZStandardError {
     #[error("Invalid compression level: {0}")]
     InvalidLevel(i32),
 
+    /// Compression parameter outside the range supported by ZStandard
+    #[error("Invalid value for ZStandard parameter {parameter}: {value}")]
+    InvalidParameter {
+        /// Name of the parameter, e.g. `windowLog`
+        parameter: &'static str,
+        /// The rejected value
+        value: i64,
+    },
+
     /// Memory allocation failed
     #[error("Memory allocation failed")]
     AllocationFailed,
@@ -50,8 +68,22 @@ pub enum ZStandardError {
 /// slower as it performs the full compression operation.
 ///
 /// The compression level is configured when creating the estimator instance.
+/// To match other compression parameters (window size, strategy, dictionary, ...) of the
+/// compressor you ship with, use [`ZStandardSizeEstimationBuilder`].
+///
+/// # Compression Context Reuse
+///
+/// The estimator keeps a ZStandard compression context, with its parameters already set,
+/// between calls to [`SizeEstimationOperations::estimate_compressed_size`]; it is created on
+/// first use and freed when the estimator is dropped. Reuse the same estimator (e.g. via the
+/// same auto transform builder) across textures to avoid recreating it.
+///
+/// The estimator can be shared between threads. If it is used by multiple threads at once,
+/// the extra calls use a temporary context of their own.
 pub struct ZStandardSizeEstimation {
-    compression_level: i32,
+    parameters: ZStandardParameters,
+    /// Cached compression context; null if not yet created, or while taken by a call.
+    context: AtomicPtr<ZSTD_CCtx>,
 }
 
 impl ZStandardSizeEstimation {
@@ -65,27 +97,82 @@ impl ZStandardSizeEstimation {
             return Err(ZStandardError::InvalidLevel(compression_level));
         }
 
-        Ok(Self { compression_level })
+        Ok(Self::with_level(compression_level))
     }
 
     /// Creates a new ZStandard size estimator with compression level 1 (fastest).
     pub fn new_fast() -> Self {
-        Self {
-            compression_level: 1,
-        }
+        Self::with_level(1)
     }
 
     /// Creates a new ZStandard size estimator with compression level 3 (default).
     pub fn new_default() -> Self {
-        Self {
-            compression_level: 3,
-        }
+        Self::with_level(3)
     }
 
     /// Creates a new ZStandard size estimator with compression level 22 (best compression).
     pub fn new_best() -> Self {
+        Self::with_level(22)
+    }
+
+    const fn with_level(compression_level: i32) -> Self {
+        Self::with_parameters(ZStandardParameters::new(compression_level))
+    }
+
+    /// Creates an estimator from parameters which were already validated.
+    pub(crate) const fn with_parameters(parameters: ZStandardParameters) -> Self {
         Self {
-            compression_level: 22,
+            parameters,
+            context: AtomicPtr::new(null_mut()),
+        }
+    }
+
+    /// Takes the cached compression context, or creates a new one if it is unavailable.
+    fn take_context(&self) -> Result<*mut ZSTD_CCtx, ZStandardError> {
+        let cctx = self.context.swap(null_mut(), Ordering::Acquire);
+        if !cctx.is_null() {
+            return Ok(cctx);
+        }
+
+        let cctx = unsafe { ZSTD_createCCtx() };
+        if cctx.is_null() {
+            return Err(ZStandardError::ZStandardInternal(
+                ZSTD_ErrorCode::ZSTD_error_GENERIC,
+            ));
+        }
+
+        // Set compression parameters (magicless format, no extra headers, custom parameters).
+        // These persist across compressions with the context.
+        if let Err(error) = self.parameters.apply(cctx) {
+            unsafe {
+                ZSTD_freeCCtx(cctx);
+            }
+            return Err(error);
+        }
+        Ok(cctx)
+    }
+
+    /// Caches a context taken with [`Self::take_context`], freeing it if another is already cached.
+    fn return_context(&self, cctx: *mut ZSTD_CCtx) {
+        if self
+            .context
+            .compare_exchange(null_mut(), cctx, Ordering::Release, Ordering::Relaxed)
+            .is_err()
+        {
+            unsafe {
+                ZSTD_freeCCtx(cctx);
+            }
+        }
+    }
+}
+
+impl Drop for ZStandardSizeEstimation {
+    fn drop(&mut self) {
+        let cctx = *self.context.get_mut();
+        if !cctx.is_null() {
+            unsafe {
+                ZSTD_freeCCtx(cctx);
+            }
         }
     }
 }
@@ -129,9 +216,11 @@ impl SizeEstimationOperations for ZStandardSizeEstimation {
         let input_data = slice::from_raw_parts(input_ptr, len_bytes);
 
         // Perform compression using the provided buffer
-        let compressed_size = compress(self.compression_level, input_data, output_buffer)?;
+        let cctx = self.take_context()?;
+        let result = compress(cctx, input_data, output_buffer);
+        self.return_context(cctx);
 
-        Ok(compressed_size)
+        result
     }
 }
 
@@ -140,21 +229,14 @@ impl SizeEstimationOperations for ZStandardSizeEstimation {
 ///
 /// # Parameters
 ///
-/// * `level`: Level at which we are compressing.
+/// * `cctx`: Compression context, with parameters set by [`zstd_setcommoncompressparams`].
 /// * `source`: Source data to compress.
 /// * `destination`: Destination buffer.
-fn compress(level: i32, source: &[u8], destination: &mut [u8]) -> Result<usize, ZStandardError> {
-    // Create a compression context
-    let cctx = unsafe { ZSTD_createCCtx() };
-    if cctx.is_null() {
-        return Err(ZStandardError::ZStandardInternal(
-            ZSTD_ErrorCode::ZSTD_error_GENERIC,
-        ));
-    }
-
-    // Set compression parameters (magicless format, no extra headers)
-    zstd_setcommoncompressparams(cctx, Some(level));
-
+fn compress(
+    cctx: *mut ZSTD_CCtx,
+    source: &[u8],
+    destination: &mut [u8],
+) -> Result<usize, ZStandardError> {
     // Perform compression
     let result = unsafe {
         ZSTD_compress2(
@@ -166,11 +248,6 @@ fn compress(level: i32, source: &[u8], destination: &mut [u8]) -> Result<usize,
         )
     };
 
-    // Free the context
-    unsafe {
-        ZSTD_freeCCtx(cctx);
-    }
-
     if unsafe { ZSTD_isError(result) } == 0 {
         return Ok(result);
     }
@@ -182,7 +259,7 @@ fn compress(level: i32, source: &[u8], destination: &mut [u8]) -> Result<usize,
 
 /// Sets common compression parameters matching the CLI's behavior.
 #[inline(always)]
-fn zstd_setcommoncompressparams(cctx: *mut ZSTD_CCtx_s, level: Option<i32>) {
+pub(crate) fn zstd_setcommoncompressparams(cctx: *mut ZSTD_CCtx_s, level: Option<i32>) {
     unsafe {
         if let Some(lv) = level {
             ZSTD_CCtx_setParameter(cctx, ZSTD_c_compressionLevel, lv);
@@ -287,4 +364,63 @@ mod tests {
         assert!(level1_size > 0);
         assert!(level10_size > 0);
     }
+
+    #[test]
+    fn reused_context_matches_fresh_context() {
+        let estimator = ZStandardSizeEstimation::new(9).unwrap();
+        let data: Vec<u8> = (0..65536u32)
+            .map(|x| ((x % 251) ^ (x / 509)) as u8)
+            .collect();
+        let max_size = estimator.max_compressed_size(data.len()).unwrap();
+        let mut output_buffer = vec![0u8; max_size];
+
+        let mut estimate = |estimator: &ZStandardSizeEstimation, len: usize| unsafe {
+            estimator
+                .estimate_compressed_size(data.as_ptr(), len, output_buffer.as_mut_ptr(), max_size)
+                .unwrap()
+        };
+
+        // Results with the cached context must match those with a new one,
+        // regardless of the data compressed before.
+        let full = estimate(&ZStandardSizeEstimation::new(9).unwrap(), data.len());
+        let half = estimate(&ZStandardSizeEstimation::new(9).unwrap(), data.len() / 2);
+        assert_eq!(estimate(&estimator, data.len()), full);
+        assert!(!estimator.context.load(Ordering::Relaxed).is_null());
+        assert_eq!(estimate(&estimator, data.len() / 2), half);
+        assert_eq!(estimate(&estimator, data.len()), full);
+    }
+
+    #[test]
+    fn concurrent_use_of_shared_estimator() {
+        let estimator = ZStandardSizeEstimation::new_fast();
+        let data = vec![0x55u8; 16384];
+        let max_size = estimator.max_compressed_size(data.len()).unwrap();
+
+        let sizes: Vec<usize> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut output_buffer = vec![0u8; max_size];
+                        let mut last = 0;
+                        for _ in 0..16 {
+                            last = unsafe {
+                                estimator
+                                    .estimate_compressed_size(
+                                        data.as_ptr(),
+                                        data.len(),
+                                        output_buffer.as_mut_ptr(),
+                                        max_size,
+                                    )
+                                    .unwrap()
+                            };
+                        }
+                        last
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|x| x.join().unwrap()).collect()
+        });
+
+        assert!(sizes.iter().all(|&x| x == sizes[0]));
+    }
 }
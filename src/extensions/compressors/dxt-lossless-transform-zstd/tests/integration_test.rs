@@ -0,0 +1,62 @@
+//! Integration test demonstrating ZStandard usage with the BC1 C API.
+
+#[cfg(all(feature = "c-exports", feature = "std"))]
+#[test]
+fn test_zstd_with_bc1_auto_transform_builder() {
+    use dxt_lossless_transform_bc1_api::c_api::transform::auto_transform_builder::*;
+    use dxt_lossless_transform_bc1_api::c_api::transform::manual_transform_builder::*;
+    use dxt_lossless_transform_zstd::c_api::*;
+
+    // Create a ZStandard estimator
+    let mut estimator = core::ptr::null_mut();
+    let result = unsafe { dltzstd_new_size_estimator(3, &mut estimator) };
+    assert!(result.is_success());
+
+    // Use it to create a BC1 auto transform builder
+    let builder = unsafe { dltbc1_new_AutoTransformBuilder(estimator) };
+    assert!(!builder.is_null());
+
+    // Test data: repeating BC1 blocks
+    let bc1_data: Vec<u8> = [0x12u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]
+        .iter()
+        .copied()
+        .cycle()
+        .take(8 * 64)
+        .collect();
+    let mut transformed = vec![0u8; bc1_data.len()];
+    let mut restored = vec![0u8; bc1_data.len()];
+
+    // Transform, picking the best settings with the ZStandard estimator
+    let mut manual_builder = core::ptr::null_mut();
+    let result = unsafe {
+        dltbc1_AutoTransformBuilder_Transform(
+            builder,
+            bc1_data.as_ptr(),
+            bc1_data.len(),
+            transformed.as_mut_ptr(),
+            transformed.len(),
+            &mut manual_builder,
+        )
+    };
+    assert!(result.is_success());
+
+    // Untransform with the chosen settings
+    let result = unsafe {
+        dltbc1_ManualTransformBuilder_Untransform(
+            transformed.as_ptr(),
+            transformed.len(),
+            restored.as_mut_ptr(),
+            restored.len(),
+            manual_builder,
+        )
+    };
+    assert!(result.is_success());
+    assert_eq!(restored, bc1_data);
+
+    // Clean up
+    unsafe {
+        dltbc1_free_ManualTransformBuilder(manual_builder);
+        dltbc1_free_AutoTransformBuilder(builder);
+        dltzstd_free_size_estimator(estimator);
+    }
+}
@@ -72,12 +72,26 @@ pub(crate) const DXGI_FORMAT_B8G8R8A8_UNORM_SRGB: u32 = 91;
 pub(crate) const DDS_HEADER_SIZE: usize = 0x80;
 pub(crate) const DX10_HEADER_SIZE: usize = 20;
 
+/// Offset of `dwSize`, which is always [`DDS_HEADER_STRUCT_SIZE`] in valid files.
+pub(crate) const DDS_SIZE_OFFSET: usize = 0x04;
+pub(crate) const DDS_HEADER_STRUCT_SIZE: u32 = 124;
+
 // DDS header field offsets for data length calculation
 pub(crate) const DDS_FLAGS_OFFSET: usize = 0x08;
 pub(crate) const DDS_HEIGHT_OFFSET: usize = 0x0C;
 pub(crate) const DDS_WIDTH_OFFSET: usize = 0x10;
 pub(crate) const DDS_MIPMAP_COUNT_OFFSET: usize = 0x1C;
 
+/// Offset of `dwReserved1[0..2]`, which holds the texture checksum of transformed files.
+pub(crate) const DDS_CHECKSUM_OFFSET: usize = 0x20;
+pub(crate) const DDS_CHECKSUM_SIZE: usize = 8;
+
+// DDS header field offsets for texture inspection
+pub(crate) const DDS_DEPTH_OFFSET: usize = 0x18;
+pub(crate) const DDS_CAPS2_OFFSET: usize = 0x70;
+pub(crate) const DX10_MISC_FLAG_OFFSET: usize = 0x88;
+pub(crate) const DX10_ARRAY_SIZE_OFFSET: usize = 0x8C;
+
 // DDS pixel format offsets (within the 32-byte DDSPIXELFORMAT structure at offset 0x4C)
 pub(crate) const DDS_PIXELFORMAT_OFFSET: usize = 0x4C;
 pub(crate) const DDS_PIXELFORMAT_FLAGS_OFFSET: usize = 0x50;
@@ -90,6 +104,16 @@ pub(crate) const DDSD_WIDTH: u32 = 0x4;
 pub(crate) const DDSD_PIXELFORMAT: u32 = 0x1000;
 pub(crate) const DDSD_LINEARSIZE: u32 = 0x80000;
 pub(crate) const DDSD_MIPMAPCOUNT: u32 = 0x20000;
+pub(crate) const DDSD_DEPTH: u32 = 0x800000;
+
+// DDS caps2 flags
+pub(crate) const DDSCAPS2_CUBEMAP: u32 = 0x200;
+/// All of the `DDSCAPS2_CUBEMAP_POSITIVEX` .. `DDSCAPS2_CUBEMAP_NEGATIVEZ` face flags.
+pub(crate) const DDSCAPS2_CUBEMAP_ALLFACES: u32 = 0xFC00;
+pub(crate) const DDSCAPS2_VOLUME: u32 = 0x200000;
+
+// DX10 misc flags
+pub(crate) const DDS_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
 
 // DDS pixel format flags
 pub(crate) const DDPF_ALPHAPIXELS: u32 = 0x1;
@@ -0,0 +1,93 @@
+//! C exports for the DDS handler.
+
+use super::DdsHandler;
+use dxt_lossless_transform_file_formats_api::c_api::error::{DltffErrorCode, DltffResult};
+use dxt_lossless_transform_file_formats_api::c_api::registry::DltffHandlerRegistry;
+
+/// Add the DDS handler to a file formats API handler registry, preferred for `.dds` files.
+///
+/// Afterwards, DDS files can be transformed with the registry via e.g. `dltff_transform_slice`.
+///
+/// # Returns
+/// A [`DltffResult`] indicating success, or [`DltffErrorCode::NullRegistryPointer`].
+///
+/// # Safety
+/// - `registry` must be a valid pointer to a [`DltffHandlerRegistry`]
+#[no_mangle]
+pub unsafe extern "C" fn dltdds_register_DdsHandler(
+    registry: *mut DltffHandlerRegistry,
+) -> DltffResult {
+    match unsafe { registry.as_mut() } {
+        Some(registry) => {
+            registry.register(DdsHandler, &["dds"]);
+            DltffResult::success()
+        }
+        None => DltffResult::from_error_code(DltffErrorCode::NullRegistryPointer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use core::ptr;
+    use dxt_lossless_transform_bc1_api::c_api::transform::manual_transform_builder::{
+        dltbc1_free_ManualTransformBuilder, dltbc1_new_ManualTransformBuilder,
+    };
+    use dxt_lossless_transform_file_formats_api::c_api::bundle::{
+        dltff_TransformBundle_SetBc1Manual, dltff_free_TransformBundle, dltff_new_TransformBundle,
+    };
+    use dxt_lossless_transform_file_formats_api::c_api::registry::{
+        dltff_free_HandlerRegistry, dltff_new_HandlerRegistry, dltff_transform_slice,
+        dltff_untransform_slice,
+    };
+
+    #[test]
+    fn registered_handler_roundtrips_dds() {
+        let input = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        let mut transformed = vec![0u8; input.len()];
+        let mut restored = vec![0u8; input.len()];
+
+        unsafe {
+            let registry = dltff_new_HandlerRegistry();
+            assert!(dltdds_register_DdsHandler(registry).is_success());
+
+            let bundle = dltff_new_TransformBundle();
+            let bc1 = dltbc1_new_ManualTransformBuilder();
+            dltff_TransformBundle_SetBc1Manual(bundle, bc1);
+            dltbc1_free_ManualTransformBuilder(bc1);
+
+            let result = dltff_transform_slice(
+                registry,
+                bundle,
+                input.as_ptr(),
+                input.len(),
+                transformed.as_mut_ptr(),
+                transformed.len(),
+                c"dds".as_ptr(),
+            );
+            assert!(result.is_success());
+            assert_ne!(transformed, input);
+
+            let result = dltff_untransform_slice(
+                registry,
+                transformed.as_ptr(),
+                transformed.len(),
+                restored.as_mut_ptr(),
+                restored.len(),
+                ptr::null(),
+            );
+            assert!(result.is_success());
+            assert_eq!(restored, input);
+
+            dltff_free_TransformBundle(bundle);
+            dltff_free_HandlerRegistry(registry);
+        }
+    }
+
+    #[test]
+    fn register_rejects_null_registry() {
+        let result = unsafe { dltdds_register_DdsHandler(ptr::null_mut()) };
+        assert_eq!(result.error_code, DltffErrorCode::NullRegistryPointer);
+    }
+}
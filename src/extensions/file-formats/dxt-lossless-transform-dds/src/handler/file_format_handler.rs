@@ -1,15 +1,21 @@
 use super::{format_conversion::dds_format_to_transform_format, DdsHandler};
 use crate::dds::{
-    constants::DDS_MAGIC,
-    parse_dds::{parse_dds, parse_dds_ignore_magic},
+    constants::{DDS_HEADER_STRUCT_SIZE, DDS_MAGIC, DDS_SIZE_OFFSET},
+    parse_dds::{parse_dds, parse_dds_ignore_magic, DdsInfo},
 };
+use alloc::vec::Vec;
 use core::fmt::Debug;
+use core::ops::Range;
 use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 use dxt_lossless_transform_file_formats_api::{
     bundle::TransformBundle,
-    embed::{TransformHeader, TRANSFORM_HEADER_SIZE},
+    embed::{TransformDetails, TransformFormat, TransformHeader, TRANSFORM_HEADER_SIZE},
     error::{FormatHandlerError, TransformResult},
-    handlers::FileFormatHandler,
+    handlers::{
+        texture_checksum, validate_in_place_size, verify_texture_checksum, FileFormatHandler,
+        FileFormatStreaming,
+    },
+    report::TransformReport,
 };
 
 impl FileFormatHandler for DdsHandler {
@@ -19,6 +25,20 @@ impl FileFormatHandler for DdsHandler {
         output: &mut [u8],
         bundle: &TransformBundle<T>,
     ) -> TransformResult<()>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        self.transform_bundle_with_report(input, output, bundle, &mut TransformReport::default())
+    }
+
+    fn transform_bundle_with_report<T>(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        bundle: &TransformBundle<T>,
+        report: &mut TransformReport,
+    ) -> TransformResult<()>
     where
         T: SizeEstimationOperations,
         T::Error: Debug,
@@ -32,44 +52,138 @@ impl FileFormatHandler for DdsHandler {
             .into());
         }
 
-        // Parse DDS header
-        let info = parse_dds(input).ok_or(FormatHandlerError::InvalidInputFileHeader)?;
-        let data_offset = info.data_offset as usize;
-        let data_length = info.data_length as usize;
-        let total_required = data_offset + data_length;
+        let layout = TextureLayout::for_transform(input, bundle)?;
 
-        // Validate input buffer contains enough data for declared texture size
-        if input.len() < total_required {
-            return Err(FormatHandlerError::InputTooShortForStatedTextureSize {
-                required: total_required,
-                actual: input.len(),
+        // Copy headers to output
+        output[..layout.data_offset].copy_from_slice(&input[..layout.data_offset]);
+
+        // Copy leftover data (and partial blocks of truncated files) after texture data verbatim
+        if input.len() > layout.data_end {
+            output[layout.data_end..input.len()].copy_from_slice(&input[layout.data_end..]);
+        }
+
+        self.transform_texture(
+            output,
+            &input[layout.texture_range()],
+            &layout,
+            bundle,
+            report,
+        )
+    }
+
+    fn untransform(&self, input: &[u8], output: &mut [u8]) -> TransformResult<()> {
+        let (header, layout) = TextureLayout::for_untransform(input)?;
+
+        // Validate buffer sizes
+        if output.len() < input.len() {
+            return Err(FormatHandlerError::OutputBufferTooSmall {
+                required: input.len(),
+                actual: output.len(),
             }
             .into());
         }
 
-        // Copy headers to output
-        output[..data_offset].copy_from_slice(&input[..data_offset]);
-
-        // Convert DDS format to transform format and dispatch (only texture data)
-        let transform_format = dds_format_to_transform_format(info.format, false)?;
-        let header = dxt_lossless_transform_file_formats_api::dispatch_transform(
-            transform_format,
-            &input[data_offset..data_offset + data_length],
-            &mut output[data_offset..data_offset + data_length],
+        // Copy the rest of the header (the magic is restored once untransformed)
+        output[4..layout.data_offset].copy_from_slice(&input[4..layout.data_offset]);
+
+        // Copy leftover data (and partial blocks of truncated files) after texture data verbatim
+        if input.len() > layout.data_end {
+            output[layout.data_end..input.len()].copy_from_slice(&input[layout.data_end..]);
+        }
+
+        self.untransform_texture(output, &input[layout.texture_range()], header, &layout)
+    }
+
+    fn transform_bundle_in_place<T>(
+        &self,
+        data: &mut [u8],
+        bundle: &TransformBundle<T>,
+    ) -> TransformResult<()>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        validate_in_place_size(self.transformed_size(data), data.len())?;
+        let layout = TextureLayout::for_transform(data, bundle)?;
+
+        // Leftover data stays where it is; the headers are copied too, so they can be restored
+        // if the transform fails.
+        let scratch: Vec<u8> = data[..layout.data_end].to_vec();
+        let result = self.transform_texture(
+            data,
+            &scratch[layout.data_offset..],
+            &layout,
             bundle,
-        )?;
+            &mut TransformReport::default(),
+        );
+        if result.is_err() {
+            data[..layout.data_end].copy_from_slice(&scratch);
+        }
+        result
+    }
+
+    fn untransform_in_place(&self, data: &mut [u8]) -> TransformResult<()> {
+        validate_in_place_size(self.untransformed_size(data), data.len())?;
+        let (header, layout) = TextureLayout::for_untransform(data)?;
+
+        // Leftover data stays where it is; the headers are copied too, so they can be restored
+        // if the untransform fails.
+        let scratch: Vec<u8> = data[..layout.data_end].to_vec();
+        let result =
+            self.untransform_texture(data, &scratch[layout.data_offset..], header, &layout);
+        if result.is_err() {
+            data[..layout.data_end].copy_from_slice(&scratch);
+        }
+        result
+    }
+}
+
+impl DdsHandler {
+    /// Transforms `input_texture_data` into the texture data area of `output` and embeds the
+    /// transform header, shared by the out-of-place and in-place transforms.
+    ///
+    /// `output` must already contain the original DDS headers; leftover data is left untouched.
+    fn transform_texture<T>(
+        &self,
+        output: &mut [u8],
+        input_texture_data: &[u8],
+        layout: &TextureLayout,
+        bundle: &TransformBundle<T>,
+        report: &mut TransformReport,
+    ) -> TransformResult<()>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        let header = dxt_lossless_transform_file_formats_api::dispatch_transform_with_report(
+            layout.format,
+            input_texture_data,
+            &mut output[layout.texture_range()],
+            bundle,
+            report,
+        )?
+        .with_truncated(layout.truncated)
+        .with_checksum(bundle.checksum());
+
+        // Store the checksum of the original texture data in the reserved header fields, if free
+        if bundle.checksum()
+            && !self.embed_checksum(
+                &mut output[..layout.data_offset],
+                texture_checksum(input_texture_data),
+            )
+        {
+            return Err(FormatHandlerError::ChecksumUnsupported.into());
+        }
 
-        // Copy leftover data after texture data verbatim
-        let leftover_start = data_offset + data_length;
-        if input.len() > leftover_start {
-            output[leftover_start..].copy_from_slice(&input[leftover_start..]);
+        // The DDS magic only has room for a standard header
+        if header.is_extended() {
+            return Err(FormatHandlerError::ExtendedHeaderNotSupported.into());
         }
 
         // Embed transform header (overwrites DDS magic)
-        // SAFETY: output.as_mut_ptr() is valid for writes of at least TRANSFORM_HEADER_SIZE bytes because:
-        // 1. We validated output.len() >= input.len() above
-        // 2. parse_dds succeeded, guaranteeing input has valid DDS structure (minimum 128 bytes)
-        // 3. Therefore output has at least 128 bytes, which is >= TRANSFORM_HEADER_SIZE bytes required for the header
+        // SAFETY: parse_dds succeeded when computing the layout, and output holds the headers of
+        // that file, so output is at least 128 bytes long, which is >= TRANSFORM_HEADER_SIZE bytes
+        // required for the header
         unsafe {
             header.write_to_ptr(output.as_mut_ptr());
         }
@@ -77,8 +191,83 @@ impl FileFormatHandler for DdsHandler {
         Ok(())
     }
 
-    fn untransform(&self, input: &[u8], output: &mut [u8]) -> TransformResult<()> {
-        // Validate buffer sizes
+    /// Untransforms `input_texture_data` into the texture data area of `output` and restores the
+    /// DDS magic, shared by the out-of-place and in-place untransforms.
+    ///
+    /// `output` must already contain the transformed DDS headers; leftover data is left untouched.
+    fn untransform_texture(
+        &self,
+        output: &mut [u8],
+        input_texture_data: &[u8],
+        header: TransformHeader,
+        layout: &TextureLayout,
+    ) -> TransformResult<()> {
+        let checksum = if header.has_checksum() {
+            Some(self.restore_checksum(&mut output[..layout.data_offset])?)
+        } else {
+            None
+        };
+
+        // Dispatch untransform based on header format (only texture data)
+        let output_texture_data = &mut output[layout.texture_range()];
+        dxt_lossless_transform_file_formats_api::dispatch_untransform(
+            header,
+            input_texture_data,
+            output_texture_data,
+        )?;
+
+        if let Some(expected) = checksum {
+            verify_texture_checksum(expected, output_texture_data)?;
+        }
+
+        // Restore DDS magic
+        output[0..4].copy_from_slice(&DDS_MAGIC.to_le_bytes());
+
+        Ok(())
+    }
+}
+
+/// Location and format of the texture data processed within a DDS file.
+struct TextureLayout {
+    /// Format the texture data is transformed as.
+    format: TransformFormat,
+    /// Offset of the texture data, i.e. the size of the DDS headers.
+    data_offset: usize,
+    /// End of the processed texture data; anything after it is copied verbatim.
+    data_end: usize,
+    /// Whether the file is shorter than its header states.
+    truncated: bool,
+}
+
+impl TextureLayout {
+    /// Parses an original DDS file, validating it can be transformed with `bundle`.
+    fn for_transform<T>(input: &[u8], bundle: &TransformBundle<T>) -> TransformResult<Self>
+    where
+        T: SizeEstimationOperations,
+        T::Error: Debug,
+    {
+        let info = parse_original_dds(input)?;
+        let format = match dds_format_to_transform_format(info.format, false) {
+            Ok(format) => format,
+            // Store formats we can't transform unchanged, if requested
+            Err(_) if bundle.passthrough_unsupported() => TransformFormat::None,
+            Err(e) => return Err(e),
+        };
+
+        // Validate input buffer contains enough data for declared texture size
+        let (format, data_length, truncated) =
+            texture_data_length(&info, input.len(), format, bundle.allow_truncated())?;
+        let data_offset = info.data_offset as usize;
+        Ok(Self {
+            format,
+            data_offset,
+            data_end: data_offset + data_length,
+            truncated,
+        })
+    }
+
+    /// Parses a transformed DDS file, returning its transform header alongside the layout.
+    fn for_untransform(input: &[u8]) -> TransformResult<(TransformHeader, Self)> {
         if input.len() < TRANSFORM_HEADER_SIZE {
             return Err(FormatHandlerError::InputTooShort {
                 required: TRANSFORM_HEADER_SIZE,
@@ -86,67 +275,133 @@ impl FileFormatHandler for DdsHandler {
             }
             .into());
         }
-
-        if output.len() < input.len() {
-            return Err(FormatHandlerError::OutputBufferTooSmall {
-                required: input.len(),
-                actual: output.len(),
-            }
-            .into());
-        }
-
-        // Read transform header from first 4 bytes
-        // SAFETY: input.as_ptr() is valid for reads of at least TRANSFORM_HEADER_SIZE bytes because we validated
-        // input.len() >= TRANSFORM_HEADER_SIZE above. The input slice guarantees pointer validity.
-        let header = unsafe { TransformHeader::read_from_ptr(input.as_ptr()) };
+        ensure_transformed(input)?;
 
         // Parse header ignoring the magic (which contains transform data)
         let info =
             parse_dds_ignore_magic(input).ok_or(FormatHandlerError::InvalidRestoredFileHeader)?;
-        let data_offset = info.data_offset as usize;
-        let data_length = info.data_length as usize;
-        let total_required = data_offset + data_length;
+
+        // Read transform header from the magic
+        // SAFETY: parse_dds_ignore_magic succeeded, so input is at least DDS_HEADER_SIZE bytes long,
+        // which covers even an extended header.
+        let header = unsafe { TransformHeader::read_from_ptr(input.as_ptr()) };
 
         // Validate input buffer contains enough data for declared texture size
-        if input.len() < total_required {
-            return Err(FormatHandlerError::InputTooShortForStatedTextureSize {
-                required: total_required,
-                actual: input.len(),
-            }
-            .into());
+        let format = header_format(header);
+        let (_, data_length, _) =
+            texture_data_length(&info, input.len(), format, header.is_truncated())?;
+
+        // The DDS magic only has room for a standard header
+        if header.is_extended() {
+            return Err(FormatHandlerError::ExtendedHeaderNotSupported.into());
         }
 
-        // Restore DDS magic
-        output[0..4].copy_from_slice(&DDS_MAGIC.to_le_bytes());
+        let data_offset = info.data_offset as usize;
+        let layout = Self {
+            format,
+            data_offset,
+            data_end: data_offset + data_length,
+            truncated: header.is_truncated(),
+        };
+        Ok((header, layout))
+    }
 
-        // Copy the rest of the header (from byte 4 to data_offset)
-        output[4..data_offset].copy_from_slice(&input[4..data_offset]);
+    /// Range of the processed texture data within the file.
+    fn texture_range(&self) -> Range<usize> {
+        self.data_offset..self.data_end
+    }
+}
 
-        // Dispatch untransform based on header format (only texture data)
-        dxt_lossless_transform_file_formats_api::dispatch_untransform(
-            header,
-            &input[data_offset..data_offset + data_length],
-            &mut output[data_offset..data_offset + data_length],
-        )?;
+/// Returns the format and length of the texture data to process, and whether the file is
+/// truncated (i.e. shorter than its header states).
+///
+/// Truncated files are rejected unless `allow_truncated` is set, in which case only the whole
+/// blocks of `format` present in the file are processed; the remaining bytes are leftover data.
+/// If not even a single block is present, the data is processed as [`TransformFormat::None`].
+fn texture_data_length(
+    info: &DdsInfo,
+    file_length: usize,
+    format: TransformFormat,
+    allow_truncated: bool,
+) -> TransformResult<(TransformFormat, usize, bool)> {
+    let data_offset = info.data_offset as usize;
+    let data_length = info.data_length as usize;
+    let total_required = data_offset + data_length;
+    if file_length >= total_required {
+        return Ok((format, data_length, false));
+    }
 
-        // Copy leftover data after texture data verbatim
-        let leftover_start = data_offset + data_length;
-        if input.len() > leftover_start {
-            output[leftover_start..].copy_from_slice(&input[leftover_start..]);
+    if !allow_truncated {
+        return Err(FormatHandlerError::InputTooShortForStatedTextureSize {
+            required: total_required,
+            actual: file_length,
         }
+        .into());
+    }
 
-        Ok(())
+    let available = file_length.saturating_sub(data_offset);
+    let format = if available < format.block_size() {
+        TransformFormat::None
+    } else {
+        format
+    };
+    Ok((format, available - available % format.block_size(), true))
+}
+
+/// Returns the format of the texture data stored under a transform header.
+///
+/// Unknown formats are treated as unchanged data; untransforming them fails regardless.
+fn header_format(header: TransformHeader) -> TransformFormat {
+    TransformDetails::from_header(header)
+        .format()
+        .unwrap_or(TransformFormat::None)
+}
+
+/// Parses an original (not transformed) DDS file.
+///
+/// Input rejected by [`parse_dds`] is reported as [`FormatHandlerError::AlreadyTransformed`]
+/// if it is a DDS file (valid `dwSize`) with a decodable transform header in place of the magic.
+fn parse_original_dds(input: &[u8]) -> TransformResult<DdsInfo> {
+    if let Some(info) = parse_dds(input) {
+        return Ok(info);
     }
+
+    let is_transformed = parse_dds_ignore_magic(input).is_some()
+        && input[DDS_SIZE_OFFSET..DDS_SIZE_OFFSET + 4] == DDS_HEADER_STRUCT_SIZE.to_le_bytes()
+        && TransformHeader::size_from_first_byte(input[0]) == TRANSFORM_HEADER_SIZE
+        // SAFETY: parse_dds_ignore_magic succeeded, so input is at least DDS_HEADER_SIZE bytes long,
+        // and the header is not extended.
+        && !matches!(
+            TransformDetails::from_header(unsafe { TransformHeader::read_from_ptr(input.as_ptr()) }),
+            TransformDetails::Unknown(_)
+        );
+    if is_transformed {
+        Err(FormatHandlerError::AlreadyTransformed.into())
+    } else {
+        Err(FormatHandlerError::InvalidInputFileHeader.into())
+    }
+}
+
+/// Rejects input which still starts with the DDS magic.
+///
+/// No transform header is encoded as the DDS magic, so such input was never transformed.
+pub(super) fn ensure_transformed(input: &[u8]) -> TransformResult<()> {
+    if input.len() >= 4 && input[0..4] == DDS_MAGIC.to_le_bytes() {
+        return Err(FormatHandlerError::NotTransformed.into());
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::dds::constants::DDS_HEADER_SIZE;
+    use crate::dds::constants::{DDS_CHECKSUM_OFFSET, DDS_HEADER_SIZE};
+    use crate::dds::DdsFormat;
     use crate::test_prelude::*;
     use dxt_lossless_transform_api_common::estimate::NoEstimation;
+    use dxt_lossless_transform_bc1_api::Bc1AutoTransformBuilder;
     use dxt_lossless_transform_file_formats_api::{
-        embed::TransformFormat,
+        embed::TransformDetails,
         error::{FormatHandlerError, TransformError},
         TransformBundle,
     };
@@ -269,6 +524,23 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn untransform_rejects_extended_transform_header() {
+        let handler = DdsHandler;
+        let mut transformed = create_valid_bc1_dds();
+        // Format nibble 0xF signals an extended header, which doesn't fit in the DDS magic
+        transformed[0..4].copy_from_slice(&[0x0F, 0x00, 0x00, 0x00]);
+        let mut output = vec![0u8; transformed.len()];
+
+        let result = handler.untransform(&transformed, &mut output);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::ExtendedHeaderNotSupported
+            ))
+        ));
+    }
+
     // Builder availability tests
 
     #[test]
@@ -389,6 +661,45 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::bc3(create_valid_bc3_dds())]
+    #[case::bc6h(create_valid_bc6h_dds())]
+    #[case::bc7(create_valid_bc7_dds())]
+    #[case::unknown(create_valid_unknown_format_dds())]
+    fn transform_bundle_passthrough_unsupported_roundtrip(#[case] input: Vec<u8>) {
+        let handler = DdsHandler;
+        let bundle =
+            TransformBundle::<NoEstimation>::default_all().with_passthrough_unsupported(true);
+        let mut transformed = vec![0u8; input.len()];
+        let mut restored = vec![0u8; input.len()];
+
+        handler
+            .transform_bundle(&input, &mut transformed, &bundle)
+            .unwrap();
+
+        // Only the magic is replaced; everything else is stored unchanged
+        assert_eq!(transformed[0] & 0x0F, TransformFormat::None as u8);
+        assert_eq!(transformed[4..], input[4..]);
+
+        handler.untransform(&transformed, &mut restored).unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[test]
+    fn transform_bundle_passthrough_missing_builder() {
+        let handler = DdsHandler;
+        let bundle = TransformBundle::<NoEstimation>::new().with_passthrough_unsupported(true);
+        let input = create_valid_bc1_dds();
+        let mut transformed = vec![0u8; input.len()];
+
+        handler
+            .transform_bundle(&input, &mut transformed, &bundle)
+            .unwrap();
+
+        assert_eq!(transformed[0] & 0x0F, TransformFormat::None as u8);
+        assert_eq!(transformed[4..], input[4..]);
+    }
+
     // Data length and leftover data tests
     #[test]
     fn transform_and_untransform_preserves_leftover_data_roundtrip() {
@@ -474,4 +785,294 @@ mod tests {
             );
         }
     }
+
+    #[rstest]
+    #[case::bc1(create_valid_bc1_dds_with_dimensions(64, 64, 2))]
+    #[case::bc2(create_valid_bc2_dds())]
+    #[case::leftover(create_bc1_dds_with_leftover_data(32, 32, b"Leftover data"))]
+    fn in_place_matches_out_of_place_roundtrip(#[case] input: Vec<u8>) {
+        let handler = DdsHandler;
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let mut expected = vec![0u8; input.len()];
+        handler
+            .transform_bundle(&input, &mut expected, &bundle)
+            .unwrap();
+
+        let mut data = input.clone();
+        handler
+            .transform_bundle_in_place(&mut data, &bundle)
+            .unwrap();
+        assert_eq!(data, expected);
+
+        handler.untransform_in_place(&mut data).unwrap();
+        assert_eq!(data, input);
+    }
+
+    #[rstest]
+    #[case::bc1(create_valid_bc1_dds_with_dimensions(64, 64, 2))]
+    #[case::bc7_passthrough(create_valid_bc7_dds())]
+    fn transform_bundle_rejects_already_transformed_input(#[case] input: Vec<u8>) {
+        let handler = DdsHandler;
+        let bundle =
+            TransformBundle::<NoEstimation>::default_all().with_passthrough_unsupported(true);
+        let mut transformed = vec![0u8; input.len()];
+        handler
+            .transform_bundle(&input, &mut transformed, &bundle)
+            .unwrap();
+
+        let mut output = vec![0u8; input.len()];
+        let result = handler.transform_bundle(&transformed, &mut output, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::AlreadyTransformed
+            ))
+        ));
+
+        let result = handler.transform_bundle_in_place(&mut transformed, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::AlreadyTransformed
+            ))
+        ));
+    }
+
+    #[test]
+    fn untransform_rejects_original_input() {
+        let handler = DdsHandler;
+        let mut input = create_valid_bc1_dds_with_dimensions(64, 64, 2);
+        let mut output = vec![0u8; input.len()];
+
+        let result = handler.untransform(&input, &mut output);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::NotTransformed
+            ))
+        ));
+
+        let result = handler.untransform_in_place(&mut input);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::NotTransformed
+            ))
+        ));
+    }
+
+    #[rstest]
+    #[case::bc1_partial_block(create_valid_bc1_dds_with_dimensions(64, 64, 2), 100)]
+    #[case::bc2_whole_blocks(create_valid_dds_with_dimensions(DdsFormat::BC2, 64, 64, 1), 32)]
+    #[case::bc7_passthrough(create_valid_bc7_dds(), 7)]
+    #[case::header_only(create_valid_bc1_dds_with_dimensions(64, 64, 1), 64 * 64 / 2)]
+    #[case::partial_block_only(create_valid_bc1_dds_with_dimensions(64, 64, 1), 64 * 64 / 2 - 5)]
+    fn truncated_input_roundtrips_when_allowed(
+        #[case] mut input: Vec<u8>,
+        #[case] removed_bytes: usize,
+    ) {
+        input.truncate(input.len() - removed_bytes);
+        let handler = DdsHandler;
+        let bundle = TransformBundle::<NoEstimation>::default_all()
+            .with_passthrough_unsupported(true)
+            .with_allow_truncated(true)
+            .with_checksum(true);
+
+        let mut transformed = vec![0u8; input.len()];
+        handler
+            .transform_bundle(&input, &mut transformed, &bundle)
+            .unwrap();
+        let header = read_header(&transformed);
+        assert!(header.is_truncated());
+        assert!(header.has_checksum());
+
+        let mut in_place = input.clone();
+        handler
+            .transform_bundle_in_place(&mut in_place, &bundle)
+            .unwrap();
+        assert_eq!(in_place, transformed);
+
+        let mut restored = vec![0u8; transformed.len()];
+        handler.untransform(&transformed, &mut restored).unwrap();
+        assert_eq!(restored, input);
+
+        handler.untransform_in_place(&mut in_place).unwrap();
+        assert_eq!(in_place, input);
+    }
+
+    #[test]
+    fn truncated_flag_is_only_set_for_truncated_input() {
+        let input = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_allow_truncated(true);
+
+        let mut transformed = vec![0u8; input.len()];
+        DdsHandler
+            .transform_bundle(&input, &mut transformed, &bundle)
+            .unwrap();
+        assert!(!read_header(&transformed).is_truncated());
+
+        // Without the flag, a truncated transformed file is still rejected
+        transformed.truncate(transformed.len() - 8);
+        let mut restored = vec![0u8; transformed.len()];
+        let result = DdsHandler.untransform(&transformed, &mut restored);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::InputTooShortForStatedTextureSize { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn transform_bundle_in_place_rejects_insufficient_data_for_declared_size() {
+        let mut data = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        data.truncate(data.len() - 100);
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let result = DdsHandler.transform_bundle_in_place(&mut data, &bundle);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::InputTooShortForStatedTextureSize { .. }
+            ))
+        ));
+    }
+
+    #[rstest]
+    #[case::bc1(create_valid_bc1_dds_with_dimensions(64, 64, 2))]
+    #[case::bc7(create_valid_bc7_dds())]
+    #[case::leftover(create_bc1_dds_with_leftover_data(32, 32, b"Leftover data"))]
+    fn checksum_roundtrip_in_and_out_of_place(#[case] input: Vec<u8>) {
+        let handler = DdsHandler;
+        let bundle = TransformBundle::<NoEstimation>::default_all()
+            .with_passthrough_unsupported(true)
+            .with_checksum(true);
+
+        let mut transformed = vec![0u8; input.len()];
+        handler
+            .transform_bundle(&input, &mut transformed, &bundle)
+            .unwrap();
+        assert!(read_header(&transformed).has_checksum());
+
+        let mut in_place = input.clone();
+        handler
+            .transform_bundle_in_place(&mut in_place, &bundle)
+            .unwrap();
+        assert_eq!(in_place, transformed);
+
+        let mut restored = vec![0u8; input.len()];
+        handler.untransform(&transformed, &mut restored).unwrap();
+        assert_eq!(restored, input);
+
+        handler.untransform_in_place(&mut in_place).unwrap();
+        assert_eq!(in_place, input);
+    }
+
+    #[test]
+    fn untransform_detects_corrupted_texture_data_with_checksum() {
+        let handler = DdsHandler;
+        let input = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_checksum(true);
+
+        let mut transformed = vec![0u8; input.len()];
+        handler
+            .transform_bundle(&input, &mut transformed, &bundle)
+            .unwrap();
+        let last = transformed.len() - 1;
+        transformed[last] ^= 0xFF;
+
+        let mut restored = vec![0u8; input.len()];
+        assert!(matches!(
+            handler.untransform(&transformed, &mut restored),
+            Err(TransformError::ChecksumMismatch { .. })
+        ));
+
+        // The failed in-place untransform leaves the data as it was
+        let corrupted = transformed.clone();
+        assert!(matches!(
+            handler.untransform_in_place(&mut transformed),
+            Err(TransformError::ChecksumMismatch { .. })
+        ));
+        assert_eq!(transformed, corrupted);
+    }
+
+    #[test]
+    fn transform_in_place_leaves_data_unchanged_on_estimator_error() {
+        let handler = DdsHandler;
+        let input = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        let bundle = TransformBundle::new()
+            .with_bc1_auto(Bc1AutoTransformBuilder::new(FailingEstimator))
+            .with_checksum(true);
+
+        let mut data = input.clone();
+        assert!(handler
+            .transform_bundle_in_place(&mut data, &bundle)
+            .is_err());
+        assert_eq!(data, input);
+    }
+
+    #[test]
+    fn checksum_is_rejected_when_reserved_fields_are_used() {
+        let handler = DdsHandler;
+        let mut input = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        input[DDS_CHECKSUM_OFFSET] = 0x42;
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_checksum(true);
+
+        let mut transformed = vec![0u8; input.len()];
+        assert!(matches!(
+            handler.transform_bundle(&input, &mut transformed, &bundle),
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::ChecksumUnsupported
+            ))
+        ));
+
+        let mut data = input.clone();
+        assert!(matches!(
+            handler.transform_bundle_in_place(&mut data, &bundle),
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::ChecksumUnsupported
+            ))
+        ));
+        assert_eq!(data, input);
+
+        // Without a checksum, such files are still transformed
+        let bundle = bundle.with_checksum(false);
+        handler
+            .transform_bundle(&input, &mut transformed, &bundle)
+            .unwrap();
+        assert_eq!(transformed[DDS_CHECKSUM_OFFSET], 0x42);
+        let mut restored = vec![0u8; input.len()];
+        handler.untransform(&transformed, &mut restored).unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[rstest]
+    #[case::bc1(create_valid_bc1_dds(), TransformFormat::Bc1, false)]
+    #[case::passthrough(create_valid_bc7_dds(), TransformFormat::None, true)]
+    fn transform_bundle_with_report_reports_format_and_settings(
+        #[case] input: Vec<u8>,
+        #[case] format: TransformFormat,
+        #[case] passthrough: bool,
+    ) {
+        let bundle =
+            TransformBundle::<NoEstimation>::default_all().with_passthrough_unsupported(true);
+        let mut output = vec![0u8; input.len()];
+        let mut report = TransformReport::default();
+        DdsHandler
+            .transform_bundle_with_report(&input, &mut output, &bundle, &mut report)
+            .unwrap();
+
+        assert_eq!(report.format, Some(format));
+        assert_eq!(
+            report.details,
+            Some(TransformDetails::from_header(read_header(&output)))
+        );
+        assert_eq!(report.details == Some(TransformDetails::None), passthrough);
+    }
+
+    fn read_header(data: &[u8]) -> TransformHeader {
+        // SAFETY: test data always starts with a standard transform header
+        unsafe { TransformHeader::read_from_ptr(data.as_ptr()) }
+    }
 }
@@ -0,0 +1,222 @@
+//! Texture inspection implementation for DDS files.
+
+use super::{format_conversion::dds_format_to_transform_format, DdsHandler};
+use crate::dds::{constants::*, parse_dds::parse_dds_ignore_magic};
+use dxt_lossless_transform_file_formats_api::{
+    embed::{TransformDetails, TransformHeader},
+    error::{FormatHandlerError, TransformResult},
+    handlers::{FileFormatInspection, TextureInfo},
+};
+use endian_writer::{EndianReader, LittleEndianReader};
+
+impl FileFormatInspection for DdsHandler {
+    fn inspect(&self, input: &[u8]) -> TransformResult<TextureInfo> {
+        // Parse header ignoring the magic, so transformed files are accepted too
+        let info =
+            parse_dds_ignore_magic(input).ok_or(FormatHandlerError::InvalidInputFileHeader)?;
+        let is_dx10 = info.data_offset as usize == DDS_HEADER_SIZE + DX10_HEADER_SIZE;
+
+        // SAFETY: parse_dds_ignore_magic succeeded, so input is at least DDS_HEADER_SIZE (128) bytes long,
+        // and at least DDS_HEADER_SIZE + DX10_HEADER_SIZE (148) bytes long if a DX10 header is present.
+        let mut reader = unsafe { LittleEndianReader::new(input.as_ptr()) };
+        let magic = unsafe { reader.read_u32_at(0) };
+        let flags = unsafe { reader.read_u32_at(DDS_FLAGS_OFFSET as isize) };
+        let height = unsafe { reader.read_u32_at(DDS_HEIGHT_OFFSET as isize) };
+        let width = unsafe { reader.read_u32_at(DDS_WIDTH_OFFSET as isize) };
+        let raw_depth = unsafe { reader.read_u32_at(DDS_DEPTH_OFFSET as isize) };
+        let raw_mipmap_count = unsafe { reader.read_u32_at(DDS_MIPMAP_COUNT_OFFSET as isize) };
+        let caps2 = unsafe { reader.read_u32_at(DDS_CAPS2_OFFSET as isize) };
+
+        // Determine mipmap count (same rules as used for the data length)
+        let mip_count = if (flags & DDSD_MIPMAPCOUNT) != 0 {
+            raw_mipmap_count.max(1)
+        } else {
+            1
+        };
+
+        let depth = if (flags & DDSD_DEPTH) != 0 && (caps2 & DDSCAPS2_VOLUME) != 0 {
+            raw_depth.max(1)
+        } else {
+            1
+        };
+
+        let (array_size, face_count) = if is_dx10 {
+            let misc_flag = unsafe { reader.read_u32_at(DX10_MISC_FLAG_OFFSET as isize) };
+            let array_size = unsafe { reader.read_u32_at(DX10_ARRAY_SIZE_OFFSET as isize) };
+            let face_count = if (misc_flag & DDS_RESOURCE_MISC_TEXTURECUBE) != 0 {
+                6
+            } else {
+                1
+            };
+            (array_size.max(1), face_count)
+        } else if (caps2 & DDSCAPS2_CUBEMAP) != 0 {
+            // Legacy cube maps may only store a subset of the faces
+            (1, (caps2 & DDSCAPS2_CUBEMAP_ALLFACES).count_ones())
+        } else {
+            (1, 1)
+        };
+
+        // Transformed files have the DDS magic replaced with a transform header
+        let transform_details = (magic != DDS_MAGIC).then(|| {
+            // SAFETY: input is at least DDS_HEADER_SIZE bytes long, which fits even an extended header
+            TransformDetails::from_header(unsafe { TransformHeader::read_from_ptr(input.as_ptr()) })
+        });
+
+        Ok(TextureInfo {
+            format: dds_format_to_transform_format(info.format, true).ok(),
+            width,
+            height,
+            depth,
+            mip_count,
+            array_size,
+            face_count,
+            data_offset: info.data_offset as usize,
+            data_length: info.data_length as usize,
+            transform_details,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_prelude::*;
+    use dxt_lossless_transform_api_common::estimate::NoEstimation;
+    use dxt_lossless_transform_file_formats_api::{
+        embed::TransformFormat, handlers::FileFormatHandler, TransformBundle, TransformError,
+    };
+    use endian_writer::{EndianWriter, LittleEndianWriter};
+
+    fn write_u32(data: &mut [u8], value: u32, offset: usize) {
+        unsafe { LittleEndianWriter::new(data.as_mut_ptr()).write_u32_at(value, offset as isize) };
+    }
+
+    fn transform(input: &[u8], bundle: &TransformBundle<NoEstimation>) -> Vec<u8> {
+        let mut output = vec![0u8; input.len()];
+        DdsHandler
+            .transform_bundle(input, &mut output, bundle)
+            .unwrap();
+        output
+    }
+
+    #[test]
+    fn inspect_reports_original_file_metadata() {
+        let input = create_valid_bc1_dds_with_dimensions(64, 32, 3);
+
+        let info = DdsHandler.inspect(&input).unwrap();
+        assert_eq!(
+            info,
+            TextureInfo {
+                format: Some(TransformFormat::Bc1),
+                width: 64,
+                height: 32,
+                depth: 1,
+                mip_count: 3,
+                array_size: 1,
+                face_count: 1,
+                data_offset: DDS_HEADER_SIZE,
+                data_length: input.len() - DDS_HEADER_SIZE,
+                transform_details: None,
+            }
+        );
+        assert!(!info.is_transformed());
+    }
+
+    #[test]
+    fn inspect_decodes_transform_details() {
+        let input = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        let transformed = transform(&input, &TransformBundle::default_all());
+
+        let original = DdsHandler.inspect(&input).unwrap();
+        let info = DdsHandler.inspect(&transformed).unwrap();
+        assert!(info.is_transformed());
+        assert!(matches!(
+            info.transform_details,
+            Some(TransformDetails::Bc1(_))
+        ));
+        assert_eq!(
+            TextureInfo {
+                transform_details: None,
+                ..info
+            },
+            original
+        );
+    }
+
+    #[test]
+    fn inspect_decodes_passthrough() {
+        let input = create_valid_bc7_dds();
+        let bundle = TransformBundle::default_all().with_passthrough_unsupported(true);
+        let transformed = transform(&input, &bundle);
+
+        let info = DdsHandler.inspect(&transformed).unwrap();
+        assert_eq!(info.format, Some(TransformFormat::Bc7));
+        assert_eq!(info.transform_details, Some(TransformDetails::None));
+    }
+
+    #[test]
+    fn inspect_only_requires_headers() {
+        let input = create_valid_bc1_dds_with_dimensions(256, 256, 1);
+
+        let info = DdsHandler.inspect(&input[..DDS_HEADER_SIZE]).unwrap();
+        assert_eq!(info.data_length, 256 * 256 / 2);
+    }
+
+    #[test]
+    fn inspect_reports_legacy_cube_map_faces() {
+        let mut input = create_valid_bc1_dds_with_dimensions(16, 16, 1);
+        write_u32(
+            &mut input,
+            DDSCAPS2_CUBEMAP | DDSCAPS2_CUBEMAP_ALLFACES,
+            DDS_CAPS2_OFFSET,
+        );
+
+        let info = DdsHandler.inspect(&input).unwrap();
+        assert_eq!(info.face_count, 6);
+        assert_eq!(info.array_size, 1);
+    }
+
+    #[test]
+    fn inspect_reports_dx10_array_size_and_faces() {
+        let mut input = create_valid_bc7_dds();
+        write_u32(
+            &mut input,
+            DDS_RESOURCE_MISC_TEXTURECUBE,
+            DX10_MISC_FLAG_OFFSET,
+        );
+        write_u32(&mut input, 4, DX10_ARRAY_SIZE_OFFSET);
+
+        let info = DdsHandler.inspect(&input).unwrap();
+        assert_eq!(info.format, Some(TransformFormat::Bc7));
+        assert_eq!(info.data_offset, DDS_DX10_TOTAL_HEADER_SIZE);
+        assert_eq!(info.face_count, 6);
+        assert_eq!(info.array_size, 4);
+    }
+
+    #[test]
+    fn inspect_reports_volume_depth() {
+        let mut input = create_valid_bc1_dds_with_dimensions(16, 16, 1);
+        let flags = u32::from_le_bytes(
+            input[DDS_FLAGS_OFFSET..DDS_FLAGS_OFFSET + 4]
+                .try_into()
+                .unwrap(),
+        );
+        write_u32(&mut input, flags | DDSD_DEPTH, DDS_FLAGS_OFFSET);
+        write_u32(&mut input, 8, DDS_DEPTH_OFFSET);
+        write_u32(&mut input, DDSCAPS2_VOLUME, DDS_CAPS2_OFFSET);
+
+        let info = DdsHandler.inspect(&input).unwrap();
+        assert_eq!(info.depth, 8);
+    }
+
+    #[test]
+    fn inspect_rejects_invalid_input() {
+        let result = DdsHandler.inspect(&[0u8; DDS_HEADER_SIZE - 1]);
+        assert!(matches!(
+            result,
+            Err(TransformError::FormatHandler(
+                FormatHandlerError::InvalidInputFileHeader
+            ))
+        ));
+    }
+}
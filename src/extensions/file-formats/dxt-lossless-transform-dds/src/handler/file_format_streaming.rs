@@ -0,0 +1,404 @@
+//! Streaming support for DDS files.
+
+use super::{file_format_handler::ensure_transformed, DdsHandler};
+use crate::dds::constants::{
+    DDS_CHECKSUM_OFFSET, DDS_CHECKSUM_SIZE, DDS_HEADER_SIZE, DDS_MAGIC, DX10_HEADER_SIZE,
+};
+use dxt_lossless_transform_file_formats_api::{
+    embed::{TransformHeader, TRANSFORM_HEADER_SIZE},
+    error::{FormatHandlerError, TransformResult},
+    handlers::FileFormatStreaming,
+};
+
+impl FileFormatStreaming for DdsHandler {
+    fn max_header_size(&self) -> usize {
+        DDS_HEADER_SIZE + DX10_HEADER_SIZE
+    }
+
+    fn embed_transform_header(
+        &self,
+        headers: &mut [u8],
+        header: TransformHeader,
+    ) -> TransformResult<()> {
+        if headers.len() < TRANSFORM_HEADER_SIZE {
+            return Err(FormatHandlerError::OutputBufferTooSmall {
+                required: TRANSFORM_HEADER_SIZE,
+                actual: headers.len(),
+            }
+            .into());
+        }
+
+        // The DDS magic only has room for a standard header
+        if header.is_extended() {
+            return Err(FormatHandlerError::ExtendedHeaderNotSupported.into());
+        }
+
+        // Embed transform header (overwrites DDS magic)
+        // SAFETY: headers.as_mut_ptr() is valid for writes of TRANSFORM_HEADER_SIZE bytes, validated above
+        unsafe {
+            header.write_to_ptr(headers.as_mut_ptr());
+        }
+
+        Ok(())
+    }
+
+    fn restore_headers(&self, headers: &mut [u8]) -> TransformResult<TransformHeader> {
+        if headers.len() < TRANSFORM_HEADER_SIZE {
+            return Err(FormatHandlerError::InputTooShort {
+                required: TRANSFORM_HEADER_SIZE,
+                actual: headers.len(),
+            }
+            .into());
+        }
+        ensure_transformed(headers)?;
+
+        // The DDS magic only has room for a standard header
+        if TransformHeader::size_from_first_byte(headers[0]) != TRANSFORM_HEADER_SIZE {
+            return Err(FormatHandlerError::ExtendedHeaderNotSupported.into());
+        }
+
+        // SAFETY: headers.as_ptr() is valid for reads of TRANSFORM_HEADER_SIZE bytes, validated above,
+        // and the header is not extended.
+        let header = unsafe { TransformHeader::read_from_ptr(headers.as_ptr()) };
+
+        // Restore DDS magic
+        headers[0..4].copy_from_slice(&DDS_MAGIC.to_le_bytes());
+        Ok(header)
+    }
+
+    fn embed_checksum(&self, headers: &mut [u8], checksum: u64) -> bool {
+        // Only use the reserved fields if nobody else does, so they can be zeroed on restore
+        let end = DDS_CHECKSUM_OFFSET + DDS_CHECKSUM_SIZE;
+        match headers.get_mut(DDS_CHECKSUM_OFFSET..end) {
+            Some(reserved) if reserved.iter().all(|&x| x == 0) => {
+                reserved.copy_from_slice(&checksum.to_le_bytes());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn restore_checksum(&self, headers: &mut [u8]) -> TransformResult<u64> {
+        let end = DDS_CHECKSUM_OFFSET + DDS_CHECKSUM_SIZE;
+        if headers.len() < end {
+            return Err(FormatHandlerError::InputTooShort {
+                required: end,
+                actual: headers.len(),
+            }
+            .into());
+        }
+
+        let reserved = &mut headers[DDS_CHECKSUM_OFFSET..end];
+        let mut checksum = [0u8; DDS_CHECKSUM_SIZE];
+        checksum.copy_from_slice(reserved);
+        reserved.fill(0);
+        Ok(u64::from_le_bytes(checksum))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds::DdsFormat;
+    use crate::test_prelude::*;
+    use dxt_lossless_transform_api_common::estimate::NoEstimation;
+    use dxt_lossless_transform_bc1_api::Bc1ManualTransformBuilder;
+    use dxt_lossless_transform_bc2_api::Bc2ManualTransformBuilder;
+    use dxt_lossless_transform_file_formats_api::{
+        file_io::{
+            transform_reader_to_writer, transform_reader_to_writer_with_segment_size,
+            untransform_reader_to_writer_with_segment_size, FileOperationError,
+        },
+        handlers::FileFormatHandler,
+        TransformBundle, TransformError,
+    };
+    use std::io::Cursor;
+
+    fn transform_slice(input: &[u8], bundle: &TransformBundle<NoEstimation>) -> Vec<u8> {
+        let mut output = vec![0u8; input.len()];
+        DdsHandler
+            .transform_bundle(input, &mut output, bundle)
+            .unwrap();
+        output
+    }
+
+    #[rstest]
+    #[case::bc1_single_segment(create_valid_bc1_dds_with_dimensions(64, 64, 3), 1 << 20, false)]
+    #[case::bc1_many_segments(create_valid_bc1_dds_with_dimensions(64, 64, 3), 48, false)]
+    #[case::bc1_split_colours(create_valid_bc1_dds_with_dimensions(64, 64, 3), 48, true)]
+    #[case::bc2_many_segments(
+        create_valid_dds_with_dimensions(DdsFormat::BC2, 64, 32, 2),
+        40,
+        false
+    )]
+    #[case::bc2_split_colours(
+        create_valid_dds_with_dimensions(DdsFormat::BC2, 64, 32, 2),
+        40,
+        true
+    )]
+    #[case::leftover(
+        create_bc1_dds_with_leftover_data(32, 32, b"Leftover data after the texture"),
+        64,
+        true
+    )]
+    fn stream_matches_slice_roundtrip(
+        #[case] mut input: Vec<u8>,
+        #[case] segment_size: usize,
+        #[case] split_colours: bool,
+    ) {
+        // Vary the block contents, so misplaced data is detected
+        for (x, byte) in input
+            .iter_mut()
+            .enumerate()
+            .skip(DDS_HEADER_SIZE + DX10_HEADER_SIZE)
+        {
+            *byte = (x * 7) as u8;
+        }
+
+        let bundle = TransformBundle::<NoEstimation>::new()
+            .with_bc1_manual(Bc1ManualTransformBuilder::new().split_colour_endpoints(split_colours))
+            .with_bc2_manual(
+                Bc2ManualTransformBuilder::new().split_colour_endpoints(split_colours),
+            );
+        let expected = transform_slice(&input, &bundle);
+
+        let mut transformed = Cursor::new(Vec::new());
+        transform_reader_to_writer_with_segment_size(
+            &DdsHandler,
+            input.as_slice(),
+            &mut transformed,
+            &bundle,
+            segment_size,
+        )
+        .unwrap();
+        assert_eq!(transformed.get_ref(), &expected);
+
+        let mut restored = Vec::new();
+        transformed.set_position(0);
+        untransform_reader_to_writer_with_segment_size(
+            &DdsHandler,
+            &mut transformed,
+            &mut restored,
+            segment_size,
+        )
+        .unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[test]
+    fn stream_passthrough_roundtrip() {
+        let input = create_valid_bc7_dds();
+        let bundle =
+            TransformBundle::<NoEstimation>::default_all().with_passthrough_unsupported(true);
+        let expected = transform_slice(&input, &bundle);
+
+        let mut transformed = Cursor::new(Vec::new());
+        transform_reader_to_writer(&DdsHandler, input.as_slice(), &mut transformed, &bundle)
+            .unwrap();
+        assert_eq!(transformed.get_ref(), &expected);
+    }
+
+    #[test]
+    fn stream_transform_rejects_truncated_texture_data() {
+        let mut input = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        input.truncate(input.len() - 100);
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+
+        let result = transform_reader_to_writer(
+            &DdsHandler,
+            input.as_slice(),
+            Cursor::new(Vec::new()),
+            &bundle,
+        );
+        assert!(matches!(
+            result,
+            Err(FileOperationError::Transform(
+                TransformError::FormatHandler(
+                    FormatHandlerError::InputTooShortForStatedTextureSize { .. }
+                )
+            ))
+        ));
+    }
+
+    #[rstest]
+    #[case::partial_block(create_valid_bc1_dds_with_dimensions(64, 64, 1), 100)]
+    #[case::less_than_one_block(create_valid_bc1_dds_with_dimensions(64, 64, 1), 64 * 64 / 2 - 4)]
+    #[case::complete(create_valid_bc1_dds_with_dimensions(64, 64, 1), 0)]
+    #[case::large_leftover(create_bc1_dds_with_leftover_data(64, 64, &[0xAB; 1 << 16]), 0)]
+    fn stream_transform_lenient_matches_slice(#[case] mut input: Vec<u8>, #[case] missing: usize) {
+        input.truncate(input.len() - missing);
+        for (x, byte) in input.iter_mut().enumerate().skip(DDS_HEADER_SIZE) {
+            *byte = (x * 7) as u8;
+        }
+        let bundle = TransformBundle::<NoEstimation>::default_all()
+            .with_allow_truncated(true)
+            .with_checksum(true);
+        let expected = transform_slice(&input, &bundle);
+
+        let mut transformed = Cursor::new(Vec::new());
+        transform_reader_to_writer_with_segment_size(
+            &DdsHandler,
+            input.as_slice(),
+            &mut transformed,
+            &bundle,
+            48,
+        )
+        .unwrap();
+        assert_eq!(transformed.get_ref(), &expected);
+
+        let mut restored = Vec::new();
+        transformed.set_position(0);
+        untransform_reader_to_writer_with_segment_size(
+            &DdsHandler,
+            &mut transformed,
+            &mut restored,
+            48,
+        )
+        .unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[test]
+    fn stream_transform_rejects_already_transformed_input() {
+        let bundle = TransformBundle::<NoEstimation>::default_all();
+        let transformed = transform_slice(&create_valid_bc1_dds(), &bundle);
+
+        let result = transform_reader_to_writer(
+            &DdsHandler,
+            transformed.as_slice(),
+            Cursor::new(Vec::new()),
+            &bundle,
+        );
+        assert!(matches!(
+            result,
+            Err(FileOperationError::Transform(
+                TransformError::FormatHandler(FormatHandlerError::AlreadyTransformed)
+            ))
+        ));
+    }
+
+    #[test]
+    fn stream_untransform_rejects_original_input() {
+        let result = untransform_reader_to_writer_with_segment_size(
+            &DdsHandler,
+            Cursor::new(create_valid_bc1_dds()),
+            Vec::new(),
+            64,
+        );
+        assert!(matches!(
+            result,
+            Err(FileOperationError::Transform(
+                TransformError::FormatHandler(FormatHandlerError::NotTransformed)
+            ))
+        ));
+    }
+
+    #[rstest]
+    #[case::truncated(create_valid_bc1_dds_with_dimensions(64, 64, 1), 100)]
+    #[case::large_leftover(create_bc1_dds_with_leftover_data(64, 64, &[0xAB; 4 << 20]), 0)]
+    fn stream_untransform_matches_slice(#[case] mut input: Vec<u8>, #[case] removed_bytes: usize) {
+        input.truncate(input.len() - removed_bytes);
+        let bundle = TransformBundle::<NoEstimation>::default_all()
+            .with_allow_truncated(true)
+            .with_checksum(true);
+        let transformed = transform_slice(&input, &bundle);
+
+        let mut restored = Vec::new();
+        untransform_reader_to_writer_with_segment_size(
+            &DdsHandler,
+            Cursor::new(transformed),
+            &mut restored,
+            64,
+        )
+        .unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[test]
+    fn restore_headers_reverses_embed_transform_header() {
+        let input = create_valid_bc1_dds();
+        let transformed = transform_slice(&input, &TransformBundle::default_all());
+
+        let mut headers = transformed[..DDS_HEADER_SIZE].to_vec();
+        let header = DdsHandler.restore_headers(&mut headers).unwrap();
+        assert_eq!(headers, input[..DDS_HEADER_SIZE]);
+
+        DdsHandler
+            .embed_transform_header(&mut headers, header)
+            .unwrap();
+        assert_eq!(headers, transformed[..DDS_HEADER_SIZE]);
+    }
+
+    #[rstest]
+    #[case::single_segment(1 << 20)]
+    #[case::many_segments(48)]
+    fn stream_checksum_matches_slice_roundtrip(#[case] segment_size: usize) {
+        let input = create_bc1_dds_with_leftover_data(64, 64, b"Leftover data after the texture");
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_checksum(true);
+        let expected = transform_slice(&input, &bundle);
+
+        let mut transformed = Cursor::new(Vec::new());
+        transform_reader_to_writer_with_segment_size(
+            &DdsHandler,
+            input.as_slice(),
+            &mut transformed,
+            &bundle,
+            segment_size,
+        )
+        .unwrap();
+        assert_eq!(transformed.get_ref(), &expected);
+
+        let mut restored = Vec::new();
+        transformed.set_position(0);
+        untransform_reader_to_writer_with_segment_size(
+            &DdsHandler,
+            &mut transformed,
+            &mut restored,
+            segment_size,
+        )
+        .unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[test]
+    fn stream_untransform_detects_corrupted_texture_data() {
+        let input = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_checksum(true);
+        let mut transformed = transform_slice(&input, &bundle);
+        let last = transformed.len() - 1;
+        transformed[last] ^= 0xFF;
+
+        let result = untransform_reader_to_writer_with_segment_size(
+            &DdsHandler,
+            Cursor::new(transformed),
+            Vec::new(),
+            64,
+        );
+        assert!(matches!(
+            result,
+            Err(FileOperationError::Transform(
+                TransformError::ChecksumMismatch { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn stream_transform_rejects_checksum_when_reserved_fields_are_used() {
+        let mut input = create_valid_bc1_dds_with_dimensions(64, 64, 1);
+        input[DDS_CHECKSUM_OFFSET] = 0x42;
+        let bundle = TransformBundle::<NoEstimation>::default_all().with_checksum(true);
+
+        let result = transform_reader_to_writer(
+            &DdsHandler,
+            input.as_slice(),
+            Cursor::new(Vec::new()),
+            &bundle,
+        );
+        assert!(matches!(
+            result,
+            Err(FileOperationError::Transform(
+                TransformError::FormatHandler(FormatHandlerError::ChecksumUnsupported)
+            ))
+        ));
+    }
+}
@@ -2,6 +2,8 @@
 
 mod file_format_detection;
 mod file_format_handler;
+mod file_format_inspection;
+mod file_format_streaming;
 mod file_format_untransform_detection;
 mod format_conversion;
 
@@ -11,9 +13,25 @@ mod file_format_block_extraction;
 #[cfg(feature = "debug")]
 mod file_format_check;
 
+/// C exports for the DDS handler.
+#[cfg(feature = "c-exports")]
+pub mod exports;
+
 /// Handler for DDS file format.
 ///
 /// This handler supports BC1/BC2/BC3/BC7 formats within DDS files,
 /// embedding transform details in the 4-byte DDS magic header.
 /// Currently only BC1 supports configurable transform options.
+///
+/// When [`TransformBundle::with_checksum`] is enabled, the checksum of the original texture
+/// data is stored in the unused `dwReserved1` header fields. Files which already use those
+/// fields fail with [`FormatHandlerError::ChecksumUnsupported`].
+///
+/// When [`TransformBundle::with_allow_truncated`] is enabled, files with less texture data than
+/// the header states are transformed up to the last whole block; the remaining bytes are copied
+/// verbatim, like any data after the texture.
+///
+/// [`TransformBundle::with_checksum`]: dxt_lossless_transform_file_formats_api::TransformBundle::with_checksum
+/// [`TransformBundle::with_allow_truncated`]: dxt_lossless_transform_file_formats_api::TransformBundle::with_allow_truncated
+/// [`FormatHandlerError::ChecksumUnsupported`]: dxt_lossless_transform_file_formats_api::error::FormatHandlerError::ChecksumUnsupported
 pub struct DdsHandler;
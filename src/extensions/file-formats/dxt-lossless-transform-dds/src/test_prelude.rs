@@ -19,6 +19,30 @@ pub use std::is_x86_feature_detected;
 // External crates commonly used in tests
 pub use rstest::rstest;
 
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
+
+/// A size estimator which always fails, for testing error paths of automatic transforms.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FailingEstimator;
+
+impl SizeEstimationOperations for FailingEstimator {
+    type Error = &'static str;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        Ok(len_bytes)
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        _input_ptr: *const u8,
+        _len_bytes: usize,
+        _output_ptr: *mut u8,
+        _output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        Err("estimation failed")
+    }
+}
+
 // Common DDS test data helpers
 use crate::dds::constants::*;
 use endian_writer::{EndianWriter, LittleEndianWriter};
@@ -261,6 +261,7 @@ where
     let transform_options = Bc1EstimateSettings {
         size_estimator,
         use_all_decorrelation_modes,
+        progress: None,
     };
 
     // Allocate output buffer for the transformed data
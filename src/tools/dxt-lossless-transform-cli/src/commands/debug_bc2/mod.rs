@@ -246,6 +246,7 @@ where
     let transform_options = Bc2EstimateSettings {
         size_estimator,
         use_all_decorrelation_modes,
+        progress: None,
     };
 
     // Allocate output buffer for the transformed data
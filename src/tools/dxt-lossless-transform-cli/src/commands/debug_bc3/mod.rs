@@ -245,6 +245,7 @@ where
     let transform_options = Bc3EstimateSettings {
         size_estimator,
         use_all_decorrelation_modes,
+        progress: None,
     };
 
     // Allocate output buffer for the transformed data
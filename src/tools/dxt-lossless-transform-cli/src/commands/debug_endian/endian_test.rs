@@ -1,5 +1,5 @@
 use super::file_compare;
-use crate::util::all_handlers;
+use crate::util::debug_handlers;
 use dxt_lossless_transform_file_formats_api::embed::TransformFormat;
 use dxt_lossless_transform_file_formats_debug::{get_transform_format, TransformFormatFilter};
 use std::fs;
@@ -46,7 +46,7 @@ const BIG_ENDIAN_TARGET: &str = "powerpc64-unknown-linux-gnu";
 /// Check if a file is a supported [`TransformFormat`] for endian testing using handlers
 /// Currently supports BC1 and BC2, excludes BC3 and BC7 (not ready yet)
 fn is_supported_format(file_path: &Path) -> Result<bool, EndianTestError> {
-    match get_transform_format(file_path, &all_handlers(), TransformFormatFilter::All) {
+    match get_transform_format(file_path, &debug_handlers(), TransformFormatFilter::All) {
         Ok(Some(format)) => {
             match format {
                 TransformFormat::Bc1 | TransformFormat::Bc2 => Ok(true),
@@ -1,7 +1,7 @@
 use std::error::Error;
 use std::path::{Path, PathBuf};
 
-use crate::util::all_handlers;
+use crate::util::{all_handlers, debug_handlers};
 use dxt_lossless_transform_api_common::estimate::NoEstimation;
 use dxt_lossless_transform_bc1_api::{Bc1ManualTransformBuilder, YCoCgVariant};
 use dxt_lossless_transform_bc2_api::Bc2ManualTransformBuilder;
@@ -22,7 +22,7 @@ pub fn handle_transform_single_file(
 
     // Detect the format of the input file using handlers
     let detected_format =
-        match get_transform_format(&input_file, &all_handlers(), TransformFormatFilter::All)? {
+        match get_transform_format(&input_file, &debug_handlers(), TransformFormatFilter::All)? {
             Some(format) => format,
             None => {
                 return Err(format!(
@@ -76,8 +76,8 @@ where
             let bundle = build_bundle(variant, split);
 
             // Try to transform with this combination
-            match file_io::transform_file_with_multiple_handlers(
-                all_handlers(),
+            match file_io::transform_file_with_registry(
+                &all_handlers(),
                 input_file,
                 output_file,
                 &bundle,
@@ -134,8 +134,8 @@ pub fn handle_untransform_single_file(
             let filename = input_path.file_name().ok_or("Invalid file name")?;
             let output_path = output_dir.join(filename);
 
-            file_io::untransform_file_with_multiple_handlers(
-                all_handlers(),
+            file_io::untransform_file_with_registry(
+                &all_handlers::<NoEstimation>(),
                 &input_path,
                 &output_path,
             )?;
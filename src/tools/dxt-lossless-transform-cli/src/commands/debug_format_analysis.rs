@@ -1,6 +1,6 @@
 //! Format analysis command for analyzing files recursively and grouping by TransformFormat.
 
-use crate::util::{all_handlers, find_all_files};
+use crate::util::{debug_handlers, find_all_files};
 use argh::FromArgs;
 use bytesize::ByteSize;
 use core::error::Error;
@@ -202,8 +202,8 @@ pub fn handle_debug_format_analysis_command(
 
 /// Detect the transform format of a file using available handlers
 fn detect_file_format(file_path: &Path) -> Option<TransformFormat> {
-    // Use the existing all_handlers function properly
-    match get_transform_format(file_path, &all_handlers(), TransformFormatFilter::All) {
+    // Use the existing debug_handlers function properly
+    match get_transform_format(file_path, &debug_handlers(), TransformFormatFilter::All) {
         Ok(Some(format)) => Some(format),
         Ok(None) => None, // No handler supports this format
         Err(_) => None,   // Handler failed to parse this file or I/O error
@@ -1,15 +1,13 @@
-use crate::util::{all_handlers, canonicalize_cli_path, find_all_files, Throughput};
+use crate::util::{all_handlers, canonicalize_cli_path, report_batch_results, Throughput};
 use argh::FromArgs;
 use bytesize::ByteSize;
-use dxt_lossless_transform_api_common::estimate::NoEstimation;
-use dxt_lossless_transform_bc1_api::{Bc1AutoTransformBuilder, Bc1ManualTransformBuilder};
-use dxt_lossless_transform_file_formats_api::{file_io, TransformBundle};
-
-use std::{
-    path::{Path, PathBuf},
-    time::Instant,
+use dxt_lossless_transform_file_formats_api::{
+    file_io::{self, BatchJob, BatchOptions},
+    TransformBundle, TransformPreset,
 };
 
+use std::{path::PathBuf, time::Instant};
+
 #[derive(FromArgs, Debug)]
 /// Transform DDS files using lossless compression optimization (Demo CLI - use API for production)
 #[argh(subcommand, name = "transform")]
@@ -23,37 +21,20 @@ pub struct TransformCmd {
     pub output: PathBuf,
 
     /// compression preset: low, medium, optimal, max [default: optimal]
-    #[argh(option, default = "CompressionPreset::Optimal")]
-    pub preset: CompressionPreset,
-}
+    #[argh(option, default = "TransformPreset::Optimal")]
+    pub preset: TransformPreset,
 
-/// Compression preset options for DXT lossless transform
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum CompressionPreset {
-    /// Default manual settings for fast processing
-    Low,
-    /// Automatic optimization using LTU estimator
-    Medium,
-    /// Automatic optimization using ZStandard level 1
-    Optimal,
-    /// Automatic optimization with brute force settings using ZStandard
-    Max,
-}
+    /// verify that every transformed file untransforms back to the original
+    #[argh(switch)]
+    pub verify: bool,
 
-impl std::str::FromStr for CompressionPreset {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "low" => Ok(Self::Low),
-            "medium" => Ok(Self::Medium),
-            "optimal" => Ok(Self::Optimal),
-            "max" => Ok(Self::Max),
-            _ => Err(format!(
-                "Unknown preset: {s}. Valid options: low, medium, optimal, max"
-            )),
-        }
-    }
+    /// store a checksum of the original texture data, verified when untransforming
+    #[argh(switch)]
+    pub checksum: bool,
+
+    /// transform files with less texture data than their header states, instead of skipping them
+    #[argh(switch)]
+    pub allow_truncated: bool,
 }
 
 pub fn handle_transform_command(cmd: TransformCmd) -> Result<(), Box<dyn std::error::Error>> {
@@ -62,36 +43,24 @@ pub fn handle_transform_command(cmd: TransformCmd) -> Result<(), Box<dyn std::er
     println!("For production use, integrate the API directly into your application.\n");
 
     // Collect all files
-    let mut entries = Vec::new();
-    find_all_files(&cmd.input, &mut entries)?;
+    let jobs = BatchJob::from_directory(&cmd.input, &cmd.output)?;
 
-    if entries.is_empty() {
+    if jobs.is_empty() {
         println!("No files found in input directory.");
         return Ok(());
     }
 
-    println!("Found {} files to process\n", entries.len());
+    println!("Found {} files to process\n", jobs.len());
 
     let start = Instant::now();
 
-    let total_bytes = match cmd.preset {
-        CompressionPreset::Low => {
-            let bundle = create_low_preset_bundle()?;
-            process_files_with_bundle(&entries, &cmd.input, &cmd.output, &bundle)
-        }
-        CompressionPreset::Medium => {
-            let bundle = create_medium_preset_bundle()?;
-            process_files_with_bundle(&entries, &cmd.input, &cmd.output, &bundle)
-        }
-        CompressionPreset::Optimal => {
-            let bundle = create_optimal_preset_bundle()?;
-            process_files_with_bundle(&entries, &cmd.input, &cmd.output, &bundle)
-        }
-        CompressionPreset::Max => {
-            let bundle = create_max_preset_bundle()?;
-            process_files_with_bundle(&entries, &cmd.input, &cmd.output, &bundle)
-        }
-    };
+    let bundle = cmd
+        .preset
+        .bundle()
+        .with_verify(cmd.verify)
+        .with_checksum(cmd.checksum)
+        .with_allow_truncated(cmd.allow_truncated);
+    let total_bytes = process_files_with_bundle(&jobs, &bundle);
 
     let elapsed = start.elapsed();
     let data_size = ByteSize(total_bytes);
@@ -109,130 +78,12 @@ pub fn handle_transform_command(cmd: TransformCmd) -> Result<(), Box<dyn std::er
     Ok(())
 }
 
-/// Create transform bundle for low preset (manual settings)
-fn create_low_preset_bundle() -> Result<TransformBundle<NoEstimation>, Box<dyn std::error::Error>> {
-    // For Low preset, Bc1ManualTransformBuilder::new() already gives default settings
-    let bundle =
-        TransformBundle::<NoEstimation>::new().with_bc1_manual(Bc1ManualTransformBuilder::new());
-    Ok(bundle)
-}
-
-/// Create transform bundle for medium preset (LTU estimator)
-fn create_medium_preset_bundle() -> Result<
-    TransformBundle<dxt_lossless_transform_ltu::LosslessTransformUtilsSizeEstimation>,
-    Box<dyn std::error::Error>,
-> {
-    let estimator = dxt_lossless_transform_ltu::LosslessTransformUtilsSizeEstimation::new();
-    let bundle = TransformBundle::new().with_bc1_auto(Bc1AutoTransformBuilder::new(estimator));
-    Ok(bundle)
-}
-
-/// Create transform bundle for optimal preset (ZStandard level 1)
-fn create_optimal_preset_bundle() -> Result<
-    TransformBundle<dxt_lossless_transform_zstd::ZStandardSizeEstimation>,
-    Box<dyn std::error::Error>,
-> {
-    // Create ZStandard level 1 estimator
-    let estimator = dxt_lossless_transform_zstd::ZStandardSizeEstimation::new(1)?;
-    let bundle = TransformBundle::new().with_bc1_auto(Bc1AutoTransformBuilder::new(estimator));
-    Ok(bundle)
-}
-
-/// Create transform bundle for max preset (ZStandard with brute force)
-fn create_max_preset_bundle() -> Result<
-    TransformBundle<dxt_lossless_transform_zstd::ZStandardSizeEstimation>,
-    Box<dyn std::error::Error>,
-> {
-    // Create ZStandard level 1 estimator
-    let estimator = dxt_lossless_transform_zstd::ZStandardSizeEstimation::new(1)?;
-    let bundle =
-        TransformBundle::new().with_bc1_auto(Bc1AutoTransformBuilder::new_ultra(estimator));
-    Ok(bundle)
-}
-
 /// Process all files using the provided bundle and return total bytes processed
-fn process_files_with_bundle<T>(
-    entries: &[std::fs::DirEntry],
-    input_dir: &Path,
-    output_dir: &Path,
-    bundle: &TransformBundle<T>,
-) -> u64
+fn process_files_with_bundle<T>(jobs: &[BatchJob], bundle: &TransformBundle<T>) -> u64
 where
     T: dxt_lossless_transform_api_common::estimate::SizeEstimationOperations + Sync,
     T::Error: std::fmt::Debug,
 {
-    use crate::util::handle_process_entry_error;
-
-    #[cfg(feature = "multithreaded")]
-    {
-        use rayon::prelude::*;
-        use std::sync::atomic::{AtomicU64, Ordering};
-
-        let bytes_processed = AtomicU64::new(0);
-        entries
-            .par_iter()
-            // 1 item at once per thread. Our items are big generally, and take time to process
-            // so 'max work stealing' is preferred.
-            .with_max_len(1)
-            .for_each(
-                |entry| match process_file_transform(entry, input_dir, output_dir, bundle) {
-                    Ok(bytes) => {
-                        bytes_processed.fetch_add(bytes, Ordering::Relaxed);
-                    }
-                    Err(e) => handle_process_entry_error(Err(e)),
-                },
-            );
-        bytes_processed.load(Ordering::Relaxed)
-    }
-
-    #[cfg(not(feature = "multithreaded"))]
-    {
-        let mut bytes_processed = 0u64;
-        for entry in entries {
-            match process_file_transform(entry, input_dir, output_dir, bundle) {
-                Ok(bytes) => bytes_processed += bytes,
-                Err(e) => handle_process_entry_error(Err(e)),
-            }
-        }
-        bytes_processed
-    }
-}
-
-/// Process a single file transform - returns bytes processed
-fn process_file_transform<T>(
-    entry: &std::fs::DirEntry,
-    input_dir: &Path,
-    output_dir: &Path,
-    bundle: &TransformBundle<T>,
-) -> Result<u64, crate::error::TransformError>
-where
-    T: dxt_lossless_transform_api_common::estimate::SizeEstimationOperations,
-    T::Error: std::fmt::Debug,
-{
-    let path = entry.path();
-    let relative = path.strip_prefix(input_dir).unwrap();
-    let target_path = output_dir.join(relative);
-
-    // Create output directory if needed
-    if let Some(parent) = target_path.parent() {
-        std::fs::create_dir_all(parent)?;
-    }
-
-    // Get file size for throughput calculation
-    let bytes = if let Ok(metadata) = std::fs::metadata(&path) {
-        metadata.len()
-    } else {
-        0
-    };
-
-    // Try different file format handlers in sequence using detection
-    // Use the new wrapper API that handles multiple handlers automatically
-    let _ = file_io::transform_file_with_multiple_handlers(
-        all_handlers(),
-        &path,
-        &target_path,
-        bundle,
-    )?;
-
-    Ok(bytes)
+    let results = file_io::transform_batch(jobs, &all_handlers(), bundle, &BatchOptions::new());
+    report_batch_results(&results)
 }
@@ -0,0 +1,41 @@
+//! Deflate compression implementation module.
+
+use super::CompressionOperations;
+use crate::error::TransformError;
+use core::{iter, slice};
+use miniz_oxide::deflate::compress_to_vec;
+use miniz_oxide::inflate::decompress_slice_iter_to_slice;
+
+/// Deflate implementation of [`CompressionOperations`].
+///
+/// Data is compressed as a raw deflate stream (as stored in zip files), using levels 1-10.
+pub struct DeflateCompression;
+
+impl CompressionOperations for DeflateCompression {
+    fn compress_data(
+        &self,
+        data_ptr: *const u8,
+        len_bytes: usize,
+        compression_level: i32,
+    ) -> Result<(Box<[u8]>, usize), TransformError> {
+        let level = u8::try_from(compression_level).map_err(|_| {
+            TransformError::Debug(format!(
+                "Invalid deflate compression level: {compression_level}"
+            ))
+        })?;
+
+        let original_slice = unsafe { slice::from_raw_parts(data_ptr, len_bytes) };
+        let compressed = compress_to_vec(original_slice, level).into_boxed_slice();
+        let compressed_size = compressed.len();
+        Ok((compressed, compressed_size))
+    }
+
+    fn decompress_data(
+        &self,
+        compressed_data: &[u8],
+        output_buffer: &mut [u8],
+    ) -> Result<usize, TransformError> {
+        decompress_slice_iter_to_slice(output_buffer, iter::once(compressed_data), false, true)
+            .map_err(|e| TransformError::Debug(format!("Deflate decompression failed: {e:?}")))
+    }
+}
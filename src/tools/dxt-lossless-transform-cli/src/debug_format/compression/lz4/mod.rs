@@ -0,0 +1,79 @@
+//! LZ4 compression implementation module.
+
+use super::CompressionOperations;
+use crate::error::TransformError;
+use core::ffi::{c_char, c_int};
+use lz4_sys::{LZ4_compressBound, LZ4_compress_HC, LZ4_compress_default, LZ4_decompress_safe};
+
+/// Lowest compression level which uses LZ4-HC rather than the fast LZ4 compressor.
+const LZ4HC_MIN_LEVEL: i32 = 3;
+
+/// LZ4 implementation of [`CompressionOperations`].
+///
+/// Levels 1-2 use the fast LZ4 compressor, levels 3-12 use LZ4-HC.
+/// Data is compressed as a single LZ4 block.
+pub struct Lz4Compression;
+
+impl CompressionOperations for Lz4Compression {
+    fn compress_data(
+        &self,
+        data_ptr: *const u8,
+        len_bytes: usize,
+        compression_level: i32,
+    ) -> Result<(Box<[u8]>, usize), TransformError> {
+        let len = c_int::try_from(len_bytes).map_err(|_| {
+            TransformError::Debug(format!("Input of {len_bytes} bytes is too large for LZ4"))
+        })?;
+        let max_compressed_size = unsafe { LZ4_compressBound(len) };
+        let mut compressed_buffer =
+            unsafe { Box::<[u8]>::new_uninit_slice(max_compressed_size as usize).assume_init() };
+
+        let source = data_ptr as *const c_char;
+        let destination = compressed_buffer.as_mut_ptr() as *mut c_char;
+        let compressed_size = unsafe {
+            if compression_level >= LZ4HC_MIN_LEVEL {
+                LZ4_compress_HC(
+                    source,
+                    destination,
+                    len,
+                    max_compressed_size,
+                    compression_level,
+                )
+            } else {
+                LZ4_compress_default(source, destination, len, max_compressed_size)
+            }
+        };
+
+        if compressed_size <= 0 && len_bytes != 0 {
+            return Err(TransformError::Debug("LZ4 compression failed".to_owned()));
+        }
+
+        Ok((compressed_buffer, compressed_size as usize))
+    }
+
+    fn decompress_data(
+        &self,
+        compressed_data: &[u8],
+        output_buffer: &mut [u8],
+    ) -> Result<usize, TransformError> {
+        let decompressed_size = match (
+            c_int::try_from(compressed_data.len()),
+            c_int::try_from(output_buffer.len()),
+        ) {
+            (Ok(compressed_len), Ok(output_len)) => unsafe {
+                LZ4_decompress_safe(
+                    compressed_data.as_ptr() as *const c_char,
+                    output_buffer.as_mut_ptr() as *mut c_char,
+                    compressed_len,
+                    output_len,
+                )
+            },
+            _ => -1,
+        };
+
+        match decompressed_size {
+            size if size >= 0 => Ok(size as usize),
+            _ => Err(TransformError::Debug("LZ4 decompression failed".to_owned())),
+        }
+    }
+}
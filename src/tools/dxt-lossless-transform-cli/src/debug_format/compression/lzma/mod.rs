@@ -0,0 +1,80 @@
+//! LZMA compression implementation module.
+
+use super::CompressionOperations;
+use crate::error::TransformError;
+use core::ptr::null;
+use liblzma_sys::{
+    lzma_easy_buffer_encode, lzma_stream_buffer_bound, lzma_stream_buffer_decode, LZMA_CHECK_NONE,
+    LZMA_OK,
+};
+
+/// LZMA implementation of [`CompressionOperations`].
+///
+/// Data is compressed as an `.xz` stream without a checksum, using the `xz` presets (0-9).
+pub struct LzmaCompression;
+
+impl CompressionOperations for LzmaCompression {
+    fn compress_data(
+        &self,
+        data_ptr: *const u8,
+        len_bytes: usize,
+        compression_level: i32,
+    ) -> Result<(Box<[u8]>, usize), TransformError> {
+        let max_compressed_size = unsafe { lzma_stream_buffer_bound(len_bytes) };
+        let mut compressed_buffer =
+            unsafe { Box::<[u8]>::new_uninit_slice(max_compressed_size).assume_init() };
+
+        let mut compressed_size = 0;
+        let result = unsafe {
+            lzma_easy_buffer_encode(
+                compression_level as u32,
+                LZMA_CHECK_NONE,
+                null(),
+                data_ptr,
+                len_bytes,
+                compressed_buffer.as_mut_ptr(),
+                &mut compressed_size,
+                max_compressed_size,
+            )
+        };
+
+        if result != LZMA_OK {
+            return Err(TransformError::Debug(format!(
+                "LZMA compression failed with error code {result}"
+            )));
+        }
+
+        Ok((compressed_buffer, compressed_size))
+    }
+
+    fn decompress_data(
+        &self,
+        compressed_data: &[u8],
+        output_buffer: &mut [u8],
+    ) -> Result<usize, TransformError> {
+        let mut memory_limit = u64::MAX;
+        let mut input_position = 0;
+        let mut output_position = 0;
+        let result = unsafe {
+            lzma_stream_buffer_decode(
+                &mut memory_limit,
+                0,
+                null(),
+                compressed_data.as_ptr(),
+                &mut input_position,
+                compressed_data.len(),
+                output_buffer.as_mut_ptr(),
+                &mut output_position,
+                output_buffer.len(),
+            )
+        };
+
+        if result != LZMA_OK {
+            return Err(TransformError::Debug(format!(
+                "LZMA decompression failed with error code {result}"
+            )));
+        }
+
+        Ok(output_position)
+    }
+}
@@ -2,12 +2,18 @@
 
 use crate::error::TransformError;
 use core::fmt;
+use deflate::DeflateCompression;
 use derive_enum_all_values::AllValues;
+use lz4::Lz4Compression;
+use lzma::LzmaCompression;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use zstd::ZStandardCompression;
 
+pub mod deflate;
 pub mod helpers;
+pub mod lz4;
+pub mod lzma;
 pub mod zstd;
 
 /// Supported compression algorithms for benchmarking and estimation.
@@ -32,6 +38,12 @@ pub enum CompressionAlgorithm {
     ZStandard,
     /// Estimate using lossless-transform-utils. Compression not supported.
     LosslessTransformUtils,
+    /// LZ4 compression; levels 1-2 use LZ4, levels 3-12 use LZ4-HC
+    Lz4,
+    /// Deflate compression (as used by zip, gzip and zlib); levels 1-10
+    Deflate,
+    /// LZMA compression (as used by xz and 7z); levels 0-9
+    Lzma,
 }
 
 impl CompressionAlgorithm {
@@ -40,6 +52,9 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::ZStandard => "zst",
             CompressionAlgorithm::LosslessTransformUtils => "ltu", // not a compression algorithm, but an estimation method
+            CompressionAlgorithm::Lz4 => "lz4",
+            CompressionAlgorithm::Deflate => "deflate",
+            CompressionAlgorithm::Lzma => "xz",
         }
     }
 
@@ -48,6 +63,9 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::ZStandard => "ZStandard",
             CompressionAlgorithm::LosslessTransformUtils => "lossless-transform-utils",
+            CompressionAlgorithm::Lz4 => "LZ4",
+            CompressionAlgorithm::Deflate => "Deflate",
+            CompressionAlgorithm::Lzma => "LZMA",
         }
     }
 
@@ -56,6 +74,9 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::ZStandard => 16,
             CompressionAlgorithm::LosslessTransformUtils => 0, // Not applicable, as this is an estimation method
+            CompressionAlgorithm::Lz4 => 9,                    // LZ4-HC default
+            CompressionAlgorithm::Deflate => 9,
+            CompressionAlgorithm::Lzma => 6, // xz default
         }
     }
 
@@ -64,6 +85,9 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::ZStandard => 3,
             CompressionAlgorithm::LosslessTransformUtils => 0, // Not applicable, as this is an estimation method
+            CompressionAlgorithm::Lz4 => 9,
+            CompressionAlgorithm::Deflate => 9,
+            CompressionAlgorithm::Lzma => 6,
         }
     }
 
@@ -72,6 +96,9 @@ impl CompressionAlgorithm {
         match self {
             CompressionAlgorithm::ZStandard => true,
             CompressionAlgorithm::LosslessTransformUtils => false,
+            CompressionAlgorithm::Lz4 => true,
+            CompressionAlgorithm::Deflate => true,
+            CompressionAlgorithm::Lzma => true,
         }
     }
 }
@@ -89,8 +116,11 @@ impl FromStr for CompressionAlgorithm {
         match s.to_lowercase().as_str() {
             "zstd" | "zstandard" => Ok(CompressionAlgorithm::ZStandard),
             "ltu" | "lossless-transform-utils" => Ok(CompressionAlgorithm::LosslessTransformUtils),
+            "lz4" | "lz4hc" => Ok(CompressionAlgorithm::Lz4),
+            "deflate" | "zlib" => Ok(CompressionAlgorithm::Deflate),
+            "lzma" | "xz" => Ok(CompressionAlgorithm::Lzma),
             _ => Err(TransformError::Debug(format!(
-                "Unknown compression algorithm: {s}. Available: zstd, ltu",
+                "Unknown compression algorithm: {s}. Available: zstd, ltu, lz4, deflate, lzma",
             ))),
         }
     }
@@ -154,6 +184,15 @@ pub fn compress_with_algorithm(
             ZStandardCompression.compress_data(data_ptr, len_bytes, compression_level)
         }
         CompressionAlgorithm::LosslessTransformUtils => todo!(),
+        CompressionAlgorithm::Lz4 => {
+            Lz4Compression.compress_data(data_ptr, len_bytes, compression_level)
+        }
+        CompressionAlgorithm::Deflate => {
+            DeflateCompression.compress_data(data_ptr, len_bytes, compression_level)
+        }
+        CompressionAlgorithm::Lzma => {
+            LzmaCompression.compress_data(data_ptr, len_bytes, compression_level)
+        }
     }
 }
 
@@ -180,5 +219,12 @@ pub fn decompress_with_algorithm(
             ZStandardCompression.decompress_data(compressed_data, output_buffer)
         }
         CompressionAlgorithm::LosslessTransformUtils => todo!(),
+        CompressionAlgorithm::Lz4 => Lz4Compression.decompress_data(compressed_data, output_buffer),
+        CompressionAlgorithm::Deflate => {
+            DeflateCompression.decompress_data(compressed_data, output_buffer)
+        }
+        CompressionAlgorithm::Lzma => {
+            LzmaCompression.decompress_data(compressed_data, output_buffer)
+        }
     }
 }
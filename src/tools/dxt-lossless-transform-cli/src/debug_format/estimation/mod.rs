@@ -38,6 +38,33 @@ pub fn create_size_estimator(
             // Create a wrapper that converts the error type
             Ok(Box::new(LtuEstimatorWrapper(estimator)))
         }
+        CompressionAlgorithm::Lz4 => {
+            use dxt_lossless_transform_lz4::Lz4SizeEstimation;
+            let estimator = Lz4SizeEstimation::new(compression_level).map_err(|e| {
+                TransformError::Debug(format!("Failed to create LZ4 estimator: {e}"))
+            })?;
+
+            // Create a wrapper that converts the error type
+            Ok(Box::new(Lz4EstimatorWrapper(estimator)))
+        }
+        CompressionAlgorithm::Deflate => {
+            use dxt_lossless_transform_deflate::DeflateSizeEstimation;
+            let estimator = DeflateSizeEstimation::new(compression_level).map_err(|e| {
+                TransformError::Debug(format!("Failed to create deflate estimator: {e}"))
+            })?;
+
+            // Create a wrapper that converts the error type
+            Ok(Box::new(DeflateEstimatorWrapper(estimator)))
+        }
+        CompressionAlgorithm::Lzma => {
+            use dxt_lossless_transform_lzma::LzmaSizeEstimation;
+            let estimator = LzmaSizeEstimation::new(compression_level).map_err(|e| {
+                TransformError::Debug(format!("Failed to create LZMA estimator: {e}"))
+            })?;
+
+            // Create a wrapper that converts the error type
+            Ok(Box::new(LzmaEstimatorWrapper(estimator)))
+        }
     }
 }
 
@@ -95,6 +122,81 @@ impl SizeEstimationOperations for LtuEstimatorWrapper {
     }
 }
 
+/// Wrapper for LZ4 estimator to convert error types
+struct Lz4EstimatorWrapper(dxt_lossless_transform_lz4::Lz4SizeEstimation);
+
+impl SizeEstimationOperations for Lz4EstimatorWrapper {
+    type Error = TransformError;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        self.0
+            .max_compressed_size(len_bytes)
+            .map_err(|e| TransformError::Debug(format!("LZ4 max compressed size failed: {e}")))
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        input_ptr: *const u8,
+        len_bytes: usize,
+        output_ptr: *mut u8,
+        output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        self.0
+            .estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len)
+            .map_err(|e| TransformError::Debug(format!("LZ4 estimation failed: {e}")))
+    }
+}
+
+/// Wrapper for deflate estimator to convert error types
+struct DeflateEstimatorWrapper(dxt_lossless_transform_deflate::DeflateSizeEstimation);
+
+impl SizeEstimationOperations for DeflateEstimatorWrapper {
+    type Error = TransformError;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        self.0
+            .max_compressed_size(len_bytes)
+            .map_err(|e| TransformError::Debug(format!("Deflate max compressed size failed: {e}")))
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        input_ptr: *const u8,
+        len_bytes: usize,
+        output_ptr: *mut u8,
+        output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        self.0
+            .estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len)
+            .map_err(|e| TransformError::Debug(format!("Deflate estimation failed: {e}")))
+    }
+}
+
+/// Wrapper for LZMA estimator to convert error types
+struct LzmaEstimatorWrapper(dxt_lossless_transform_lzma::LzmaSizeEstimation);
+
+impl SizeEstimationOperations for LzmaEstimatorWrapper {
+    type Error = TransformError;
+
+    fn max_compressed_size(&self, len_bytes: usize) -> Result<usize, Self::Error> {
+        self.0
+            .max_compressed_size(len_bytes)
+            .map_err(|e| TransformError::Debug(format!("LZMA max compressed size failed: {e}")))
+    }
+
+    unsafe fn estimate_compressed_size(
+        &self,
+        input_ptr: *const u8,
+        len_bytes: usize,
+        output_ptr: *mut u8,
+        output_len: usize,
+    ) -> Result<usize, Self::Error> {
+        self.0
+            .estimate_compressed_size(input_ptr, len_bytes, output_ptr, output_len)
+            .map_err(|e| TransformError::Debug(format!("LZMA estimation failed: {e}")))
+    }
+}
+
 /// Caching wrapper for SizeEstimationOperations that uses [`CompressionSizeCache`]
 ///
 /// This wrapper adds caching functionality on top of any [`SizeEstimationOperations`]
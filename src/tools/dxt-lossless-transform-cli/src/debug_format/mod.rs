@@ -7,7 +7,7 @@ pub mod compression;
 pub mod compression_size_cache;
 pub mod estimation;
 
-use crate::{error::TransformError, util::all_handlers};
+use crate::{error::TransformError, util::debug_handlers};
 use dxt_lossless_transform_file_formats_api::embed::TransformFormat;
 use dxt_lossless_transform_file_formats_debug::{
     extract_blocks_from_file_format, TransformFormatFilter,
@@ -35,7 +35,7 @@ where
     };
 
     // Use the file-formats-debug function and convert the error
-    extract_blocks_from_file_format(file_path, &all_handlers(), filter, transform_format_fn)
+    extract_blocks_from_file_format(file_path, &debug_handlers(), filter, transform_format_fn)
         .map_err(TransformError::FileOperation)
 }
 
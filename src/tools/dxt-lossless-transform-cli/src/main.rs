@@ -4,6 +4,7 @@
 mod commands;
 #[cfg(feature = "debug-format")]
 mod debug_format;
+#[cfg(feature = "debug-format")]
 mod error;
 mod util;
 use argh::FromArgs;
@@ -1,3 +1,4 @@
+use dxt_lossless_transform_file_formats_api::file_io::BatchFileResult;
 use std::fs;
 use std::path::*;
 
@@ -16,6 +17,7 @@ use std::path::*;
 /// # Returns
 ///
 /// A `Result` indicating whether the traversal was successful.
+#[cfg(any(feature = "debug-format", feature = "debug-endian"))]
 pub fn find_all_files(dir: &Path, entries: &mut Vec<fs::DirEntry>) -> std::io::Result<()> {
     // Gracefully handle cases where the directory cannot be read
     let dir_entries = match fs::read_dir(dir) {
@@ -40,11 +42,16 @@ pub fn find_all_files(dir: &Path, entries: &mut Vec<fs::DirEntry>) -> std::io::R
     Ok(())
 }
 
-/// Handles errors from process_dir_entry function by printing to stderr.
-pub fn handle_process_entry_error(result: Result<(), crate::error::TransformError>) {
-    if let Err(e) = result {
-        eprintln!("{e}");
+/// Prints the errors of a batch operation to stderr and returns the total bytes processed.
+pub fn report_batch_results(results: &[BatchFileResult]) -> u64 {
+    let mut bytes_processed = 0u64;
+    for file in results {
+        match &file.result {
+            Ok(bytes) => bytes_processed += bytes,
+            Err(e) => eprintln!("{}: {e}", file.job.input.display()),
+        }
     }
+    bytes_processed
 }
 
 /// Canonicalizes a CLI path argument, creating the directory if it doesn't exist.
@@ -1,10 +1,27 @@
+use core::fmt::Debug;
+use dxt_lossless_transform_api_common::estimate::SizeEstimationOperations;
 use dxt_lossless_transform_dds::DdsHandler;
+use dxt_lossless_transform_file_formats_api::HandlerRegistry;
 
-/// Returns an array of all supported file format handlers.
+/// Returns a registry of all supported file format handlers.
 ///
 /// This function provides a centralized way to access all available
-/// file format handlers, avoiding the need to hardcode handler arrays
-/// throughout the codebase.
-pub fn all_handlers() -> [DdsHandler; 1] {
+/// file format handlers, avoiding the need to hardcode handler lists
+/// throughout the codebase. Handlers registered for a file's extension
+/// are tried first.
+pub fn all_handlers<T>() -> HandlerRegistry<T>
+where
+    T: SizeEstimationOperations,
+    T::Error: Debug,
+{
+    HandlerRegistry::new().with_handler(DdsHandler, &["dds"])
+}
+
+/// Returns an array of all file format handlers supporting the debug traits.
+///
+/// The debug traits (block extraction, format checks) are not object safe,
+/// so these cannot be stored in a [`HandlerRegistry`].
+#[cfg(any(feature = "debug-format", feature = "debug-endian"))]
+pub fn debug_handlers() -> [DdsHandler; 1] {
     [DdsHandler]
 }